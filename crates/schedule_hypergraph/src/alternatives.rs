@@ -0,0 +1,204 @@
+use scheduling_environment::Period;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::ResourceAlternative;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// Public API for activities that can be performed in more than one way.
+impl ScheduleGraph
+{
+    /// The ways to perform the activity. The first is the activity's own
+    /// requirement, followed by its `ResourceAlternative`s in the order they
+    /// were declared.
+    pub fn activity_alternatives(
+        &self,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<Vec<ResourceAlternative>, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+        let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+            return Err(self.activity_missing(work_order_number, activity_number));
+        };
+
+        let requirements = self
+            .node_skills(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires))
+            .into_iter()
+            .map(|skill| ResourceAlternative {
+                number_of_people: activity.number_of_people(),
+                skill,
+                duration: activity.duration(),
+            });
+        Ok(requirements.chain(activity.alternatives().iter().copied()).collect())
+    }
+
+    /// The alternative with the fewest person-hours that the capacity of the
+    /// period can staff. An alternative can be staffed when enough
+    /// technicians with its skill are available for its duration in the
+    /// period. Alternatives without a duration are considered after the
+    /// ones with a duration, and ties go to the one declared first.
+    ///
+    /// Schedulers use this to decide how an activity is performed before
+    /// assigning technicians to it. `None` if no alternative can be staffed.
+    pub fn cheapest_feasible_alternative(
+        &self,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        period: Period,
+    ) -> Result<Option<ResourceAlternative>, ScheduleGraphErrors>
+    {
+        let alternatives = self.activity_alternatives(work_order_number, activity_number)?;
+        let capacity_calendar = self.capacity_calendar(&[period])?;
+        let period_capacity = &capacity_calendar.0[&period];
+
+        let mut cheapest: Option<ResourceAlternative> = None;
+        for alternative in alternatives {
+            let required_hours = alternative.duration.map_or(Work::ZERO, Work::from_duration);
+            let available_technicians = period_capacity.skills.get(&alternative.skill).map_or(0, |skill_capacity| {
+                skill_capacity
+                    .technicians
+                    .values()
+                    .filter(|technician_capacity| technician_capacity.total_hours > Work::ZERO && technician_capacity.total_hours >= required_hours)
+                    .count()
+            });
+            if (available_technicians as u64) < alternative.number_of_people {
+                continue;
+            }
+
+            let is_cheaper = match (cheapest, alternative.work()) {
+                (None, _) => true,
+                (Some(cheapest), Some(work)) => cheapest.work().is_none_or(|cheapest_work| work < cheapest_work),
+                (Some(_), None) => false,
+            };
+            if is_cheaper {
+                cheapest = Some(alternative);
+            }
+        }
+        Ok(cheapest)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::ResourceAlternative;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::infeasibility::InfeasibilityReason;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_cheapest_feasible_alternative()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        // 3 mechanics for 4 hours, 1 mechanic for 16 hours, or 2
+        // electricians for 7 hours.
+        let one_mechanic = ResourceAlternative {
+            number_of_people: 1,
+            skill: Skill::MTN_MECH,
+            duration: Some(TimeDelta::hours(16)),
+        };
+        let two_electricians = ResourceAlternative {
+            number_of_people: 2,
+            skill: Skill::MTN_ELEC,
+            duration: Some(TimeDelta::hours(7)),
+        };
+        let activity = Activity::new(ActivityNumber::new(10), 3, Skill::MTN_MECH)
+            .with_duration(TimeDelta::hours(4))
+            .with_alternative(one_mechanic)
+            .with_alternative(two_electricians);
+        let work_order = WorkOrder::new(WorkOrderNumber::new(1122334455).unwrap(), start_date, vec![activity]).unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let unregistered_work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334466).unwrap(),
+            start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_alternative(ResourceAlternative {
+                    skill: Skill::new("Insulation"),
+                    ..one_mechanic
+                }),
+            ],
+        )
+        .unwrap();
+        assert!(schedule_graph.add_work_order(&unregistered_work_order).is_err());
+
+        assert_eq!(
+            schedule_graph
+                .activity_alternatives(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10))
+                .unwrap()
+                .len(),
+            3
+        );
+        assert_eq!(
+            schedule_graph.cheapest_feasible_alternative(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10), period),
+            Ok(None)
+        );
+        assert_eq!(
+            schedule_graph.diagnose_infeasibility(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(Some(vec![InfeasibilityReason::NoSkillCapacity {
+                period,
+                skill: Skill::MTN_MECH,
+            }]))
+        );
+
+        let add_technician = |schedule_graph: &mut ScheduleGraph, technician_id, skill, days| {
+            let start = start_date.and_hms_opt(8, 0, 0).unwrap();
+            let end = (start_date + Duration::days(days)).and_hms_opt(16, 0, 0).unwrap();
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(skill)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        };
+        add_technician(&mut schedule_graph, TechnicianId::new(1001), Skill::MTN_ELEC, 0);
+        add_technician(&mut schedule_graph, TechnicianId::new(1002), Skill::MTN_ELEC, 0);
+        add_technician(&mut schedule_graph, TechnicianId::new(1003), Skill::MTN_MECH, 0);
+        assert_eq!(schedule_graph.diagnose_infeasibility(WorkOrderNumber::new(1122334455).unwrap()), Ok(None));
+        // The only mechanic is available for 8 hours.
+        assert_eq!(
+            schedule_graph.cheapest_feasible_alternative(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10), period),
+            Ok(Some(two_electricians))
+        );
+
+        add_technician(&mut schedule_graph, TechnicianId::new(1004), Skill::MTN_MECH, 1);
+        assert_eq!(
+            schedule_graph.cheapest_feasible_alternative(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10), period),
+            Ok(Some(two_electricians))
+        );
+
+        add_technician(&mut schedule_graph, TechnicianId::new(1005), Skill::MTN_MECH, 0);
+        assert_eq!(
+            schedule_graph.cheapest_feasible_alternative(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10), period),
+            Ok(Some(ResourceAlternative {
+                number_of_people: 3,
+                skill: Skill::MTN_MECH,
+                duration: Some(TimeDelta::hours(4)),
+            }))
+        );
+    }
+}
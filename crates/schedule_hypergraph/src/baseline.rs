@@ -0,0 +1,179 @@
+use scheduling_environment::Period;
+
+use crate::schedule_graph::AssignmentView;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// Schedule compliance of a period: the share of the baseline assignments
+/// that were executed as planned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleAdherence
+{
+    pub baseline_assignments: usize,
+    pub executed_as_planned: usize,
+}
+
+impl ScheduleAdherence
+{
+    /// The adherence in percent. A period with an empty baseline is fully
+    /// adherent.
+    pub fn percentage(&self) -> f64
+    {
+        if self.baseline_assignments == 0 {
+            return 100.0;
+        }
+        100.0 * self.executed_as_planned as f64 / self.baseline_assignments as f64
+    }
+}
+
+/// Public API to commit the plan of a period and measure how well it was
+/// followed.
+impl ScheduleGraph
+{
+    /// Commits the current assignments of the period as its baseline. This
+    /// is meant to be done once at the start of the period. Like the
+    /// close-out, the baseline is not part of the undo history.
+    pub fn commit_baseline(&mut self, period: Period) -> Result<&[AssignmentView], ScheduleGraphErrors>
+    {
+        if self.is_period_closed(period) {
+            return Err(ScheduleGraphErrors::PeriodClosed { period });
+        }
+        if self.period_baselines().contains_key(&period) {
+            return Err(ScheduleGraphErrors::BaselineDuplicate { period });
+        }
+
+        let baseline = self.assignments_for_period(period)?;
+        Ok(self.period_baselines_mut().entry(period).or_insert(baseline))
+    }
+
+    pub fn baseline(&self, period: Period) -> Option<&[AssignmentView]>
+    {
+        self.period_baselines().get(&period).map(Vec::as_slice)
+    }
+
+    /// The adherence to the baseline of the period so far. A baseline
+    /// assignment is executed as planned while it is still in the graph
+    /// under the same `AssignmentId`, so moved and removed assignments count
+    /// against the adherence. `None` if no baseline is committed.
+    pub fn schedule_adherence(&self, period: Period) -> Option<ScheduleAdherence>
+    {
+        self.schedule_adherence_by(period, |_| true)
+    }
+
+    /// Like `schedule_adherence`, but only the baseline assignments that
+    /// satisfy the filter can count as executed as planned.
+    pub(crate) fn schedule_adherence_by(&self, period: Period, filter: impl Fn(&AssignmentView) -> bool) -> Option<ScheduleAdherence>
+    {
+        let baseline = self.period_baselines().get(&period)?;
+
+        Some(ScheduleAdherence {
+            baseline_assignments: baseline.len(),
+            executed_as_planned: baseline
+                .iter()
+                .filter(|assignment| self.assignment(assignment.assignment_id).as_ref() == Ok(*assignment))
+                .filter(|assignment| filter(assignment))
+                .count(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::ScheduleAdherence;
+    use crate::close_out::CloseOutDecision;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_schedule_adherence()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+
+        let work_order_numbers = [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+            WorkOrderNumber::new(1122334488).unwrap(),
+        ];
+        for work_order_number in work_order_numbers {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = start_date.and_hms_opt(8, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        for work_order_number in work_order_numbers {
+            schedule_graph
+                .add_assignment_work_order(TechnicianId::new(1001), work_order_number, period)
+                .unwrap();
+        }
+
+        assert_eq!(schedule_graph.schedule_adherence(period), None);
+        assert_eq!(schedule_graph.commit_baseline(period).unwrap().len(), 4);
+        assert_eq!(
+            schedule_graph.commit_baseline(period),
+            Err(ScheduleGraphErrors::BaselineDuplicate { period })
+        );
+
+        // 1122334488 is removed from the plan during the period.
+        let assignment_id = schedule_graph.assignments_for_period(period).unwrap()[3].assignment_id;
+        schedule_graph.remove_assignment(assignment_id).unwrap();
+        schedule_graph.compact();
+        assert_eq!(
+            schedule_graph.schedule_adherence(period),
+            Some(ScheduleAdherence {
+                baseline_assignments: 4,
+                executed_as_planned: 3,
+            })
+        );
+
+        // Only completed work is executed as planned at close-out.
+        let close_out_decisions = BTreeMap::from([
+            (WorkOrderNumber::new(1122334455).unwrap(), CloseOutDecision::Completed),
+            (WorkOrderNumber::new(1122334466).unwrap(), CloseOutDecision::Completed),
+            (WorkOrderNumber::new(1122334477).unwrap(), CloseOutDecision::CarryOver(next_period)),
+        ]);
+        let period_close_out = schedule_graph.close_period(period, &close_out_decisions).unwrap();
+        let schedule_adherence = period_close_out.schedule_adherence.unwrap();
+        assert_eq!(schedule_adherence.executed_as_planned, 2);
+        assert_eq!(schedule_adherence.percentage(), 50.0);
+
+        assert_eq!(schedule_graph.commit_baseline(period), Err(ScheduleGraphErrors::PeriodClosed { period }));
+        assert_eq!(schedule_graph.baseline(period).unwrap().len(), 4);
+        assert_eq!(schedule_graph.baseline(next_period), None);
+    }
+}
@@ -0,0 +1,396 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::ContractorPoolId;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+/// Capacity of the graph that can be drilled down from
+/// `Period` -> `Skill` -> `TechnicianId` -> `NaiveDate`.
+///
+/// A technician with several skills contributes the same hours to each of
+/// its skills, as the hours can be spent on either of them. The skills and
+/// the end of the contract of a technician are effective-dated, see
+/// `TechnicianChange`.
+#[derive(Debug, Default, PartialEq)]
+pub struct CapacityCalendar(pub BTreeMap<Period, PeriodCapacity>);
+
+#[derive(Debug, Default, PartialEq)]
+pub struct PeriodCapacity
+{
+    pub total_hours: Work,
+    pub skills: BTreeMap<Skill, SkillCapacity>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SkillCapacity
+{
+    pub total_hours: Work,
+    pub technicians: BTreeMap<TechnicianId, TechnicianCapacity>,
+    /// The hours purchased from contractors, see `PurchasedHours`.
+    pub contractor_pools: BTreeMap<ContractorPoolId, Work>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TechnicianCapacity
+{
+    pub total_hours: Work,
+    pub days: BTreeMap<NaiveDate, Work>,
+}
+
+/// Public API to aggregate the capacity found on the `Available` and
+/// `Purchased` hyperedges.
+impl ScheduleGraph
+{
+    pub fn capacity_calendar(&self, periods: &[Period]) -> Result<CapacityCalendar, ScheduleGraphErrors>
+    {
+        let mut capacity_calendar = CapacityCalendar::default();
+
+        for period in periods {
+            let days_in_period = self.period_days(period)?;
+            let period_capacity = capacity_calendar.0.entry(*period).or_default();
+
+            let technicians = self.nodes().iter().enumerate().filter_map(|(node_index, node)| match node {
+                Node::Technician(technician_id) => Some((node_index, *technician_id)),
+                _ => None,
+            });
+
+            for (technician_node_index, technician_id) in technicians {
+                for edge_index in &self.incidence_list()[technician_node_index] {
+                    let hyperedge = &self.hyperedges()[*edge_index];
+                    let EdgeType::Available(availability) = hyperedge.edge_type() else {
+                        continue;
+                    };
+
+                    let skills = hyperedge
+                        .nodes()
+                        .iter()
+                        .filter_map(|node_index| match &self.nodes()[*node_index] {
+                            Node::Skill(skill) => Some(*skill),
+                            _ => None,
+                        })
+                        .collect::<BTreeSet<_>>();
+
+                    let contract_end = self.node_contract_end(technician_node_index);
+                    let days = days_in_period
+                        .iter()
+                        .filter(|day| contract_end.is_none_or(|contract_end| **day < contract_end))
+                        .map(|day| (*day, hours_on_day(availability, *day)))
+                        .filter(|(_, hours)| *hours > Work::ZERO)
+                        .collect::<BTreeMap<_, _>>();
+                    let technician_hours: Work = days.values().sum();
+
+                    if days.is_empty() {
+                        continue;
+                    }
+
+                    period_capacity.total_hours += technician_hours;
+                    for (day, hours) in &days {
+                        for skill in self.skills_on(technician_node_index, skills.clone(), *day) {
+                            let skill_capacity = period_capacity.skills.entry(skill).or_default();
+                            skill_capacity.total_hours += *hours;
+
+                            let technician_capacity = skill_capacity.technicians.entry(technician_id).or_default();
+                            technician_capacity.total_hours += *hours;
+                            *technician_capacity.days.entry(*day).or_default() += *hours;
+                        }
+                    }
+                }
+            }
+
+            for (skill, contractor_pools) in self.purchased_hours_in(self.period_node_index(*period)?) {
+                let skill_capacity = period_capacity.skills.entry(skill).or_default();
+                for (contractor_pool_id, hours) in contractor_pools {
+                    period_capacity.total_hours += hours;
+                    skill_capacity.total_hours += hours;
+                    skill_capacity.contractor_pools.insert(contractor_pool_id, hours);
+                }
+            }
+        }
+
+        Ok(capacity_calendar)
+    }
+
+    /// The planned work of the activities that are assigned in each period,
+    /// per skill. An activity is assigned in a period when its work order is
+    /// assigned to the period or the activity is assigned on one of its
+    /// days. Activities without planned work and the work of
+    /// `Commitment::Forecast` work orders are left out.
+    pub fn planned_work_demand(&self, periods: &[Period]) -> Result<BTreeMap<Period, BTreeMap<Skill, Work>>, ScheduleGraphErrors>
+    {
+        self.work_demand(periods, Commitment::Firm)
+    }
+
+    /// Like `planned_work_demand`, but only the work of
+    /// `Commitment::Forecast` work orders.
+    pub fn forecast_work_demand(&self, periods: &[Period]) -> Result<BTreeMap<Period, BTreeMap<Skill, Work>>, ScheduleGraphErrors>
+    {
+        self.work_demand(periods, Commitment::Forecast)
+    }
+
+    /// The planned work of the activities of the work order, per skill.
+    /// Activities without planned work are left out.
+    pub fn work_order_work_load(&self, work_order_number: WorkOrderNumber) -> Result<BTreeMap<Skill, Work>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        let mut work_load = BTreeMap::new();
+
+        let activity_node_indices = self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|edge_index| &self.hyperedges()[*edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Contains))
+            .filter_map(|hyperedge| hyperedge.nodes().get(1).copied());
+        for activity_node_index in activity_node_indices {
+            let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+                continue;
+            };
+            let Some(work) = activity.work() else {
+                continue;
+            };
+            for skill in self.node_skills(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires)) {
+                *work_load.entry(skill).or_default() += work;
+            }
+        }
+        Ok(work_load)
+    }
+
+    fn work_demand(&self, periods: &[Period], commitment: Commitment) -> Result<BTreeMap<Period, BTreeMap<Skill, Work>>, ScheduleGraphErrors>
+    {
+        let mut planned_work_demand = BTreeMap::new();
+
+        for period in periods {
+            let days_in_period = self.period_days(period)?;
+
+            let mut activity_node_indices = BTreeSet::new();
+            for (edge_index, hyperedge) in self.edges() {
+                let Some(assignment) = self.assignment_view(edge_index) else {
+                    continue;
+                };
+                match assignment.activity {
+                    Some(_) if assignment.days.iter().any(|day| days_in_period.contains(day)) => {
+                        activity_node_indices.insert(hyperedge.nodes()[0]);
+                    }
+                    None if assignment.period == Some(*period) => {
+                        let work_order_node_index = self.work_order_node_index(assignment.work_order)?;
+                        activity_node_indices.extend(
+                            self.incidence_list()[work_order_node_index]
+                                .iter()
+                                .map(|edge_index| &self.hyperedges()[*edge_index])
+                                .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Contains))
+                                .filter_map(|hyperedge| hyperedge.nodes().get(1).copied()),
+                        );
+                    }
+                    _ => (),
+                }
+            }
+
+            let skill_demand: &mut BTreeMap<Skill, Work> = planned_work_demand.entry(*period).or_default();
+            for activity_node_index in activity_node_indices {
+                let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+                    continue;
+                };
+                let Some(work) = activity.work() else {
+                    continue;
+                };
+                if self.work_order_commitment(activity.work_order_number())? != commitment {
+                    continue;
+                }
+                for skill in self.node_skills(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires)) {
+                    *skill_demand.entry(skill).or_default() += work;
+                }
+            }
+        }
+
+        Ok(planned_work_demand)
+    }
+}
+
+/// Hours of the `Availability` that falls inside of the `day`.
+fn hours_on_day(availability: &Availability, day: NaiveDate) -> Work
+{
+    let day_start = day.and_time(NaiveTime::MIN);
+    let day_finish = day_start + Duration::days(1);
+
+    let start = availability.start().max(day_start);
+    let finish = availability.end().min(day_finish);
+
+    if finish <= start {
+        return Work::ZERO;
+    }
+    Work::from_duration(finish - start)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Commitment;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_capacity_calendar()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let period_start = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(period_start);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let start = period_start.and_hms_opt(8, 0, 0).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 14).unwrap().and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .add_skill(Skill::MTN_ELEC)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let capacity_calendar = schedule_graph.capacity_calendar(&[period]).unwrap();
+        let period_capacity = &capacity_calendar.0[&period];
+
+        assert_eq!(period_capacity.total_hours, Work::from_hours(32.0));
+        for skill in [Skill::MTN_MECH, Skill::MTN_ELEC] {
+            let skill_capacity = &period_capacity.skills[&skill];
+            assert_eq!(skill_capacity.total_hours, Work::from_hours(32.0));
+
+            let technician_capacity = &skill_capacity.technicians[&TechnicianId::new(1001)];
+            assert_eq!(technician_capacity.days[&period_start], Work::from_hours(16.0));
+            assert_eq!(
+                technician_capacity.days[&NaiveDate::from_ymd_opt(2025, 1, 14).unwrap()],
+                Work::from_hours(16.0)
+            );
+            assert_eq!(technician_capacity.days.len(), 2);
+        }
+
+        let missing_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        assert_eq!(
+            schedule_graph.capacity_calendar(&[missing_period]),
+            Err(ScheduleGraphErrors::PeriodMissing {
+                period: missing_period,
+                periods: vec![period],
+            })
+        );
+    }
+
+    #[test]
+    fn test_planned_work_demand()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let period_start = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(period_start);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            period_start,
+            vec![
+                Activity::new(ActivityNumber::new(10), 2, Skill::MTN_MECH).with_work(Work::from_hours(12.0)),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_ELEC).with_work(Work::from_hours(4.0)),
+                Activity::new(ActivityNumber::new(30), 1, Skill::MTN_ELEC),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334466).unwrap(),
+            period_start,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_work(Work::from_hours(6.0))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334477).unwrap(),
+            period_start,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_work(Work::from_hours(5.0))],
+        )
+        .unwrap()
+        .with_commitment(Commitment::Forecast);
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = period_start.and_hms_opt(8, 0, 0).unwrap();
+        let end = period.end_date().and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), period)
+            .unwrap();
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334477).unwrap(), next_period)
+            .unwrap();
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(14, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![period.end_date()],
+                shift,
+            )
+            .unwrap();
+
+        let planned_work_demand = schedule_graph.planned_work_demand(&[period, next_period]).unwrap();
+
+        assert_eq!(
+            planned_work_demand[&period],
+            BTreeMap::from([(Skill::MTN_MECH, Work::from_hours(12.0)), (Skill::MTN_ELEC, Work::from_hours(4.0))])
+        );
+        assert_eq!(
+            planned_work_demand[&next_period],
+            BTreeMap::from([(Skill::MTN_MECH, Work::from_hours(6.0))])
+        );
+
+        let forecast_work_demand = schedule_graph.forecast_work_demand(&[period, next_period]).unwrap();
+        assert_eq!(forecast_work_demand[&period], BTreeMap::new());
+        assert_eq!(
+            forecast_work_demand[&next_period],
+            BTreeMap::from([(Skill::MTN_MECH, Work::from_hours(5.0))])
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use scheduling_environment::Period;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::conflicts::ScheduleConflict;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::TechnicianId;
+
+/// What an optimizer run changed, written for the planners that have to
+/// review it. Build it with `ScheduleGraph::change_summary` and render it
+/// with `ChangeSummary::to_markdown`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChangeSummary
+{
+    /// Only periods where work orders moved in or out are present.
+    pub periods: BTreeMap<Period, PeriodChanges>,
+    /// Only technicians that gained or lost assignments are present.
+    pub technicians: BTreeMap<TechnicianId, TechnicianChanges>,
+    pub objective_before: f64,
+    pub objective_after: f64,
+    pub introduced_conflicts: Vec<ScheduleConflict>,
+    pub resolved_conflicts: Vec<ScheduleConflict>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PeriodChanges
+{
+    pub moved_in: BTreeSet<WorkOrderNumber>,
+    pub moved_out: BTreeSet<WorkOrderNumber>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TechnicianChanges
+{
+    pub added_assignments: usize,
+    pub removed_assignments: usize,
+    /// The work orders of the added and removed assignments.
+    pub work_orders: BTreeSet<WorkOrderNumber>,
+}
+
+impl ChangeSummary
+{
+    pub fn is_empty(&self) -> bool
+    {
+        self.periods.is_empty() && self.technicians.is_empty() && self.introduced_conflicts.is_empty() && self.resolved_conflicts.is_empty()
+    }
+
+    pub fn objective_delta(&self) -> f64
+    {
+        self.objective_after - self.objective_before
+    }
+
+    pub fn to_markdown(&self) -> String
+    {
+        let mut markdown = String::new();
+
+        writeln!(markdown, "# Schedule Changes\n").unwrap();
+        writeln!(
+            markdown,
+            "Objective: {:.1} -> {:.1} ({:+.1})",
+            self.objective_before,
+            self.objective_after,
+            self.objective_delta()
+        )
+        .unwrap();
+
+        writeln!(markdown, "\n## Periods\n").unwrap();
+        writeln!(markdown, "| Period | Moved in | Moved out |").unwrap();
+        writeln!(markdown, "|---|---|---|").unwrap();
+        for (period, period_changes) in &self.periods {
+            writeln!(
+                markdown,
+                "| {} | {} | {} |",
+                period.start_date(),
+                work_orders_to_string(&period_changes.moved_in),
+                work_orders_to_string(&period_changes.moved_out)
+            )
+            .unwrap();
+        }
+
+        writeln!(markdown, "\n## Technicians\n").unwrap();
+        writeln!(markdown, "| Technician | Added | Removed | Work orders |").unwrap();
+        writeln!(markdown, "|---|---|---|---|").unwrap();
+        for (technician_id, technician_changes) in &self.technicians {
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {} |",
+                technician_id,
+                technician_changes.added_assignments,
+                technician_changes.removed_assignments,
+                work_orders_to_string(&technician_changes.work_orders)
+            )
+            .unwrap();
+        }
+
+        writeln!(markdown, "\n## Conflicts\n").unwrap();
+        for conflict in &self.introduced_conflicts {
+            writeln!(markdown, "- Introduced: {}", conflict_to_string(conflict)).unwrap();
+        }
+        for conflict in &self.resolved_conflicts {
+            writeln!(markdown, "- Resolved: {}", conflict_to_string(conflict)).unwrap();
+        }
+
+        markdown
+    }
+}
+
+/// Public API to summarize the changes of a scheduler run.
+impl ScheduleGraph
+{
+    /// Summarizes the changes from `self`, the graph before the run, to
+    /// `after`. The `objective` is evaluated on both graphs, so any
+    /// scheduler can pass its own objective function.
+    pub fn change_summary(&self, after: &ScheduleGraph, objective: impl Fn(&ScheduleGraph) -> f64) -> ChangeSummary
+    {
+        let mut change_summary = ChangeSummary {
+            objective_before: objective(self),
+            objective_after: objective(after),
+            ..ChangeSummary::default()
+        };
+
+        let work_orders_before = self.work_orders_by_period();
+        let work_orders_after = after.work_orders_by_period();
+        let periods = work_orders_before.keys().chain(work_orders_after.keys()).collect::<BTreeSet<_>>();
+        for period in periods {
+            let before = work_orders_before.get(period).cloned().unwrap_or_default();
+            let after = work_orders_after.get(period).cloned().unwrap_or_default();
+            let period_changes = PeriodChanges {
+                moved_in: after.difference(&before).copied().collect(),
+                moved_out: before.difference(&after).copied().collect(),
+            };
+            if period_changes != PeriodChanges::default() {
+                change_summary.periods.insert(*period, period_changes);
+            }
+        }
+
+        let schedule_diff = self.diff(after);
+        for assignment in &schedule_diff.added_assignments {
+            for technician_id in &assignment.technicians {
+                let technician_changes = change_summary.technicians.entry(*technician_id).or_default();
+                technician_changes.added_assignments += 1;
+                technician_changes.work_orders.insert(assignment.work_order);
+            }
+        }
+        for assignment in &schedule_diff.removed_assignments {
+            for technician_id in &assignment.technicians {
+                let technician_changes = change_summary.technicians.entry(*technician_id).or_default();
+                technician_changes.removed_assignments += 1;
+                technician_changes.work_orders.insert(assignment.work_order);
+            }
+        }
+
+        let conflicts_before = self.detect_conflicts().into_iter().collect::<BTreeSet<_>>();
+        let conflicts_after = after.detect_conflicts().into_iter().collect::<BTreeSet<_>>();
+        change_summary.introduced_conflicts = conflicts_after.difference(&conflicts_before).cloned().collect();
+        change_summary.resolved_conflicts = conflicts_before.difference(&conflicts_after).cloned().collect();
+
+        change_summary
+    }
+
+    /// The work orders with an assignment in each period. An activity
+    /// assignment belongs to every period that contains one of its days.
+    fn work_orders_by_period(&self) -> BTreeMap<Period, BTreeSet<WorkOrderNumber>>
+    {
+        let periods = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Period(period) => Some(*period),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut work_orders_by_period = BTreeMap::<Period, BTreeSet<WorkOrderNumber>>::new();
+        for (edge_index, _) in self.edges() {
+            let Some(assignment) = self.assignment_view(edge_index) else {
+                continue;
+            };
+            for period in &periods {
+                if assignment.period == Some(*period) || assignment.days.iter().any(|day| period.contains(day)) {
+                    work_orders_by_period.entry(*period).or_default().insert(assignment.work_order);
+                }
+            }
+        }
+        work_orders_by_period
+    }
+}
+
+fn work_orders_to_string(work_orders: &BTreeSet<WorkOrderNumber>) -> String
+{
+    work_orders
+        .iter()
+        .map(|work_order_number| work_order_number.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn conflict_to_string(conflict: &ScheduleConflict) -> String
+{
+    match conflict {
+        ScheduleConflict::DoubleBooking {
+            technician_id,
+            first,
+            second,
+            start,
+            finish,
+        } => format!(
+            "technician {} is double booked on {}/{} and {}/{} from {} to {}",
+            technician_id, first.0, first.1, second.0, second.1, start, finish
+        ),
+        ScheduleConflict::ExcludedPeriod {
+            work_order_number, period, ..
+        } => format!(
+            "work order {} is assigned in the excluded period {}",
+            work_order_number,
+            period.start_date()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_change_summary()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = start_date.and_hms_opt(0, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(23, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        }
+
+        let shift = |start: u32, finish: u32| {
+            Shift::new(
+                NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(finish, 0, 0).unwrap(),
+                false,
+            )
+            .unwrap()
+        };
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), period)
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1002)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift(8, 12),
+            )
+            .unwrap();
+
+        // The run moves 1122334455 to the next period and overlaps the
+        // activity of 1122334466 for 1002.
+        let mut optimized_graph = schedule_graph.clone();
+        let assignment_id = optimized_graph.assignments_for_technician(TechnicianId::new(1001)).unwrap()[0].assignment_id;
+        optimized_graph.remove_assignment(assignment_id).unwrap();
+        optimized_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), next_period)
+            .unwrap();
+        optimized_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1002)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift(10, 14),
+            )
+            .unwrap();
+
+        let objective = |graph: &ScheduleGraph| graph.assignments_for_period(next_period).unwrap().len() as f64;
+        assert!(schedule_graph.change_summary(&schedule_graph, objective).is_empty());
+
+        let change_summary = schedule_graph.change_summary(&optimized_graph, objective);
+
+        assert_eq!(change_summary.objective_delta(), 1.0);
+        assert_eq!(change_summary.periods.len(), 1);
+        // 1122334455 keeps an activity assignment in the first period.
+        assert!(!change_summary.periods.contains_key(&period));
+        assert_eq!(
+            change_summary.periods[&next_period].moved_in,
+            [WorkOrderNumber::new(1122334455).unwrap()].into()
+        );
+        assert_eq!(change_summary.technicians[&TechnicianId::new(1001)].added_assignments, 1);
+        assert_eq!(change_summary.technicians[&TechnicianId::new(1001)].removed_assignments, 1);
+        assert_eq!(
+            change_summary.technicians[&TechnicianId::new(1002)].work_orders,
+            [WorkOrderNumber::new(1122334455).unwrap()].into()
+        );
+        assert_eq!(change_summary.introduced_conflicts.len(), 1);
+        assert!(change_summary.resolved_conflicts.is_empty());
+
+        let markdown = change_summary.to_markdown();
+        assert!(markdown.contains("Objective: 0.0 -> 1.0 (+1.0)"));
+        assert!(markdown.contains(&format!("| {} | 1122334455 |  |", next_period.start_date())));
+        assert!(markdown.contains("- Introduced: technician 1002 is double booked on 1122334455/10 and 1122334466/10"));
+
+        let reverse_summary = optimized_graph.change_summary(&schedule_graph, objective);
+        assert_eq!(reverse_summary.resolved_conflicts, change_summary.introduced_conflicts);
+    }
+}
@@ -0,0 +1,473 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeDelta;
+use scheduling_environment::SystemClock;
+use scheduling_environment::WallClock;
+
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+pub type ClaimId = u64;
+
+/// Hours of a technician that one planning domain has reserved, so that
+/// other graphs sharing the technician do not plan the same hours.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AvailabilityClaim
+{
+    pub claim_id: ClaimId,
+    /// The graph that holds the claim, e.g. "projects" or "maintenance".
+    pub graph_id: String,
+    pub technician_id: TechnicianId,
+    pub intervals: Vec<(NaiveDateTime, NaiveDateTime)>,
+    /// The claim is released automatically at this time unless it is
+    /// renewed, so a planning domain that stops responding cannot hold the
+    /// technician forever.
+    pub expires_at: NaiveDateTime,
+}
+
+impl AvailabilityClaim
+{
+    fn overlaps(&self, intervals: &[(NaiveDateTime, NaiveDateTime)]) -> bool
+    {
+        self.intervals
+            .iter()
+            .any(|(claimed_start, claimed_finish)| intervals.iter().any(|(start, finish)| start < claimed_finish && claimed_start < finish))
+    }
+}
+
+/// An assignment of a graph that overlaps hours claimed by another graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimConflict
+{
+    pub assignment_id: AssignmentId,
+    pub technician_id: TechnicianId,
+    pub claim_id: ClaimId,
+    pub graph_id: String,
+}
+
+/// Exchanges availability claims between independent graphs that share
+/// technicians.
+///
+/// Claiming never waits: a claim that overlaps an active claim of another
+/// graph is rejected with `ScheduleGraphErrors::ClaimConflict` and all
+/// intervals of a claim are taken at once. A graph therefore never holds
+/// some hours while waiting for others, which is what makes the protocol
+/// free of deadlocks. Expired claims are ignored and dropped by the next
+/// mutation of the registry.
+#[derive(Clone, Debug)]
+pub struct ClaimRegistry
+{
+    claims: BTreeMap<ClaimId, AvailabilityClaim>,
+    next_claim_id: ClaimId,
+    system_clock: Arc<dyn SystemClock>,
+}
+
+impl Default for ClaimRegistry
+{
+    fn default() -> Self
+    {
+        Self {
+            claims: BTreeMap::new(),
+            next_claim_id: ClaimId::default(),
+            system_clock: Arc::new(WallClock),
+        }
+    }
+}
+
+impl ClaimRegistry
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    pub fn set_system_clock(&mut self, system_clock: impl SystemClock + 'static)
+    {
+        self.system_clock = Arc::new(system_clock);
+    }
+
+    /// Claims the intervals of the technician for the graph until the time
+    /// to live has passed. Claims of the same graph may overlap.
+    pub fn claim(
+        &mut self,
+        graph_id: &str,
+        technician_id: TechnicianId,
+        intervals: Vec<(NaiveDateTime, NaiveDateTime)>,
+        time_to_live: TimeDelta,
+    ) -> Result<ClaimId, ScheduleGraphErrors>
+    {
+        self.expire();
+        self.check_claimable(graph_id, technician_id, &intervals)?;
+        Ok(self.insert(graph_id, technician_id, intervals, time_to_live))
+    }
+
+    /// Claims the hours of every technician of the activity assignment in
+    /// the graph. Either all technicians are claimed or none of them. Work
+    /// order assignments have no hours and claim nothing.
+    pub fn claim_assignment(
+        &mut self,
+        graph_id: &str,
+        schedule_graph: &ScheduleGraph,
+        assignment_id: AssignmentId,
+        time_to_live: TimeDelta,
+    ) -> Result<Vec<ClaimId>, ScheduleGraphErrors>
+    {
+        self.expire();
+        let assignment = schedule_graph.assignment(assignment_id)?;
+        let intervals = assignment.intervals();
+        if intervals.is_empty() {
+            return Ok(vec![]);
+        }
+        for technician_id in &assignment.technicians {
+            self.check_claimable(graph_id, *technician_id, &intervals)?;
+        }
+
+        Ok(assignment
+            .technicians
+            .iter()
+            .map(|technician_id| self.insert(graph_id, *technician_id, intervals.clone(), time_to_live))
+            .collect())
+    }
+
+    /// Extends an active claim to expire the time to live from now.
+    pub fn renew(&mut self, claim_id: ClaimId, time_to_live: TimeDelta) -> Result<(), ScheduleGraphErrors>
+    {
+        self.expire();
+        let expires_at = self.system_clock.now() + time_to_live;
+        let availability_claim = self.claims.get_mut(&claim_id).ok_or(ScheduleGraphErrors::ClaimMissing { claim_id })?;
+        availability_claim.expires_at = expires_at;
+        Ok(())
+    }
+
+    pub fn release(&mut self, claim_id: ClaimId) -> Result<AvailabilityClaim, ScheduleGraphErrors>
+    {
+        self.expire();
+        self.claims.remove(&claim_id).ok_or(ScheduleGraphErrors::ClaimMissing { claim_id })
+    }
+
+    /// Releases every claim of the graph, e.g. when its plan is discarded.
+    pub fn release_graph(&mut self, graph_id: &str) -> Vec<AvailabilityClaim>
+    {
+        self.expire();
+        let (released, kept) = std::mem::take(&mut self.claims)
+            .into_iter()
+            .partition::<BTreeMap<_, _>, _>(|(_, availability_claim)| availability_claim.graph_id == graph_id);
+        self.claims = kept;
+        released.into_values().collect()
+    }
+
+    /// The active claims, ordered by `ClaimId`.
+    pub fn claims(&self) -> impl Iterator<Item = &AvailabilityClaim>
+    {
+        let now = self.system_clock.now();
+        self.claims.values().filter(move |availability_claim| now < availability_claim.expires_at)
+    }
+
+    fn check_claimable(
+        &self,
+        graph_id: &str,
+        technician_id: TechnicianId,
+        intervals: &[(NaiveDateTime, NaiveDateTime)],
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        match self.claims().find(|availability_claim| {
+            availability_claim.graph_id != graph_id && availability_claim.technician_id == technician_id && availability_claim.overlaps(intervals)
+        }) {
+            Some(availability_claim) => Err(ScheduleGraphErrors::ClaimConflict {
+                technician_id,
+                claim_id: availability_claim.claim_id,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn insert(
+        &mut self,
+        graph_id: &str,
+        technician_id: TechnicianId,
+        intervals: Vec<(NaiveDateTime, NaiveDateTime)>,
+        time_to_live: TimeDelta,
+    ) -> ClaimId
+    {
+        let claim_id = self.next_claim_id;
+        self.next_claim_id += 1;
+        self.claims.insert(
+            claim_id,
+            AvailabilityClaim {
+                claim_id,
+                graph_id: graph_id.to_string(),
+                technician_id,
+                intervals,
+                expires_at: self.system_clock.now() + time_to_live,
+            },
+        );
+        claim_id
+    }
+
+    fn expire(&mut self)
+    {
+        let now = self.system_clock.now();
+        self.claims.retain(|_, availability_claim| now < availability_claim.expires_at);
+    }
+}
+
+/// Public API to check the plan of a graph against the claims of other
+/// graphs.
+impl ScheduleGraph
+{
+    /// The activity assignments of this graph that overlap active claims of
+    /// other graphs, ordered by `AssignmentId`. The graph is identified by
+    /// the `graph_id` that it claims with.
+    pub fn claim_conflicts(&self, claim_registry: &ClaimRegistry, graph_id: &str) -> Vec<ClaimConflict>
+    {
+        let mut assignments = self
+            .edges()
+            .filter_map(|(edge_index, _)| self.assignment_view(edge_index))
+            .collect::<Vec<_>>();
+        assignments.sort_by_key(|assignment| assignment.assignment_id);
+
+        let mut claim_conflicts = vec![];
+        for assignment in assignments {
+            let intervals = assignment.intervals();
+            for technician_id in &assignment.technicians {
+                claim_conflicts.extend(
+                    claim_registry
+                        .claims()
+                        .filter(|availability_claim| {
+                            availability_claim.graph_id != graph_id
+                                && availability_claim.technician_id == *technician_id
+                                && availability_claim.overlaps(&intervals)
+                        })
+                        .map(|availability_claim| ClaimConflict {
+                            assignment_id: assignment.assignment_id,
+                            technician_id: *technician_id,
+                            claim_id: availability_claim.claim_id,
+                            graph_id: availability_claim.graph_id.clone(),
+                        }),
+                );
+            }
+        }
+        claim_conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::FixedClock;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::ClaimConflict;
+    use super::ClaimRegistry;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    fn schedule_graph(start_date: NaiveDate, work_order_number: WorkOrderNumber) -> ScheduleGraph
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+        let work_order = WorkOrder::new(
+            work_order_number,
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = (start_date + Duration::days(13)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        schedule_graph
+    }
+
+    #[test]
+    fn test_claim_registry()
+    {
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let mut claim_registry = ClaimRegistry::new();
+        let now = start_date.and_hms_opt(0, 0, 0).unwrap();
+        claim_registry.set_system_clock(FixedClock(now));
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let mut projects = schedule_graph(start_date, WorkOrderNumber::new(1122334455).unwrap());
+        let project_assignment_id = projects
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift,
+            )
+            .unwrap();
+        let mut maintenance = schedule_graph(start_date, WorkOrderNumber::new(1122334466).unwrap());
+        let maintenance_assignment_id = maintenance
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift,
+            )
+            .unwrap();
+
+        let claim_ids = claim_registry
+            .claim_assignment("projects", &projects, project_assignment_id, Duration::hours(1))
+            .unwrap();
+        assert_eq!(claim_ids.len(), 1);
+        assert_eq!(
+            claim_registry.claim_assignment("maintenance", &maintenance, maintenance_assignment_id, Duration::hours(1)),
+            Err(ScheduleGraphErrors::ClaimConflict {
+                technician_id: TechnicianId::new(1001),
+                claim_id: claim_ids[0],
+            })
+        );
+        assert_eq!(
+            maintenance.claim_conflicts(&claim_registry, "maintenance"),
+            vec![ClaimConflict {
+                assignment_id: maintenance_assignment_id,
+                technician_id: TechnicianId::new(1001),
+                claim_id: claim_ids[0],
+                graph_id: "projects".to_string(),
+            }]
+        );
+        assert!(projects.claim_conflicts(&claim_registry, "projects").is_empty());
+
+        // The afternoon is still free for maintenance.
+        let afternoon = (start_date.and_hms_opt(12, 0, 0).unwrap(), start_date.and_hms_opt(16, 0, 0).unwrap());
+        let afternoon_claim_id = claim_registry
+            .claim("maintenance", TechnicianId::new(1001), vec![afternoon], Duration::hours(3))
+            .unwrap();
+
+        // The claim of projects expires unless it is renewed.
+        claim_registry.set_system_clock(FixedClock(now + Duration::hours(2)));
+        assert_eq!(
+            claim_registry.renew(claim_ids[0], Duration::hours(1)),
+            Err(ScheduleGraphErrors::ClaimMissing { claim_id: claim_ids[0] })
+        );
+        assert!(maintenance.claim_conflicts(&claim_registry, "maintenance").is_empty());
+        let maintenance_claim_ids = claim_registry
+            .claim_assignment("maintenance", &maintenance, maintenance_assignment_id, Duration::hours(1))
+            .unwrap();
+        claim_registry.renew(afternoon_claim_id, Duration::hours(1)).unwrap();
+        assert_eq!(
+            claim_registry
+                .claims()
+                .map(|availability_claim| availability_claim.claim_id)
+                .collect::<Vec<_>>(),
+            vec![afternoon_claim_id, maintenance_claim_ids[0]]
+        );
+
+        assert_eq!(claim_registry.release_graph("maintenance").len(), 2);
+        assert_eq!(
+            claim_registry.release(afternoon_claim_id),
+            Err(ScheduleGraphErrors::ClaimMissing {
+                claim_id: afternoon_claim_id
+            })
+        );
+        assert!(
+            claim_registry
+                .claim_assignment("projects", &projects, project_assignment_id, Duration::hours(1))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_claim_assignment_claims_all_or_nothing()
+    {
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let mut claim_registry = ClaimRegistry::new();
+        claim_registry.set_system_clock(FixedClock(start_date.and_hms_opt(0, 0, 0).unwrap()));
+
+        let mut maintenance = schedule_graph(start_date, WorkOrderNumber::new(1122334466).unwrap());
+        let work_order_number = WorkOrderNumber::new(1122334477).unwrap();
+        let work_order = WorkOrder::new(
+            work_order_number,
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 2, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        maintenance.add_work_order(&work_order).unwrap();
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = (start_date + Duration::days(13)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1002))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        maintenance.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let assignment_id = maintenance
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001), TechnicianId::new(1002)],
+                work_order_number,
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift,
+            )
+            .unwrap();
+
+        // Projects holds the second technician, so the first one is not
+        // claimed either.
+        let morning = (start_date.and_hms_opt(9, 0, 0).unwrap(), start_date.and_hms_opt(10, 0, 0).unwrap());
+        let claim_id = claim_registry
+            .claim("projects", TechnicianId::new(1002), vec![morning], Duration::hours(1))
+            .unwrap();
+        assert_eq!(
+            claim_registry.claim_assignment("maintenance", &maintenance, assignment_id, Duration::hours(1)),
+            Err(ScheduleGraphErrors::ClaimConflict {
+                technician_id: TechnicianId::new(1002),
+                claim_id,
+            })
+        );
+        assert_eq!(
+            claim_registry
+                .claims()
+                .map(|availability_claim| availability_claim.claim_id)
+                .collect::<Vec<_>>(),
+            vec![claim_id]
+        );
+
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+            maintenance
+                .unassign_activity(technician_id, work_order_number, ActivityNumber::new(10))
+                .unwrap();
+        }
+        assert_eq!(
+            claim_registry.claim_assignment("maintenance", &maintenance, assignment_id, Duration::hours(1)),
+            Err(ScheduleGraphErrors::AssignmentMissing { assignment_id })
+        );
+    }
+}
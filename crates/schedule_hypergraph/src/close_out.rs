@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::baseline::ScheduleAdherence;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+/// What happened to a work order that was assigned in a closed period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CloseOutDecision
+{
+    /// The work is done and its actual hours are recorded in the skill-hour
+    /// ledger.
+    Completed,
+    /// The technicians of the work order are assigned to it again in the
+    /// later period.
+    CarryOver(Period),
+    /// The work order is left unassigned for the planners to place again.
+    ReturnToBacklog,
+}
+
+/// The record of a closed period. Once a period has a `PeriodCloseOut` it is
+/// a previous period.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeriodCloseOut
+{
+    pub completed: Vec<WorkOrderNumber>,
+    pub carried_over: BTreeMap<WorkOrderNumber, Period>,
+    pub returned_to_backlog: Vec<WorkOrderNumber>,
+    /// The actual hours of each skill from the skill-hour ledger.
+    pub actual_hours: BTreeMap<Skill, Work>,
+    /// The adherence to the baseline of the period, if one was committed.
+    /// Only baseline assignments of completed work orders are executed as
+    /// planned.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub schedule_adherence: Option<ScheduleAdherence>,
+}
+
+/// Public API for the end-of-period close-out.
+impl ScheduleGraph
+{
+    /// Closes the period. Every work order assigned in the period needs a
+    /// `CloseOutDecision`, otherwise nothing is changed and
+    /// `ScheduleGraphErrors::CloseOutDecisionMissing` lists the work orders
+    /// without one. Decisions for work orders that are not assigned in the
+    /// period are ignored.
+    ///
+    /// The assignments of the closed period are kept as history. Like the
+    /// skill-hour ledger, the close-out itself is not part of the undo
+    /// history.
+    pub fn close_period(
+        &mut self,
+        period: Period,
+        close_out_decisions: &BTreeMap<WorkOrderNumber, CloseOutDecision>,
+    ) -> Result<&PeriodCloseOut, ScheduleGraphErrors>
+    {
+        if self.period_close_outs().contains_key(&period) {
+            return Err(ScheduleGraphErrors::PeriodClosed { period });
+        }
+
+        let mut technicians = BTreeMap::<WorkOrderNumber, BTreeSet<TechnicianId>>::new();
+        for assignment in self.assignments_for_period(period)? {
+            technicians.entry(assignment.work_order).or_default().extend(assignment.technicians);
+        }
+
+        let missing_decisions = technicians
+            .keys()
+            .filter(|work_order_number| !close_out_decisions.contains_key(work_order_number))
+            .copied()
+            .collect::<Vec<_>>();
+        if !missing_decisions.is_empty() {
+            return Err(ScheduleGraphErrors::CloseOutDecisionMissing {
+                work_order_numbers: missing_decisions,
+            });
+        }
+
+        let mut period_close_out = PeriodCloseOut {
+            schedule_adherence: self.schedule_adherence_by(period, |assignment| {
+                close_out_decisions.get(&assignment.work_order) == Some(&CloseOutDecision::Completed)
+            }),
+            ..PeriodCloseOut::default()
+        };
+        for skill in self.nodes().iter().filter_map(|node| match node {
+            Node::Skill(skill) => Some(*skill),
+            _ => None,
+        }) {
+            let actual_hours = self.skill_hour_ledger(period, skill, None)?.actual_hours;
+            period_close_out.actual_hours.insert(skill, actual_hours);
+        }
+
+        self.transaction(|graph| {
+            graph.journaled(|graph| {
+                for (work_order_number, technician_ids) in &technicians {
+                    match close_out_decisions[work_order_number] {
+                        CloseOutDecision::Completed => period_close_out.completed.push(*work_order_number),
+                        CloseOutDecision::CarryOver(next_period) => {
+                            if next_period <= period {
+                                return Err(ScheduleGraphErrors::CarryOverBackwards);
+                            }
+                            for technician_id in technician_ids {
+                                graph.add_assignment_work_order(*technician_id, *work_order_number, next_period)?;
+                            }
+                            period_close_out.carried_over.insert(*work_order_number, next_period);
+                        }
+                        CloseOutDecision::ReturnToBacklog => period_close_out.returned_to_backlog.push(*work_order_number),
+                    }
+                }
+                Ok(())
+            })
+        })?;
+
+        Ok(self.period_close_outs_mut().entry(period).or_insert(period_close_out))
+    }
+
+    pub fn period_close_out(&self, period: Period) -> Option<&PeriodCloseOut>
+    {
+        self.period_close_outs().get(&period)
+    }
+
+    pub fn is_period_closed(&self, period: Period) -> bool
+    {
+        self.period_close_outs().contains_key(&period)
+    }
+
+    /// The number of closed periods that the work order was carried over
+    /// from.
+    pub fn carry_over_count(&self, work_order_number: WorkOrderNumber) -> usize
+    {
+        self.period_close_outs()
+            .values()
+            .filter(|period_close_out| period_close_out.carried_over.contains_key(&work_order_number))
+            .count()
+    }
+
+    /// Work orders that have been carried over at least
+    /// `minimum_carry_overs` times with their carry-over count, sorted by
+    /// the count with the chronic slippers first.
+    pub fn chronic_slippers(&self, minimum_carry_overs: usize) -> Vec<(WorkOrderNumber, usize)>
+    {
+        let mut carry_over_counts = BTreeMap::<WorkOrderNumber, usize>::new();
+        for period_close_out in self.period_close_outs().values() {
+            for work_order_number in period_close_out.carried_over.keys() {
+                *carry_over_counts.entry(*work_order_number).or_default() += 1;
+            }
+        }
+
+        let mut chronic_slippers = carry_over_counts
+            .into_iter()
+            .filter(|(_, carry_over_count)| *carry_over_count >= minimum_carry_overs)
+            .collect::<Vec<_>>();
+        chronic_slippers.sort_by_key(|(work_order_number, carry_over_count)| (std::cmp::Reverse(*carry_over_count), *work_order_number));
+        chronic_slippers
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::CloseOutDecision;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_chronic_slippers()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let mut periods = vec![Period::from_start_date(start_date)];
+        for _ in 0..3 {
+            periods.push(Period::from_start_date(periods.last().unwrap().end_date()));
+        }
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        for period in &periods {
+            schedule_graph.add_period(*period).unwrap();
+        }
+
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = start_date.and_hms_opt(8, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), periods[0])
+            .unwrap();
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), periods[0])
+            .unwrap();
+
+        // 1122334455 slips every period, 1122334466 slips once.
+        for (position, period) in periods[..3].iter().enumerate() {
+            let mut close_out_decisions = BTreeMap::from([(
+                WorkOrderNumber::new(1122334455).unwrap(),
+                CloseOutDecision::CarryOver(periods[position + 1]),
+            )]);
+            if position == 0 {
+                close_out_decisions.insert(WorkOrderNumber::new(1122334466).unwrap(), CloseOutDecision::CarryOver(periods[1]));
+            } else if position == 1 {
+                close_out_decisions.insert(WorkOrderNumber::new(1122334466).unwrap(), CloseOutDecision::Completed);
+            }
+            schedule_graph.close_period(*period, &close_out_decisions).unwrap();
+        }
+
+        assert_eq!(schedule_graph.carry_over_count(WorkOrderNumber::new(1122334455).unwrap()), 3);
+        assert_eq!(schedule_graph.carry_over_count(WorkOrderNumber::new(1122334466).unwrap()), 1);
+        assert_eq!(
+            schedule_graph.chronic_slippers(1),
+            vec![
+                (WorkOrderNumber::new(1122334455).unwrap(), 3),
+                (WorkOrderNumber::new(1122334466).unwrap(), 1)
+            ]
+        );
+        assert_eq!(schedule_graph.chronic_slippers(2), vec![(WorkOrderNumber::new(1122334455).unwrap(), 3)]);
+    }
+
+    #[test]
+    fn test_close_period()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = start_date.and_hms_opt(8, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            schedule_graph
+                .add_assignment_work_order(TechnicianId::new(1001), work_order_number, period)
+                .unwrap();
+        }
+        schedule_graph
+            .record_actual_skill_hours(period, Skill::MTN_MECH, Work::from_hours(6.0), period.end_date())
+            .unwrap();
+
+        let mut close_out_decisions = BTreeMap::from([
+            (WorkOrderNumber::new(1122334455).unwrap(), CloseOutDecision::Completed),
+            (WorkOrderNumber::new(1122334466).unwrap(), CloseOutDecision::CarryOver(next_period)),
+        ]);
+        assert_eq!(
+            schedule_graph.close_period(period, &close_out_decisions),
+            Err(ScheduleGraphErrors::CloseOutDecisionMissing {
+                work_order_numbers: vec![WorkOrderNumber::new(1122334477).unwrap()],
+            })
+        );
+
+        close_out_decisions.insert(WorkOrderNumber::new(1122334477).unwrap(), CloseOutDecision::CarryOver(period));
+        assert_eq!(
+            schedule_graph.close_period(period, &close_out_decisions),
+            Err(ScheduleGraphErrors::CarryOverBackwards)
+        );
+        assert_eq!(schedule_graph.assignments_for_period(next_period), Ok(vec![]));
+
+        close_out_decisions.insert(WorkOrderNumber::new(1122334477).unwrap(), CloseOutDecision::ReturnToBacklog);
+        let period_close_out = schedule_graph.close_period(period, &close_out_decisions).unwrap().clone();
+
+        assert_eq!(period_close_out.completed, vec![WorkOrderNumber::new(1122334455).unwrap()]);
+        assert_eq!(
+            period_close_out.carried_over,
+            BTreeMap::from([(WorkOrderNumber::new(1122334466).unwrap(), next_period)])
+        );
+        assert_eq!(period_close_out.returned_to_backlog, vec![WorkOrderNumber::new(1122334477).unwrap()]);
+        assert_eq!(period_close_out.actual_hours[&Skill::MTN_MECH], Work::from_hours(6.0));
+
+        let carried_over_assignments = schedule_graph.assignments_for_period(next_period).unwrap();
+        assert_eq!(carried_over_assignments.len(), 1);
+        assert_eq!(carried_over_assignments[0].work_order, WorkOrderNumber::new(1122334466).unwrap());
+        assert_eq!(carried_over_assignments[0].technicians, vec![TechnicianId::new(1001)]);
+
+        assert!(schedule_graph.is_period_closed(period));
+        assert_eq!(schedule_graph.period_close_out(period), Some(&period_close_out));
+        assert_eq!(
+            schedule_graph.close_period(period, &close_out_decisions),
+            Err(ScheduleGraphErrors::PeriodClosed { period })
+        );
+
+        let unknown_period = Period::from_start_date(next_period.end_date());
+        assert_eq!(
+            schedule_graph.close_period(unknown_period, &BTreeMap::new()),
+            Err(ScheduleGraphErrors::PeriodMissing {
+                period: unknown_period,
+                periods: vec![period, next_period],
+            })
+        );
+        assert!(!schedule_graph.is_period_closed(unknown_period));
+    }
+}
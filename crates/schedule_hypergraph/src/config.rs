@@ -0,0 +1,95 @@
+use chrono::NaiveDate;
+use chrono::TimeDelta;
+use scheduling_environment::Period;
+use scheduling_environment::PeriodLength;
+
+use crate::conflicts::ConflictTolerance;
+use crate::labor_rules::LaborRules;
+use crate::safety::SafetyPolicy;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The settings of a site that the graph is planned for. The config is
+/// stored on the graph and serialized with it, so that every subsystem
+/// reads the same settings instead of taking them per call.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct GraphConfig
+{
+    /// The length of the periods that the graph creates itself, see
+    /// `ScheduleGraph::add_period_starting`. Periods that are added
+    /// directly keep their own length.
+    pub period_length: PeriodLength,
+    /// The local time of the site minus UTC. Days, shifts and
+    /// availabilities are in local time, while the `SystemClock` is in UTC.
+    pub utc_offset: TimeDelta,
+    pub labor_rules: LaborRules,
+    /// See `ScheduleGraph::set_strict_availability`.
+    pub strict_availability: bool,
+    /// The last frozen day, see `ScheduleGraph::freeze_until`.
+    pub frozen_until: Option<NaiveDate>,
+    /// The first day of the rolling horizon, see
+    /// `ScheduleGraph::roll_horizon`.
+    pub horizon_start: Option<NaiveDate>,
+    pub conflict_tolerance: ConflictTolerance,
+    pub safety_policy: SafetyPolicy,
+}
+
+/// Public API for the parts of the graph that depend on the `GraphConfig`.
+impl ScheduleGraph
+{
+    /// The current day at the site.
+    pub fn today(&self) -> NaiveDate
+    {
+        (self.system_clock().now() + self.config().utc_offset).date()
+    }
+
+    /// Adds the period of `GraphConfig::period_length` that starts on the
+    /// day.
+    pub fn add_period_starting(&mut self, start_date: NaiveDate) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.add_period(Period::new(start_date, self.config().period_length))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::TimeDelta;
+    use scheduling_environment::FixedClock;
+    use scheduling_environment::Period;
+    use scheduling_environment::PeriodLength;
+
+    use super::GraphConfig;
+    use crate::schedule_graph::ScheduleGraph;
+
+    #[test]
+    fn test_graph_config()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        assert_eq!(schedule_graph.config(), &GraphConfig::default());
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.set_config(GraphConfig {
+            period_length: PeriodLength::Weekly,
+            utc_offset: TimeDelta::hours(-6),
+            ..GraphConfig::default()
+        });
+        schedule_graph.set_system_clock(FixedClock(start_date.and_hms_opt(3, 0, 0).unwrap()));
+        assert_eq!(schedule_graph.today(), start_date.pred_opt().unwrap());
+
+        schedule_graph.add_period_starting(start_date).unwrap();
+        assert_eq!(
+            schedule_graph.period_days(&Period::new(start_date, PeriodLength::Weekly)).unwrap().len(),
+            7
+        );
+
+        schedule_graph.set_strict_availability(true);
+        schedule_graph.freeze_until(start_date);
+        assert!(schedule_graph.config().strict_availability);
+        assert_eq!(schedule_graph.config().frozen_until, Some(start_date));
+        assert_eq!(schedule_graph.config().period_length, PeriodLength::Weekly);
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+/// A line of the daily feed from a time-writing system like SAP CATS.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Confirmation
+{
+    pub technician_id: TechnicianId,
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub day: NaiveDate,
+    pub hours: Work,
+    /// The activity is finished and no more work is expected on it.
+    pub final_confirmation: bool,
+}
+
+/// The payload of an `EdgeType::ActualWork` hyperedge.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActualWork
+{
+    pub hours: Work,
+    pub final_confirmation: bool,
+}
+
+/// A day where the confirmed hours of a technician on an activity differ
+/// from the planned hours. Unplanned work has no planned hours and missing
+/// work has no confirmed hours.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfirmationMismatch
+{
+    pub technician_id: TechnicianId,
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub day: NaiveDate,
+    pub planned_hours: Work,
+    pub confirmed_hours: Work,
+}
+
+/// Public API for the actual work that technicians confirm.
+impl ScheduleGraph
+{
+    /// Adds an `EdgeType::ActualWork` for each confirmation. A final
+    /// confirmation closes the activity, after which it can neither be
+    /// assigned nor confirmed.
+    ///
+    /// The confirmations are validated together and either all or none of
+    /// them are added.
+    pub fn ingest_confirmations(&mut self, confirmations: &[Confirmation]) -> Result<Vec<EdgeIndex>, ScheduleGraphErrors>
+    {
+        let mut closed_in_feed = vec![];
+        let mut actual_work_nodes = vec![];
+        for confirmation in confirmations {
+            if confirmation.hours <= Work::ZERO {
+                return Err(ScheduleGraphErrors::ConfirmationInvalid);
+            }
+            let activity_node_index = self.activity_node_index(confirmation.work_order_number, confirmation.activity_number)?;
+            let technician_node_index = self.technician_node_index(confirmation.technician_id)?;
+            let day_node_index = self.day_node_index(confirmation.day)?;
+            if self.activity_closed(activity_node_index) || closed_in_feed.contains(&activity_node_index) {
+                return Err(ScheduleGraphErrors::ActivityClosed {
+                    work_order_number: confirmation.work_order_number,
+                    activity_number: confirmation.activity_number,
+                });
+            }
+            if confirmation.final_confirmation {
+                closed_in_feed.push(activity_node_index);
+            }
+            actual_work_nodes.push(vec![activity_node_index, technician_node_index, day_node_index]);
+        }
+
+        Ok(self.journaled(|graph| {
+            confirmations
+                .iter()
+                .zip(actual_work_nodes)
+                .map(|(confirmation, nodes)| {
+                    let actual_work = ActualWork {
+                        hours: confirmation.hours,
+                        final_confirmation: confirmation.final_confirmation,
+                    };
+                    graph.add_edge(EdgeType::ActualWork(actual_work), nodes)
+                })
+                .collect()
+        }))
+    }
+
+    /// The confirmed hours of the activity, summed over its technicians.
+    pub fn actual_hours(&self, work_order_number: WorkOrderNumber, activity_number: ActivityNumber) -> Result<Work, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+        Ok(self.actual_work_edges(activity_node_index).map(|actual_work| actual_work.hours).sum())
+    }
+
+    pub fn is_activity_closed(&self, work_order_number: WorkOrderNumber, activity_number: ActivityNumber) -> Result<bool, ScheduleGraphErrors>
+    {
+        Ok(self.activity_closed(self.activity_node_index(work_order_number, activity_number)?))
+    }
+
+    /// Compares the confirmed hours with the planned hours of the activity
+    /// assignments per technician, activity and day, for the days up to and
+    /// including `through`. Later days are not expected to be confirmed yet.
+    pub fn confirmation_mismatches(&self, through: NaiveDate) -> Vec<ConfirmationMismatch>
+    {
+        type Key = (TechnicianId, WorkOrderNumber, ActivityNumber, NaiveDate);
+        let mut hours = BTreeMap::<Key, (Work, Work)>::new();
+
+        for assignment in self.edges().filter_map(|(edge_index, _)| self.assignment_view(edge_index)) {
+            let (Some(activity_number), Some(shift)) = (assignment.activity, assignment.shift) else {
+                continue;
+            };
+            let planned_hours = Work::from_duration(shift.duration());
+            for technician_id in &assignment.technicians {
+                for day in assignment.days.iter().filter(|day| **day <= through) {
+                    hours.entry((*technician_id, assignment.work_order, activity_number, *day)).or_default().0 += planned_hours;
+                }
+            }
+        }
+
+        for (_, hyperedge) in self.edges() {
+            let EdgeType::ActualWork(actual_work) = hyperedge.edge_type() else {
+                continue;
+            };
+            let nodes = hyperedge.nodes();
+            if let (Node::Activity(activity), Node::Technician(technician_id), Node::Day(day)) =
+                (&self.nodes()[nodes[0]], &self.nodes()[nodes[1]], &self.nodes()[nodes[2]])
+                && *day <= through
+            {
+                hours
+                    .entry((*technician_id, activity.work_order_number(), activity.activity_number(), *day))
+                    .or_default()
+                    .1 += actual_work.hours;
+            }
+        }
+
+        hours
+            .into_iter()
+            .filter(|(_, (planned_hours, confirmed_hours))| planned_hours != confirmed_hours)
+            .map(
+                |((technician_id, work_order_number, activity_number, day), (planned_hours, confirmed_hours))| ConfirmationMismatch {
+                    technician_id,
+                    work_order_number,
+                    activity_number,
+                    day,
+                    planned_hours,
+                    confirmed_hours,
+                },
+            )
+            .collect()
+    }
+
+    pub(crate) fn activity_closed(&self, activity_node_index: NodeIndex) -> bool
+    {
+        self.actual_work_edges(activity_node_index)
+            .any(|actual_work| actual_work.final_confirmation)
+    }
+
+    fn actual_work_edges(&self, activity_node_index: NodeIndex) -> impl Iterator<Item = &ActualWork>
+    {
+        self.incidence_list()[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter_map(move |hyperedge| match hyperedge.edge_type() {
+                EdgeType::ActualWork(actual_work) if hyperedge.nodes()[0] == activity_node_index => Some(actual_work),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::Confirmation;
+    use super::ConfirmationMismatch;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_ingest_confirmations()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let tuesday = monday + Duration::days(1);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            monday,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = monday.and_hms_opt(6, 0, 0).unwrap();
+        let end = (monday + Duration::days(13)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday, tuesday],
+                shift,
+            )
+            .unwrap();
+
+        let confirmation = |activity_number, day, hours, final_confirmation| Confirmation {
+            technician_id: TechnicianId::new(1001),
+            work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            activity_number,
+            day,
+            hours,
+            final_confirmation,
+        };
+        assert_eq!(
+            schedule_graph.ingest_confirmations(&[
+                confirmation(ActivityNumber::new(10), monday, Work::from_hours(8.0), false),
+                confirmation(ActivityNumber::new(10), monday, Work::from_hours(-1.0), false)
+            ]),
+            Err(ScheduleGraphErrors::ConfirmationInvalid)
+        );
+        assert_eq!(
+            schedule_graph.ingest_confirmations(&[
+                confirmation(ActivityNumber::new(10), monday, Work::from_hours(8.0), true),
+                confirmation(ActivityNumber::new(10), tuesday, Work::from_hours(2.0), false)
+            ]),
+            Err(ScheduleGraphErrors::ActivityClosed {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                activity_number: ActivityNumber::new(10),
+            })
+        );
+        assert_eq!(
+            schedule_graph.actual_hours(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+            Ok(Work::ZERO)
+        );
+
+        schedule_graph
+            .ingest_confirmations(&[
+                confirmation(ActivityNumber::new(10), monday, Work::from_hours(8.0), false),
+                confirmation(ActivityNumber::new(10), tuesday, Work::from_hours(5.0), true),
+                confirmation(ActivityNumber::new(20), tuesday, Work::from_hours(3.0), false),
+            ])
+            .unwrap();
+        assert_eq!(
+            schedule_graph.actual_hours(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+            Ok(Work::from_hours(13.0))
+        );
+        assert_eq!(
+            schedule_graph.is_activity_closed(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+            Ok(true)
+        );
+        assert_eq!(
+            schedule_graph.is_activity_closed(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(20)),
+            Ok(false)
+        );
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday + Duration::days(2)],
+                shift
+            ),
+            Err(ScheduleGraphErrors::ActivityClosed {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                activity_number: ActivityNumber::new(10),
+            })
+        );
+
+        assert!(schedule_graph.confirmation_mismatches(monday).is_empty());
+        assert_eq!(
+            schedule_graph.confirmation_mismatches(tuesday),
+            vec![
+                ConfirmationMismatch {
+                    technician_id: TechnicianId::new(1001),
+                    work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                    activity_number: ActivityNumber::new(10),
+                    day: tuesday,
+                    planned_hours: Work::from_hours(8.0),
+                    confirmed_hours: Work::from_hours(5.0),
+                },
+                ConfirmationMismatch {
+                    technician_id: TechnicianId::new(1001),
+                    work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                    activity_number: ActivityNumber::new(20),
+                    day: tuesday,
+                    planned_hours: Work::ZERO,
+                    confirmed_hours: Work::from_hours(3.0),
+                },
+            ]
+        );
+        assert!(schedule_graph.validate().is_valid());
+    }
+}
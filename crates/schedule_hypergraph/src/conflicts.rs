@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+use chrono::TimeDelta;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::TechnicianId;
+
+/// A breach that has to be resolved before a schedule is published.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScheduleConflict
+{
+    /// The technician is assigned to two different activities whose shifts
+    /// overlap between `start` and `finish`.
+    DoubleBooking
+    {
+        technician_id: TechnicianId,
+        first: (WorkOrderNumber, ActivityNumber),
+        second: (WorkOrderNumber, ActivityNumber),
+        start: NaiveDateTime,
+        finish: NaiveDateTime,
+    },
+    /// The work order, or one of its activities, is assigned in a period
+    /// that the work order is excluded from.
+    ExcludedPeriod
+    {
+        work_order_number: WorkOrderNumber,
+        activity_number: Option<ActivityNumber>,
+        period: Period,
+    },
+}
+
+/// The double-booking that a site accepts as planned efficiency loss. The
+/// `Skill` of an activity is the work center that executes it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConflictTolerance
+{
+    /// The overlap accepted in work centers without their own tolerance.
+    pub default_overlap: TimeDelta,
+    pub work_center_overlaps: BTreeMap<Skill, TimeDelta>,
+}
+
+impl ConflictTolerance
+{
+    /// Accepts overlaps up to a share of a day, e.g. `0.1` for 10%, in
+    /// every work center.
+    pub fn share_of_day(share: f64) -> Self
+    {
+        Self {
+            default_overlap: TimeDelta::seconds((TimeDelta::days(1).num_seconds() as f64 * share) as i64),
+            work_center_overlaps: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_work_center(mut self, work_center: Skill, overlap: TimeDelta) -> Self
+    {
+        self.work_center_overlaps.insert(work_center, overlap);
+        self
+    }
+
+    pub fn overlap(&self, work_center: Skill) -> TimeDelta
+    {
+        self.work_center_overlaps.get(&work_center).copied().unwrap_or(self.default_overlap)
+    }
+}
+
+/// The conflicts of the graph split by whether they block publishing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConflictReport
+{
+    pub hard: Vec<ScheduleConflict>,
+    /// Double-bookings within the `ConflictTolerance`.
+    pub soft: Vec<ScheduleConflict>,
+}
+
+impl ConflictReport
+{
+    pub fn is_publishable(&self) -> bool
+    {
+        self.hard.is_empty()
+    }
+}
+
+/// Public API to validate the assignments before publishing a schedule.
+impl ScheduleGraph
+{
+    /// Returns every `ScheduleConflict` in the graph, sorted. An overlap is
+    /// reported once per technician and pair of activities for each pair of
+    /// overlapping shifts.
+    pub fn detect_conflicts(&self) -> Vec<ScheduleConflict>
+    {
+        self.detect_conflicts_with_tolerance(&ConflictTolerance::default()).hard
+    }
+
+    /// Like `detect_conflicts`, but a `ScheduleConflict::DoubleBooking` is
+    /// a soft conflict if the overlap is within the
+    /// `GraphConfig::conflict_tolerance` of the work centers of both
+    /// activities.
+    pub fn conflict_report(&self) -> ConflictReport
+    {
+        self.detect_conflicts_with_tolerance(&self.config().conflict_tolerance)
+    }
+
+    fn detect_conflicts_with_tolerance(&self, conflict_tolerance: &ConflictTolerance) -> ConflictReport
+    {
+        let mut conflicts = vec![];
+
+        type ShiftInterval = ((WorkOrderNumber, ActivityNumber), NaiveDateTime, NaiveDateTime);
+        let mut technician_intervals = BTreeMap::<TechnicianId, Vec<ShiftInterval>>::new();
+
+        for (edge_index, _) in self.edges() {
+            let Some(assignment) = self.assignment_view(edge_index) else {
+                continue;
+            };
+
+            let excluded_periods = self.periods_excluding(assignment.work_order).unwrap_or_default();
+            for period in excluded_periods {
+                let assigned_in_period = assignment.period == Some(period) || assignment.days.iter().any(|day| period.contains(day));
+                if assigned_in_period {
+                    conflicts.push(ScheduleConflict::ExcludedPeriod {
+                        work_order_number: assignment.work_order,
+                        activity_number: assignment.activity,
+                        period,
+                    });
+                }
+            }
+
+            let Some(activity_number) = assignment.activity else {
+                continue;
+            };
+            for technician_id in &assignment.technicians {
+                technician_intervals.entry(*technician_id).or_default().extend(
+                    assignment
+                        .intervals()
+                        .into_iter()
+                        .map(|(start, finish)| ((assignment.work_order, activity_number), start, finish)),
+                );
+            }
+        }
+
+        for (technician_id, intervals) in technician_intervals {
+            for (position, (activity, start, finish)) in intervals.iter().enumerate() {
+                for (other_activity, other_start, other_finish) in &intervals[position + 1..] {
+                    if activity == other_activity || !(start < other_finish && other_start < finish) {
+                        continue;
+                    }
+                    conflicts.push(ScheduleConflict::DoubleBooking {
+                        technician_id,
+                        first: *activity.min(other_activity),
+                        second: *activity.max(other_activity),
+                        start: *start.max(other_start),
+                        finish: *finish.min(other_finish),
+                    });
+                }
+            }
+        }
+
+        conflicts.sort();
+        conflicts.dedup();
+
+        let mut conflict_report = ConflictReport::default();
+        for conflict in conflicts {
+            let tolerated = match &conflict {
+                ScheduleConflict::DoubleBooking {
+                    first,
+                    second,
+                    start,
+                    finish,
+                    ..
+                } => {
+                    *finish - *start
+                        <= self
+                            .tolerated_overlap(*first, conflict_tolerance)
+                            .min(self.tolerated_overlap(*second, conflict_tolerance))
+                }
+                ScheduleConflict::ExcludedPeriod { .. } => false,
+            };
+            if tolerated {
+                conflict_report.soft.push(conflict);
+            } else {
+                conflict_report.hard.push(conflict);
+            }
+        }
+        conflict_report
+    }
+
+    /// The smallest tolerance of the work centers of the activity.
+    fn tolerated_overlap(
+        &self,
+        (work_order_number, activity_number): (WorkOrderNumber, ActivityNumber),
+        conflict_tolerance: &ConflictTolerance,
+    ) -> TimeDelta
+    {
+        let Ok(activity_node_index) = self.activity_node_index(work_order_number, activity_number) else {
+            return TimeDelta::zero();
+        };
+        self.node_skills(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires))
+            .into_iter()
+            .map(|work_center| conflict_tolerance.overlap(work_center))
+            .min()
+            .unwrap_or(conflict_tolerance.default_overlap)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::ConflictTolerance;
+    use super::ScheduleConflict;
+    use crate::config::GraphConfig;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_detect_conflicts()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let tuesday = monday + Duration::days(1);
+        let period = Period::from_start_date(monday);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                monday,
+                vec![
+                    Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                    Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+                ],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = monday.and_hms_opt(0, 0, 0).unwrap();
+        let end = tuesday.and_hms_opt(23, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = |start: u32, finish: u32| {
+            Shift::new(
+                NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(finish, 0, 0).unwrap(),
+                false,
+            )
+            .unwrap()
+        };
+
+        // Consecutive shifts and shifts on different days do not conflict.
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift(7, 11),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+                vec![monday],
+                shift(11, 15),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(20),
+                vec![tuesday],
+                shift(7, 15),
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.detect_conflicts(), vec![]);
+
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift(9, 12),
+            )
+            .unwrap();
+        schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334466).unwrap(), &period).unwrap();
+
+        assert_eq!(
+            schedule_graph.detect_conflicts(),
+            vec![
+                ScheduleConflict::DoubleBooking {
+                    technician_id: TechnicianId::new(1001),
+                    first: (WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+                    second: (WorkOrderNumber::new(1122334466).unwrap(), ActivityNumber::new(10)),
+                    start: monday.and_hms_opt(9, 0, 0).unwrap(),
+                    finish: monday.and_hms_opt(11, 0, 0).unwrap(),
+                },
+                ScheduleConflict::DoubleBooking {
+                    technician_id: TechnicianId::new(1001),
+                    first: (WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(20)),
+                    second: (WorkOrderNumber::new(1122334466).unwrap(), ActivityNumber::new(10)),
+                    start: monday.and_hms_opt(11, 0, 0).unwrap(),
+                    finish: monday.and_hms_opt(12, 0, 0).unwrap(),
+                },
+                ScheduleConflict::ExcludedPeriod {
+                    work_order_number: WorkOrderNumber::new(1122334466).unwrap(),
+                    activity_number: Some(ActivityNumber::new(10)),
+                    period,
+                },
+                ScheduleConflict::ExcludedPeriod {
+                    work_order_number: WorkOrderNumber::new(1122334466).unwrap(),
+                    activity_number: Some(ActivityNumber::new(20)),
+                    period,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conflict_report()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            monday,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(30), 1, Skill::MTN_ELEC),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = monday.and_hms_opt(0, 0, 0).unwrap();
+        let end = monday.and_hms_opt(23, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .add_skill(Skill::MTN_ELEC)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = |start: (u32, u32), finish: (u32, u32)| {
+            Shift::new(
+                NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+                NaiveTime::from_hms_opt(finish.0, finish.1, 0).unwrap(),
+                false,
+            )
+            .unwrap()
+        };
+
+        // 10 and 20 overlap by two hours, 20 and 30 by three hours.
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift((7, 0), (11, 0)),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+                vec![monday],
+                shift((9, 0), (15, 0)),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(30),
+                vec![monday],
+                shift((12, 0), (18, 0)),
+            )
+            .unwrap();
+
+        let conflict_report = schedule_graph.conflict_report();
+        assert_eq!(conflict_report.hard, schedule_graph.detect_conflicts());
+        assert_eq!(conflict_report.hard.len(), 2);
+        assert!(conflict_report.soft.is_empty());
+
+        // 10% of a day is 2 hours and 24 minutes.
+        let mut config = GraphConfig {
+            conflict_tolerance: ConflictTolerance::share_of_day(0.1),
+            ..GraphConfig::default()
+        };
+        schedule_graph.set_config(config.clone());
+        let conflict_report = schedule_graph.conflict_report();
+        assert!(!conflict_report.is_publishable());
+        assert!(matches!(
+            conflict_report.soft[..],
+            [ScheduleConflict::DoubleBooking {
+                second,
+                ..
+            }] if second == (WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(20))
+        ));
+        assert!(matches!(
+            conflict_report.hard[..],
+            [ScheduleConflict::DoubleBooking {
+                second,
+                ..
+            }] if second == (WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(30))
+        ));
+
+        // Both work centers of an overlap have to tolerate it.
+        config.conflict_tolerance = config.conflict_tolerance.with_work_center(Skill::MTN_ELEC, TimeDelta::hours(3));
+        schedule_graph.set_config(config.clone());
+        assert!(!schedule_graph.conflict_report().is_publishable());
+        config.conflict_tolerance = config.conflict_tolerance.with_work_center(Skill::MTN_MECH, TimeDelta::hours(3));
+        schedule_graph.set_config(config);
+        let conflict_report = schedule_graph.conflict_report();
+        assert!(conflict_report.is_publishable());
+        assert_eq!(conflict_report.soft.len(), 2);
+    }
+}
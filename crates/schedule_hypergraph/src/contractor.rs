@@ -0,0 +1,423 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::ContractorPoolId;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The payload of an `EdgeType::Purchased` hyperedge. The purchased hours
+/// are paid whether they are used or not.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PurchasedHours
+{
+    pub hours: Work,
+    pub hourly_rate: f64,
+}
+
+impl PartialEq for PurchasedHours
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PurchasedHours {}
+
+impl PartialOrd for PurchasedHours
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PurchasedHours
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        self.hours.cmp(&other.hours).then(self.hourly_rate.total_cmp(&other.hourly_rate))
+    }
+}
+
+/// The payload of an `EdgeType::Contracted` hyperedge.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContractedHours
+{
+    pub hours: Work,
+}
+
+/// The hours of a contractor pool in one skill and period, see
+/// `ScheduleGraph::contractor_pool_usage`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractorPoolUsage
+{
+    pub contractor_pool_id: ContractorPoolId,
+    pub skill: Skill,
+    pub period: Period,
+    pub purchased_hours: Work,
+    pub contracted_hours: Work,
+    /// The purchased hours at the hourly rate.
+    pub cost: f64,
+}
+
+impl ContractorPoolUsage
+{
+    pub fn remaining_hours(&self) -> Work
+    {
+        self.purchased_hours - self.contracted_hours
+    }
+}
+
+/// Public API for external capacity. A contractor pool is a block of
+/// hours purchased per skill and period from a contractor, not a group of
+/// named technicians, so the work contracted to a pool is placed in a
+/// period instead of on days and shifts.
+///
+/// The planned work of an activity can be split between technicians and
+/// pools. The `CapacityCalendar` counts the purchased hours and the
+/// `ValidationReport` counts the contracted hours towards the planned work.
+impl ScheduleGraph
+{
+    pub fn add_contractor_pool(&mut self, contractor_pool_id: ContractorPoolId) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            if graph.contractor_pool_node_index(contractor_pool_id).is_ok() {
+                return Err(ScheduleGraphErrors::ContractorPoolDuplicate { contractor_pool_id });
+            }
+            Ok(graph.add_node(Node::ContractorPool(contractor_pool_id)))
+        })
+    }
+
+    /// Purchases hours of the skill in the period. An earlier purchase of
+    /// the pool for the skill and period is replaced, but never below the
+    /// hours that are already contracted.
+    pub fn purchase_contractor_hours(
+        &mut self,
+        contractor_pool_id: ContractorPoolId,
+        skill: Skill,
+        period: Period,
+        purchased_hours: PurchasedHours,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let contractor_pool_node_index = self.contractor_pool_node_index(contractor_pool_id)?;
+        let skill_node_index = self.skill_node_index(skill)?;
+        let period_node_index = self.period_node_index(period)?;
+        if purchased_hours.hours < self.contracted_hours(contractor_pool_node_index, skill, period) {
+            return Err(ScheduleGraphErrors::ContractorPoolExhausted);
+        }
+
+        self.journaled(|graph| {
+            if let Some((edge_index, _)) = graph.purchase(contractor_pool_node_index, skill_node_index, period_node_index) {
+                graph.remove_edge(edge_index)?;
+            }
+            Ok(graph.add_edge(
+                EdgeType::Purchased(purchased_hours),
+                vec![contractor_pool_node_index, skill_node_index, period_node_index],
+            ))
+        })
+    }
+
+    /// Contracts hours of the activity to the pool in the period. The pool
+    /// has to have enough purchased hours left in the skill of the
+    /// activity.
+    pub fn add_assignment_contractor_pool(
+        &mut self,
+        contractor_pool_id: ContractorPoolId,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        period: Period,
+        hours: Work,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let contractor_pool_node_index = self.contractor_pool_node_index(contractor_pool_id)?;
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+        let period_node_index = self.period_node_index(period)?;
+        self.check_frozen_days(&self.period_days(&period)?)?;
+
+        let required_skills = self.node_skills(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires));
+        for skill in &required_skills {
+            let purchased_hours = self
+                .purchase(contractor_pool_node_index, self.skill_node_index(*skill)?, period_node_index)
+                .map_or(Work::ZERO, |(_, purchased_hours)| purchased_hours.hours);
+            if self.contracted_hours(contractor_pool_node_index, *skill, period) + hours > purchased_hours {
+                return Err(ScheduleGraphErrors::ContractorPoolExhausted);
+            }
+        }
+
+        Ok(self.journaled(|graph| {
+            graph.add_edge(
+                EdgeType::Contracted(ContractedHours { hours }),
+                vec![activity_node_index, contractor_pool_node_index, period_node_index],
+            )
+        }))
+    }
+
+    /// The hours of the activity that are contracted to pools.
+    pub fn activity_contracted_hours(&self, work_order_number: WorkOrderNumber, activity_number: ActivityNumber)
+    -> Result<Work, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+        Ok(self.node_contracted_hours(activity_node_index))
+    }
+
+    /// The purchased and contracted hours of every pool in the periods,
+    /// ordered by pool, skill and period.
+    pub fn contractor_pool_usage(&self, periods: &[Period]) -> Vec<ContractorPoolUsage>
+    {
+        let mut contractor_pool_usage = vec![];
+        for (_, hyperedge) in self.edges() {
+            let EdgeType::Purchased(purchased_hours) = hyperedge.edge_type() else {
+                continue;
+            };
+            let (Node::ContractorPool(contractor_pool_id), Node::Skill(skill), Node::Period(period)) = (
+                &self.nodes()[hyperedge.nodes()[0]],
+                &self.nodes()[hyperedge.nodes()[1]],
+                &self.nodes()[hyperedge.nodes()[2]],
+            ) else {
+                continue;
+            };
+            if !periods.contains(period) {
+                continue;
+            }
+            contractor_pool_usage.push(ContractorPoolUsage {
+                contractor_pool_id: *contractor_pool_id,
+                skill: *skill,
+                period: *period,
+                purchased_hours: purchased_hours.hours,
+                contracted_hours: self.contracted_hours(hyperedge.nodes()[0], *skill, *period),
+                cost: purchased_hours.hours.hours() * purchased_hours.hourly_rate,
+            });
+        }
+        contractor_pool_usage.sort_by_key(|usage| (usage.contractor_pool_id, usage.skill, usage.period));
+        contractor_pool_usage
+    }
+
+    /// The purchased hours of each pool per skill in the period.
+    pub(crate) fn purchased_hours_in(&self, period_node_index: NodeIndex) -> BTreeMap<Skill, BTreeMap<ContractorPoolId, Work>>
+    {
+        let mut purchased_hours_in = BTreeMap::<Skill, BTreeMap<ContractorPoolId, Work>>::new();
+        for edge_index in &self.incidence_list()[period_node_index] {
+            let hyperedge = &self.hyperedges()[*edge_index];
+            let EdgeType::Purchased(purchased_hours) = hyperedge.edge_type() else {
+                continue;
+            };
+            if let (Node::ContractorPool(contractor_pool_id), Node::Skill(skill)) =
+                (&self.nodes()[hyperedge.nodes()[0]], &self.nodes()[hyperedge.nodes()[1]])
+            {
+                *purchased_hours_in.entry(*skill).or_default().entry(*contractor_pool_id).or_default() += purchased_hours.hours;
+            }
+        }
+        purchased_hours_in
+    }
+
+    /// The hours contracted to pools on the activity.
+    pub(crate) fn node_contracted_hours(&self, activity_node_index: NodeIndex) -> Work
+    {
+        self.incidence_list()[activity_node_index]
+            .iter()
+            .filter_map(|edge_index| match self.hyperedges()[*edge_index].edge_type() {
+                EdgeType::Contracted(contracted_hours) => Some(contracted_hours.hours),
+                _ => None,
+            })
+            .sum()
+    }
+
+    fn purchase(
+        &self,
+        contractor_pool_node_index: NodeIndex,
+        skill_node_index: NodeIndex,
+        period_node_index: NodeIndex,
+    ) -> Option<(EdgeIndex, PurchasedHours)>
+    {
+        self.incidence_list()[contractor_pool_node_index]
+            .iter()
+            .find_map(|edge_index| match self.hyperedges()[*edge_index].edge_type() {
+                EdgeType::Purchased(purchased_hours)
+                    if self.hyperedges()[*edge_index].nodes() == [contractor_pool_node_index, skill_node_index, period_node_index] =>
+                {
+                    Some((*edge_index, *purchased_hours))
+                }
+                _ => None,
+            })
+    }
+
+    /// The hours contracted to the pool in the period on activities that
+    /// require the skill.
+    fn contracted_hours(&self, contractor_pool_node_index: NodeIndex, skill: Skill, period: Period) -> Work
+    {
+        self.incidence_list()[contractor_pool_node_index]
+            .iter()
+            .filter_map(|edge_index| {
+                let hyperedge = &self.hyperedges()[*edge_index];
+                let EdgeType::Contracted(contracted_hours) = hyperedge.edge_type() else {
+                    return None;
+                };
+                let activity_node_index = hyperedge.nodes()[0];
+                (self.nodes()[hyperedge.nodes()[2]] == Node::Period(period)
+                    && self
+                        .node_skills(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires))
+                        .contains(&skill))
+                .then_some(contracted_hours.hours)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::ContractorPoolUsage;
+    use super::PurchasedHours;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_contractor_pools()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        let activity = Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_work(Work::from_hours(100.0));
+        schedule_graph
+            .add_work_order(&WorkOrder::new(WorkOrderNumber::new(1122334455).unwrap(), start_date, vec![activity]).unwrap())
+            .unwrap();
+
+        schedule_graph.add_contractor_pool(7).unwrap();
+        assert_eq!(
+            schedule_graph.add_contractor_pool(7),
+            Err(ScheduleGraphErrors::ContractorPoolDuplicate { contractor_pool_id: 7 })
+        );
+        let purchased_hours = PurchasedHours {
+            hours: Work::from_hours(60.0),
+            hourly_rate: 90.0,
+        };
+        schedule_graph
+            .purchase_contractor_hours(7, Skill::MTN_MECH, period, purchased_hours)
+            .unwrap();
+
+        // The pool has no electricians and only 60 mechanical hours.
+        schedule_graph
+            .add_assignment_contractor_pool(
+                7,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                period,
+                Work::from_hours(40.0),
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_assignment_contractor_pool(
+                7,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                period,
+                Work::from_hours(40.0)
+            ),
+            Err(ScheduleGraphErrors::ContractorPoolExhausted)
+        );
+        assert_eq!(
+            schedule_graph.activity_contracted_hours(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+            Ok(Work::from_hours(40.0))
+        );
+        assert_eq!(
+            schedule_graph.purchase_contractor_hours(
+                7,
+                Skill::MTN_MECH,
+                period,
+                PurchasedHours {
+                    hours: Work::from_hours(30.0),
+                    hourly_rate: 90.0,
+                }
+            ),
+            Err(ScheduleGraphErrors::ContractorPoolExhausted)
+        );
+
+        schedule_graph
+            .purchase_contractor_hours(
+                7,
+                Skill::MTN_MECH,
+                period,
+                PurchasedHours {
+                    hours: Work::from_hours(80.0),
+                    hourly_rate: 90.0,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.contractor_pool_usage(&[period]),
+            vec![ContractorPoolUsage {
+                contractor_pool_id: 7,
+                skill: Skill::MTN_MECH,
+                period,
+                purchased_hours: Work::from_hours(80.0),
+                contracted_hours: Work::from_hours(40.0),
+                cost: 7200.0,
+            }]
+        );
+
+        let capacity_calendar = schedule_graph.capacity_calendar(&[period]).unwrap();
+        let period_capacity = &capacity_calendar.0[&period];
+        assert_eq!(period_capacity.total_hours, Work::from_hours(80.0));
+        assert_eq!(period_capacity.skills[&Skill::MTN_MECH].contractor_pools[&7], Work::from_hours(80.0));
+
+        // The contracted hours count towards the planned work of the
+        // activity.
+        schedule_graph
+            .add_assignment_contractor_pool(
+                7,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                period,
+                Work::from_hours(40.0),
+            )
+            .unwrap();
+        assert!(schedule_graph.validate().work_overruns.is_empty());
+        schedule_graph
+            .purchase_contractor_hours(
+                7,
+                Skill::MTN_MECH,
+                period,
+                PurchasedHours {
+                    hours: Work::from_hours(120.0),
+                    hourly_rate: 90.0,
+                },
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_contractor_pool(
+                7,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                period,
+                Work::from_hours(40.0),
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.validate().work_overruns[0].assigned_work, Work::from_hours(120.0));
+    }
+}
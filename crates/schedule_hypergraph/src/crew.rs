@@ -0,0 +1,482 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::NaiveDate;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::CrewId;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::Shift;
+use crate::schedule_graph::TechnicianId;
+
+/// The days that a technician is a member of a crew. The membership starts
+/// on `from` and ends before `until`, like a `Period`. An open bound is
+/// unbounded, so the default membership is forever.
+#[derive(Hash, Clone, Copy, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Membership
+{
+    pub from: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+impl Membership
+{
+    pub fn contains(&self, day: NaiveDate) -> bool
+    {
+        self.from.is_none_or(|from| from <= day) && self.until.is_none_or(|until| day < until)
+    }
+
+    pub fn overlaps(&self, other: &Membership) -> bool
+    {
+        let starts_before_other_ends = match (self.from, other.until) {
+            (Some(from), Some(until)) => from < until,
+            _ => true,
+        };
+        let ends_after_other_starts = match (self.until, other.from) {
+            (Some(until), Some(from)) => from < until,
+            _ => true,
+        };
+        starts_before_other_ends && ends_after_other_starts
+    }
+}
+
+impl Display for Membership
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        if let Some(from) = self.from {
+            write!(f, "{from}")?;
+        }
+        write!(f, "..")?;
+        if let Some(until) = self.until {
+            write!(f, "{until}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Public API for crews. A crew is a team of technicians that supervisors
+/// schedule as one unit.
+///
+/// The members of a crew change over time. Every `Membership` is an
+/// `EdgeType::MemberOf` hyperedge of its own, so the crew of any day can be
+/// looked up after the fact.
+impl ScheduleGraph
+{
+    pub fn add_crew(&mut self, crew_id: CrewId, technicians: &[TechnicianId]) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            if graph.crew_node_index(crew_id).is_ok() {
+                return Err(ScheduleGraphErrors::CrewDuplicate { crew_id });
+            }
+
+            let technician_node_indices = technicians
+                .iter()
+                .map(|&technician_id| graph.technician_node_index(technician_id))
+                .collect::<Result<BTreeSet<_>, _>>()?;
+            if technician_node_indices.len() != technicians.len() {
+                return Err(ScheduleGraphErrors::CrewMemberDuplicate);
+            }
+
+            let crew_node_index = graph.add_node(Node::Crew(crew_id));
+            for technician_node_index in technician_node_indices {
+                graph.add_edge(EdgeType::MemberOf(Membership::default()), vec![technician_node_index, crew_node_index]);
+            }
+            Ok(crew_node_index)
+        })
+    }
+
+    /// Adds the technician to the crew for good, see
+    /// `ScheduleGraph::add_crew_membership`.
+    pub fn add_crew_member(&mut self, crew_id: CrewId, technician_id: TechnicianId) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        self.add_crew_membership(crew_id, technician_id, Membership::default())
+    }
+
+    /// Adds the technician to the crew on the days of the membership. A
+    /// membership that overlaps another membership of the technician in the
+    /// crew is rejected with `ScheduleGraphErrors::CrewMemberDuplicate`.
+    pub fn add_crew_membership(
+        &mut self,
+        crew_id: CrewId,
+        technician_id: TechnicianId,
+        membership: Membership,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let crew_node_index = self.crew_node_index(crew_id)?;
+        let technician_node_index = self.technician_node_index(technician_id)?;
+        if self
+            .membership_edge_indices(crew_node_index, technician_node_index)
+            .iter()
+            .any(|(_, other_membership)| membership.overlaps(other_membership))
+        {
+            return Err(ScheduleGraphErrors::CrewMemberDuplicate);
+        }
+
+        Ok(self.journaled(|graph| graph.add_edge(EdgeType::MemberOf(membership), vec![technician_node_index, crew_node_index])))
+    }
+
+    /// The technician leaves the crew on the day. The membership that
+    /// contains the day ends the day before, and disappears if it had not
+    /// started yet. Existing assignments of the crew keep the technician.
+    pub fn end_crew_membership(&mut self, crew_id: CrewId, technician_id: TechnicianId, until: NaiveDate) -> Result<(), ScheduleGraphErrors>
+    {
+        let crew_node_index = self.crew_node_index(crew_id)?;
+        let technician_node_index = self.technician_node_index(technician_id)?;
+        let (edge_index, membership) = self
+            .membership_edge_indices(crew_node_index, technician_node_index)
+            .into_iter()
+            .find(|(_, membership)| membership.contains(until))
+            .ok_or(ScheduleGraphErrors::CrewMemberMissing)?;
+
+        self.journaled(|graph| {
+            graph.remove_edge(edge_index)?;
+            if membership.from.is_none_or(|from| from < until) {
+                let membership = Membership {
+                    until: Some(until),
+                    ..membership
+                };
+                graph.add_edge(EdgeType::MemberOf(membership), vec![technician_node_index, crew_node_index]);
+            }
+            Ok(())
+        })
+    }
+
+    /// Removes every membership of the technician in the crew, including the
+    /// past ones. Use `ScheduleGraph::end_crew_membership` to keep the
+    /// history. Existing assignments of the crew keep the technician, as
+    /// they were expanded to the members when they were made.
+    pub fn remove_crew_member(&mut self, crew_id: CrewId, technician_id: TechnicianId) -> Result<(), ScheduleGraphErrors>
+    {
+        let crew_node_index = self.crew_node_index(crew_id)?;
+        let technician_node_index = self.technician_node_index(technician_id)?;
+        let edge_indices = self.membership_edge_indices(crew_node_index, technician_node_index);
+        if edge_indices.is_empty() {
+            return Err(ScheduleGraphErrors::CrewMemberMissing);
+        }
+
+        self.journaled(|graph| {
+            for (edge_index, _) in edge_indices {
+                graph.remove_edge(edge_index)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// The members of the crew today, ordered by `TechnicianId`.
+    pub fn crew_members(&self, crew_id: CrewId) -> Result<Vec<TechnicianId>, ScheduleGraphErrors>
+    {
+        self.crew_members_on(crew_id, self.today())
+    }
+
+    /// The members of the crew on the day, ordered by `TechnicianId`.
+    pub fn crew_members_on(&self, crew_id: CrewId, day: NaiveDate) -> Result<Vec<TechnicianId>, ScheduleGraphErrors>
+    {
+        let mut technicians = self
+            .crew_memberships(crew_id)?
+            .into_iter()
+            .filter(|(_, membership)| membership.contains(day))
+            .map(|(technician_id, _)| technician_id)
+            .collect::<Vec<_>>();
+        technicians.dedup();
+        Ok(technicians)
+    }
+
+    /// Every past, current and future membership of the crew, ordered by
+    /// `TechnicianId` and start.
+    pub fn crew_memberships(&self, crew_id: CrewId) -> Result<Vec<(TechnicianId, Membership)>, ScheduleGraphErrors>
+    {
+        let crew_node_index = self.crew_node_index(crew_id)?;
+
+        let mut memberships = self.incidence_list()[crew_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter_map(|hyperedge| match (hyperedge.edge_type(), &self.nodes()[hyperedge.nodes()[0]]) {
+                (EdgeType::MemberOf(membership), Node::Technician(technician_id)) => Some((*technician_id, *membership)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        memberships.sort();
+        Ok(memberships)
+    }
+
+    /// Assigns every member of the crew to the activity in one hyperedge.
+    /// The crew is expanded to its members on the days, so the assignment
+    /// is checked for capacity and conflicts like any other activity
+    /// assignment. If the members change during the days the assignment is
+    /// rejected with `ScheduleGraphErrors::CrewMembershipChanged`, assign the
+    /// days before and after the change separately.
+    pub fn add_assignment_crew(
+        &mut self,
+        crew_id: CrewId,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        days: Vec<NaiveDate>,
+        shift: Shift,
+    ) -> Result<AssignmentId, ScheduleGraphErrors>
+    {
+        let mut members_per_day = days
+            .iter()
+            .map(|day| self.crew_members_on(crew_id, *day))
+            .collect::<Result<BTreeSet<_>, _>>()?;
+        if members_per_day.len() > 1 {
+            return Err(ScheduleGraphErrors::CrewMembershipChanged);
+        }
+        let technicians = match members_per_day.pop_first() {
+            Some(technicians) => technicians,
+            None => self.crew_members(crew_id)?,
+        };
+        if technicians.is_empty() {
+            return Err(ScheduleGraphErrors::CrewMemberMissing);
+        }
+
+        self.add_assignment_activity(technicians, work_order_number, activity_number, days, shift)
+    }
+
+    fn membership_edge_indices(&self, crew_node_index: NodeIndex, technician_node_index: NodeIndex) -> Vec<(EdgeIndex, Membership)>
+    {
+        self.incidence_list()[crew_node_index]
+            .iter()
+            .filter_map(|&edge_index| {
+                let hyperedge = &self.hyperedges()[edge_index];
+                match hyperedge.edge_type() {
+                    EdgeType::MemberOf(membership) if hyperedge.nodes()[0] == technician_node_index => Some((edge_index, *membership)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::Membership;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_add_assignment_crew()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 3, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = start_date.and_hms_opt(7, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(19, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002), TechnicianId::new(1003)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        }
+
+        schedule_graph.add_crew(1, &[TechnicianId::new(1002), TechnicianId::new(1001)]).unwrap();
+        schedule_graph.add_crew(2, &[]).unwrap();
+        assert_eq!(schedule_graph.add_crew(1, &[]), Err(ScheduleGraphErrors::CrewDuplicate { crew_id: 1 }));
+        assert_eq!(
+            schedule_graph.add_crew(3, &[TechnicianId::new(1004)]),
+            Err(ScheduleGraphErrors::WorkerMissing {
+                technician_id: TechnicianId::new(1004),
+            })
+        );
+        assert_eq!(
+            schedule_graph.add_crew(3, &[TechnicianId::new(1003), TechnicianId::new(1003)]),
+            Err(ScheduleGraphErrors::CrewMemberDuplicate)
+        );
+        assert_eq!(schedule_graph.crew_members(3), Err(ScheduleGraphErrors::CrewMissing { crew_id: 3 }));
+        assert_eq!(
+            schedule_graph.add_crew_member(1, TechnicianId::new(1001)),
+            Err(ScheduleGraphErrors::CrewMemberDuplicate)
+        );
+
+        schedule_graph.add_crew_member(1, TechnicianId::new(1003)).unwrap();
+        schedule_graph.remove_crew_member(1, TechnicianId::new(1002)).unwrap();
+        assert_eq!(
+            schedule_graph.remove_crew_member(1, TechnicianId::new(1002)),
+            Err(ScheduleGraphErrors::CrewMemberMissing)
+        );
+        assert_eq!(schedule_graph.crew_members(1), Ok(vec![TechnicianId::new(1001), TechnicianId::new(1003)]));
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            schedule_graph.add_assignment_crew(
+                2,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift
+            ),
+            Err(ScheduleGraphErrors::CrewMemberMissing)
+        );
+
+        let assignment_id = schedule_graph
+            .add_assignment_crew(
+                1,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.assignment(assignment_id).unwrap().technicians,
+            vec![TechnicianId::new(1001), TechnicianId::new(1003)]
+        );
+        assert_eq!(schedule_graph.assignments_for_technician(TechnicianId::new(1003)).unwrap().len(), 1);
+        assert!(schedule_graph.validate().is_valid());
+    }
+
+    #[test]
+    fn test_crew_memberships()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 2, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = start_date.and_hms_opt(7, 0, 0).unwrap();
+        let end = (start_date + Duration::days(4)).and_hms_opt(19, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002), TechnicianId::new(1003)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        }
+
+        // 1002 hands over to 1003 on Wednesday.
+        let wednesday = start_date + Duration::days(2);
+        schedule_graph.add_crew(1, &[TechnicianId::new(1001), TechnicianId::new(1002)]).unwrap();
+        schedule_graph.end_crew_membership(1, TechnicianId::new(1002), wednesday).unwrap();
+        schedule_graph
+            .add_crew_membership(
+                1,
+                TechnicianId::new(1003),
+                Membership {
+                    from: Some(wednesday),
+                    until: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_crew_membership(
+                1,
+                TechnicianId::new(1002),
+                Membership {
+                    from: Some(start_date),
+                    until: Some(start_date + Duration::days(1)),
+                },
+            ),
+            Err(ScheduleGraphErrors::CrewMemberDuplicate)
+        );
+        assert_eq!(
+            schedule_graph.end_crew_membership(1, TechnicianId::new(1002), wednesday),
+            Err(ScheduleGraphErrors::CrewMemberMissing)
+        );
+
+        assert_eq!(
+            schedule_graph.crew_members_on(1, start_date),
+            Ok(vec![TechnicianId::new(1001), TechnicianId::new(1002)])
+        );
+        assert_eq!(
+            schedule_graph.crew_members_on(1, wednesday),
+            Ok(vec![TechnicianId::new(1001), TechnicianId::new(1003)])
+        );
+        assert_eq!(
+            schedule_graph.crew_memberships(1).unwrap()[1],
+            (
+                TechnicianId::new(1002),
+                Membership {
+                    from: None,
+                    until: Some(wednesday),
+                }
+            )
+        );
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            schedule_graph.add_assignment_crew(
+                1,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date, wednesday],
+                shift
+            ),
+            Err(ScheduleGraphErrors::CrewMembershipChanged)
+        );
+        let assignment_id = schedule_graph
+            .add_assignment_crew(
+                1,
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![wednesday, wednesday + Duration::days(1)],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.assignment(assignment_id).unwrap().technicians,
+            vec![TechnicianId::new(1001), TechnicianId::new(1003)]
+        );
+
+        // Removing a member erases the history.
+        schedule_graph.remove_crew_member(1, TechnicianId::new(1002)).unwrap();
+        assert_eq!(schedule_graph.crew_members_on(1, start_date), Ok(vec![TechnicianId::new(1001)]));
+    }
+}
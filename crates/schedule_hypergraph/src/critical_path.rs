@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+
+use chrono::TimeDelta;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The critical path timing of an activity. The times are offsets from the
+/// start of the work order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActivityTiming
+{
+    pub activity_number: ActivityNumber,
+    pub earliest_start: TimeDelta,
+    pub latest_finish: TimeDelta,
+    /// How much the activity can be delayed without delaying the work order.
+    pub slack: TimeDelta,
+}
+
+impl ActivityTiming
+{
+    pub fn is_critical(&self) -> bool
+    {
+        self.slack.is_zero()
+    }
+}
+
+/// Public API for the critical path method (CPM) over the precedence
+/// relations of a work order.
+impl ScheduleGraph
+{
+    /// Runs the forward and backward pass of CPM over the `StartStart`,
+    /// `FinishStart` and `Postpone` relations of the work order with the
+    /// durations of its activities. The timings are returned in the order
+    /// of the activity numbers.
+    ///
+    /// Fails with `ScheduleGraphErrors::ActivityDurationMissing` if one of
+    /// the activities has no duration.
+    pub fn critical_path(&self, work_order_number: WorkOrderNumber) -> Result<Vec<ActivityTiming>, ScheduleGraphErrors>
+    {
+        let order = self.precedence_order(work_order_number)?;
+        let relations = self.precedence_relations(work_order_number)?;
+
+        let mut duration = BTreeMap::<NodeIndex, TimeDelta>::new();
+        for activity_node_index in &order {
+            let Node::Activity(activity) = &self.nodes()[*activity_node_index] else {
+                continue;
+            };
+            let activity_duration = activity.duration().ok_or(ScheduleGraphErrors::ActivityDurationMissing)?;
+            duration.insert(*activity_node_index, activity_duration);
+        }
+
+        let mut earliest_start = BTreeMap::<NodeIndex, TimeDelta>::new();
+        for activity_node_index in &order {
+            let mut start = TimeDelta::zero();
+            for (previous, next, edge_type) in &relations {
+                if next != activity_node_index {
+                    continue;
+                }
+                let bound = match edge_type {
+                    EdgeType::StartStart => earliest_start[previous],
+                    EdgeType::Postpone(time_delta) => earliest_start[previous] + duration[previous] + *time_delta,
+                    _ => earliest_start[previous] + duration[previous],
+                };
+                start = start.max(bound);
+            }
+            earliest_start.insert(*activity_node_index, start);
+        }
+
+        let work_order_finish = order
+            .iter()
+            .map(|activity_node_index| earliest_start[activity_node_index] + duration[activity_node_index])
+            .max()
+            .unwrap_or_default();
+
+        let mut latest_finish = BTreeMap::<NodeIndex, TimeDelta>::new();
+        for activity_node_index in order.iter().rev() {
+            let mut finish = work_order_finish;
+            for (previous, next, edge_type) in &relations {
+                if previous != activity_node_index {
+                    continue;
+                }
+                let next_latest_start = latest_finish[next] - duration[next];
+                let bound = match edge_type {
+                    EdgeType::StartStart => next_latest_start + duration[previous],
+                    EdgeType::Postpone(time_delta) => next_latest_start - *time_delta,
+                    _ => next_latest_start,
+                };
+                finish = finish.min(bound);
+            }
+            latest_finish.insert(*activity_node_index, finish);
+        }
+
+        let mut activity_timings = order
+            .iter()
+            .filter_map(|activity_node_index| match &self.nodes()[*activity_node_index] {
+                Node::Activity(activity) => Some(ActivityTiming {
+                    activity_number: activity.activity_number(),
+                    earliest_start: earliest_start[activity_node_index],
+                    latest_finish: latest_finish[activity_node_index],
+                    slack: latest_finish[activity_node_index] - earliest_start[activity_node_index] - duration[activity_node_index],
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        activity_timings.sort_by_key(|activity_timing| activity_timing.activity_number);
+        Ok(activity_timings)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::ActivityRelation;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::ActivityTiming;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_critical_path()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        // 10 is followed by 20 after a cure of 4 hours, and 30 starts
+        // together with 20.
+        let activity_relations = vec![ActivityRelation::Postpone(TimeDelta::hours(4)), ActivityRelation::StartStart];
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_duration(TimeDelta::hours(2)),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH).with_duration(TimeDelta::hours(8)),
+                Activity::new(ActivityNumber::new(30), 1, Skill::MTN_MECH).with_duration(TimeDelta::hours(3)),
+            ],
+        )
+        .unwrap()
+        .with_activity_relations(activity_relations.clone())
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let work_order_without_durations = WorkOrder::new(
+            WorkOrderNumber::new(1122334466).unwrap(),
+            basic_start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_duration(TimeDelta::hours(2)),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(30), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap()
+        .with_activity_relations(activity_relations)
+        .unwrap();
+        schedule_graph.add_work_order(&work_order_without_durations).unwrap();
+
+        assert_eq!(
+            schedule_graph.critical_path(WorkOrderNumber::new(1122334466).unwrap()),
+            Err(ScheduleGraphErrors::ActivityDurationMissing)
+        );
+
+        let activity_timings = schedule_graph.critical_path(WorkOrderNumber::new(1122334455).unwrap()).unwrap();
+
+        assert_eq!(
+            activity_timings,
+            vec![
+                ActivityTiming {
+                    activity_number: ActivityNumber::new(10),
+                    earliest_start: TimeDelta::zero(),
+                    latest_finish: TimeDelta::hours(2),
+                    slack: TimeDelta::zero(),
+                },
+                ActivityTiming {
+                    activity_number: ActivityNumber::new(20),
+                    earliest_start: TimeDelta::hours(6),
+                    latest_finish: TimeDelta::hours(14),
+                    slack: TimeDelta::zero(),
+                },
+                ActivityTiming {
+                    activity_number: ActivityNumber::new(30),
+                    earliest_start: TimeDelta::hours(6),
+                    latest_finish: TimeDelta::hours(14),
+                    slack: TimeDelta::hours(5),
+                },
+            ]
+        );
+        assert!(activity_timings[1].is_critical());
+        assert!(!activity_timings[2].is_critical());
+    }
+}
@@ -0,0 +1,501 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::Shift;
+
+/// A work order that is assigned to start before a work order it depends on
+/// is finished.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DependencyViolation
+{
+    pub predecessor: WorkOrderNumber,
+    pub successor: WorkOrderNumber,
+}
+
+/// When a work order is assigned. Work order assignments are only placed in
+/// a period, so a dependency is checked on the periods and, where both work
+/// orders have activity assignments, on the shifts.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WorkOrderSchedule
+{
+    periods: BTreeSet<Period>,
+    intervals: Vec<(NaiveDateTime, NaiveDateTime)>,
+}
+
+impl WorkOrderSchedule
+{
+    pub(crate) fn from_period(period: Period) -> Self
+    {
+        Self {
+            periods: BTreeSet::from([period]),
+            intervals: vec![],
+        }
+    }
+
+    /// The successor starts before the predecessor is finished.
+    fn precedes_violated(predecessor: &Self, successor: &Self) -> bool
+    {
+        let periods_violated = matches!(
+            (predecessor.periods.last(), successor.periods.first()),
+            (Some(predecessor_period), Some(successor_period)) if successor_period < predecessor_period
+        );
+        let finish = predecessor.intervals.iter().map(|(_, finish)| *finish).max();
+        let start = successor.intervals.iter().map(|(start, _)| *start).min();
+        let intervals_violated = matches!((finish, start), (Some(finish), Some(start)) if start < finish);
+        periods_violated || intervals_violated
+    }
+}
+
+/// Public API for dependencies between work orders, e.g. an isolation that
+/// has to finish before the repair starts.
+impl ScheduleGraph
+{
+    /// Adds an `EdgeType::DependsOn` from the predecessor to the successor.
+    ///
+    /// This method can fail when:
+    /// * Either `WorkOrderNumber` does not exist.
+    /// * The dependency already exists.
+    /// * The dependency closes a cycle of dependencies.
+    /// * The current assignments of the two work orders violate it.
+    pub fn add_work_order_dependency(&mut self, predecessor: WorkOrderNumber, successor: WorkOrderNumber) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let predecessor_node_index = self.work_order_node_index(predecessor)?;
+        let successor_node_index = self.work_order_node_index(successor)?;
+
+        let nodes = vec![predecessor_node_index, successor_node_index];
+        if self.edge_exists(&EdgeType::DependsOn, &nodes) {
+            return Err(ScheduleGraphErrors::DependencyDuplicate);
+        }
+
+        let mut stack = vec![successor_node_index];
+        let mut visited = BTreeSet::new();
+        while let Some(node_index) = stack.pop() {
+            if node_index == predecessor_node_index {
+                return Err(ScheduleGraphErrors::DependencyCycle);
+            }
+            if visited.insert(node_index) {
+                stack.extend(self.dependency_neighbours(node_index, true));
+            }
+        }
+
+        if WorkOrderSchedule::precedes_violated(
+            &self.work_order_schedule(predecessor_node_index),
+            &self.work_order_schedule(successor_node_index),
+        ) {
+            return Err(ScheduleGraphErrors::DependencyViolated { predecessor, successor });
+        }
+
+        Ok(self.add_edge(EdgeType::DependsOn, nodes))
+    }
+
+    /// The work orders that the work order depends on.
+    pub fn work_order_predecessors(&self, work_order_number: WorkOrderNumber) -> Result<Vec<WorkOrderNumber>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        Ok(self.work_order_numbers_of(self.dependency_neighbours(work_order_node_index, false)))
+    }
+
+    /// The work orders that depend on the work order.
+    pub fn work_order_successors(&self, work_order_number: WorkOrderNumber) -> Result<Vec<WorkOrderNumber>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        Ok(self.work_order_numbers_of(self.dependency_neighbours(work_order_node_index, true)))
+    }
+
+    /// Every dependency that the current assignments violate.
+    pub fn validate_dependencies(&self) -> Vec<DependencyViolation>
+    {
+        let mut dependency_violations = self
+            .edges()
+            .filter(|(_, hyperedge)| matches!(hyperedge.edge_type(), EdgeType::DependsOn))
+            .filter_map(|(_, hyperedge)| {
+                let [predecessor_node_index, successor_node_index] = hyperedge.nodes() else {
+                    return None;
+                };
+                let (Node::WorkOrder(predecessor), Node::WorkOrder(successor)) =
+                    (&self.nodes()[*predecessor_node_index], &self.nodes()[*successor_node_index])
+                else {
+                    return None;
+                };
+                WorkOrderSchedule::precedes_violated(
+                    &self.work_order_schedule(*predecessor_node_index),
+                    &self.work_order_schedule(*successor_node_index),
+                )
+                .then_some(DependencyViolation {
+                    predecessor: *predecessor,
+                    successor: *successor,
+                })
+            })
+            .collect::<Vec<_>>();
+        dependency_violations.sort();
+        dependency_violations
+    }
+
+    /// Rejects an assignment of the work order that starts before one of its
+    /// predecessors is finished or finishes after one of its successors has
+    /// started.
+    pub(crate) fn check_work_order_dependencies(
+        &self,
+        work_order_number: WorkOrderNumber,
+        added_schedule: &WorkOrderSchedule,
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        for predecessor_node_index in self.dependency_neighbours(work_order_node_index, false) {
+            if WorkOrderSchedule::precedes_violated(&self.work_order_schedule(predecessor_node_index), added_schedule) {
+                return Err(ScheduleGraphErrors::DependencyViolated {
+                    predecessor: self.work_order_numbers_of([predecessor_node_index])[0],
+                    successor: work_order_number,
+                });
+            }
+        }
+        for successor_node_index in self.dependency_neighbours(work_order_node_index, true) {
+            if WorkOrderSchedule::precedes_violated(added_schedule, &self.work_order_schedule(successor_node_index)) {
+                return Err(ScheduleGraphErrors::DependencyViolated {
+                    predecessor: work_order_number,
+                    successor: self.work_order_numbers_of([successor_node_index])[0],
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The `WorkOrderSchedule` of an activity assignment with the shift on
+    /// the days.
+    pub(crate) fn work_order_schedule_on(&self, days: &[NaiveDate], shift: &Shift) -> WorkOrderSchedule
+    {
+        WorkOrderSchedule {
+            periods: self.periods_of_days(days),
+            intervals: days.iter().map(|day| shift.interval_on(*day)).collect(),
+        }
+    }
+
+    /// The `WorkOrderSchedule` that a split activity assignment adds.
+    pub(crate) fn work_order_schedule_of_segments(&self, segments: &[(NaiveDate, Shift)]) -> WorkOrderSchedule
+    {
+        let days = segments.iter().map(|(day, _)| *day).collect::<Vec<_>>();
+        WorkOrderSchedule {
+            periods: self.periods_of_days(&days),
+            intervals: segments.iter().map(|(day, shift)| shift.interval_on(*day)).collect(),
+        }
+    }
+
+    /// The `WorkOrderSchedule` of the work order and its activities.
+    fn work_order_schedule(&self, work_order_node_index: NodeIndex) -> WorkOrderSchedule
+    {
+        let activity_node_indices = self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|edge_index| &self.hyperedges()[*edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Contains) && hyperedge.nodes()[0] == work_order_node_index)
+            .filter_map(|hyperedge| hyperedge.nodes().get(1).copied());
+
+        let mut work_order_schedule = WorkOrderSchedule::default();
+        for node_index in std::iter::once(work_order_node_index).chain(activity_node_indices) {
+            for edge_index in &self.incidence_list()[node_index] {
+                let Some(assignment) = self.assignment_view(*edge_index) else {
+                    continue;
+                };
+                work_order_schedule.periods.extend(assignment.period);
+                work_order_schedule.periods.extend(self.periods_of_days(&assignment.days));
+                work_order_schedule.intervals.extend(assignment.intervals());
+            }
+        }
+        work_order_schedule
+    }
+
+    fn periods_of_days(&self, days: &[NaiveDate]) -> BTreeSet<Period>
+    {
+        self.nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Period(period) if days.iter().any(|day| period.contains(day)) => Some(*period),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The work orders that the work order depends on, or the work orders
+    /// depending on it when `successors` is set.
+    fn dependency_neighbours(&self, work_order_node_index: NodeIndex, successors: bool) -> Vec<NodeIndex>
+    {
+        self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|edge_index| &self.hyperedges()[*edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::DependsOn))
+            .filter_map(|hyperedge| match hyperedge.nodes() {
+                [predecessor, successor] if successors && *predecessor == work_order_node_index => Some(*successor),
+                [predecessor, successor] if !successors && *successor == work_order_node_index => Some(*predecessor),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn work_order_numbers_of(&self, node_indices: impl IntoIterator<Item = NodeIndex>) -> Vec<WorkOrderNumber>
+    {
+        node_indices
+            .into_iter()
+            .filter_map(|node_index| match &self.nodes()[node_index] {
+                Node::WorkOrder(work_order_number) => Some(*work_order_number),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::DependencyViolation;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_add_work_order_dependency()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(monday);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+
+        // 1122334455 isolates, 1122334466 repairs and 1122334477 tests.
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                monday,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = monday.and_hms_opt(7, 0, 0).unwrap();
+        let end = monday.and_hms_opt(15, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        schedule_graph
+            .add_work_order_dependency(WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap())
+            .unwrap();
+        schedule_graph
+            .add_work_order_dependency(WorkOrderNumber::new(1122334466).unwrap(), WorkOrderNumber::new(1122334477).unwrap())
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_work_order_dependency(WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()),
+            Err(ScheduleGraphErrors::DependencyDuplicate)
+        );
+        assert_eq!(
+            schedule_graph.add_work_order_dependency(WorkOrderNumber::new(1122334477).unwrap(), WorkOrderNumber::new(1122334455).unwrap()),
+            Err(ScheduleGraphErrors::DependencyCycle)
+        );
+        assert_eq!(
+            schedule_graph.work_order_predecessors(WorkOrderNumber::new(1122334466).unwrap()),
+            Ok(vec![WorkOrderNumber::new(1122334455).unwrap()])
+        );
+        assert_eq!(
+            schedule_graph.work_order_successors(WorkOrderNumber::new(1122334466).unwrap()),
+            Ok(vec![WorkOrderNumber::new(1122334477).unwrap()])
+        );
+
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), next_period)
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334477).unwrap(), period),
+            Err(ScheduleGraphErrors::DependencyViolated {
+                predecessor: WorkOrderNumber::new(1122334466).unwrap(),
+                successor: WorkOrderNumber::new(1122334477).unwrap(),
+            })
+        );
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), period)
+            .unwrap();
+
+        // On the shifts the repair cannot start before the isolation is done.
+        let shift = |start: u32, finish: u32| {
+            Shift::new(
+                NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(finish, 0, 0).unwrap(),
+                false,
+            )
+            .unwrap()
+        };
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift(7, 11),
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift(9, 13)
+            ),
+            Err(ScheduleGraphErrors::DependencyViolated {
+                predecessor: WorkOrderNumber::new(1122334455).unwrap(),
+                successor: WorkOrderNumber::new(1122334466).unwrap(),
+            })
+        );
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift(11, 15),
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.validate_dependencies(), vec![]);
+
+        // A malformed import that lets the isolation wait for the repair.
+        let predecessor_node_index = schedule_graph.work_order_node_index(WorkOrderNumber::new(1122334466).unwrap()).unwrap();
+        let successor_node_index = schedule_graph.work_order_node_index(WorkOrderNumber::new(1122334455).unwrap()).unwrap();
+        schedule_graph.add_edge(EdgeType::DependsOn, vec![predecessor_node_index, successor_node_index]);
+
+        assert_eq!(
+            schedule_graph.validate_dependencies(),
+            vec![DependencyViolation {
+                predecessor: WorkOrderNumber::new(1122334466).unwrap(),
+                successor: WorkOrderNumber::new(1122334455).unwrap(),
+            }]
+        );
+        assert!(!schedule_graph.validate().is_valid());
+    }
+
+    #[test]
+    fn test_move_assignment_ahead_of_predecessor()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let tuesday = monday.succ_opt().unwrap();
+        let wednesday = tuesday.succ_opt().unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+
+        // 1122334455 isolates on Tuesday and 1122334466 repairs on Wednesday.
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                monday,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+        let start = monday.and_hms_opt(7, 0, 0).unwrap();
+        let end = wednesday.and_hms_opt(15, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        }
+        schedule_graph
+            .add_work_order_dependency(WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap())
+            .unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![tuesday],
+                shift,
+            )
+            .unwrap();
+        let assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1002)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![wednesday],
+                shift,
+            )
+            .unwrap();
+
+        // Moving the repair to Monday would start it before the isolation,
+        // and the repair stays on Wednesday.
+        assert_eq!(
+            schedule_graph.move_assignment(
+                TechnicianId::new(1002),
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift
+            ),
+            Err(ScheduleGraphErrors::DependencyViolated {
+                predecessor: WorkOrderNumber::new(1122334455).unwrap(),
+                successor: WorkOrderNumber::new(1122334466).unwrap(),
+            })
+        );
+        assert_eq!(schedule_graph.assignment(assignment_id).unwrap().days, vec![wednesday]);
+
+        // Moving the isolation past the repair is rejected as well.
+        assert_eq!(
+            schedule_graph.move_assignment(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![wednesday],
+                shift
+            ),
+            Err(ScheduleGraphErrors::DependencyViolated {
+                predecessor: WorkOrderNumber::new(1122334455).unwrap(),
+                successor: WorkOrderNumber::new(1122334466).unwrap(),
+            })
+        );
+        assert_eq!(schedule_graph.validate_dependencies(), vec![]);
+    }
+}
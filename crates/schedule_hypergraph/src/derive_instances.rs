@@ -1,8 +1,11 @@
+use std::collections::HashMap;
+
+use scheduling_environment::Period;
 use scheduling_environment::work_order::WorkOrderNumber;
 
-use crate::schedule_graph::HyperEdge;
-use crate::schedule_graph::Node;
 use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
 
 /// This contains the API for deriving problem instances for the
 /// optimization algorithms.
@@ -10,40 +13,34 @@ use crate::schedule_graph::ScheduleGraph;
 /// Every methods has to be non-mutating
 impl ScheduleGraph
 {
-    pub fn derive_work_orders(&self, work_order_numbers: &[WorkOrderNumber]) -> GraphWorkOrders
+    // ESSAY:
+    // What is the information that you want to get out here?
+    //
+    // QUESTION: How to get the hours?
+    // ANSWER: For each Technician use the availabilities to
+    // calculate the correct
+    pub fn derive_work_orders(&self, work_order_numbers: &[WorkOrderNumber]) -> Result<GraphWorkOrders, ScheduleGraphErrors>
     {
-        for node in self.nodes() {
-            // ESSAY:
-            // What is the information that you want to get out here?
-            //
-            // You want to extract all the
-            // QUESTION: How to get the hours?
-            // ANSWER: For each Technician use the availabilities to
-            // calculate the correct
-            match node {
-                Node::Technician(e) => todo!(),
-                Node::WorkOrder(work_order_node_index) => {
-                    // Derive every hyperedge
-                    let hyperedge_indices = &self.incidence_list()[*work_order_node_index as usize];
-
-                    // Now we have to find each of the assignments
-                    //
-
-                    for hyperedge_index in hyperedge_indices {
-                        let hyperedge = self.hyperedges()[*hyperedge_index];
+        let mut graph_work_orders = HashMap::new();
+        for work_order_number in work_order_numbers {
+            let graph_work_order = GraphWorkOrder {
+                technicians: self.technicians_for_work_order(*work_order_number)?,
+                excluded_periods: self.periods_excluding(*work_order_number)?,
+            };
 
-                        match hyperedge {}
-                    }
-                }
-                Node::Activity(activity_node) => todo!(),
-                Node::Period(period) => todo!(),
-                Node::Skill(skill) => todo!(),
-                Node::Day(naive_date) => todo!(),
-            }
+            graph_work_orders.insert(*work_order_number, graph_work_order);
         }
 
-        GraphWorkOrders {}
+        Ok(GraphWorkOrders(graph_work_orders))
     }
 }
 
-struct GraphWorkOrders {}
+#[derive(Debug, Default, PartialEq)]
+pub struct GraphWorkOrders(pub HashMap<WorkOrderNumber, GraphWorkOrder>);
+
+#[derive(Debug, PartialEq)]
+pub struct GraphWorkOrder
+{
+    pub technicians: Vec<TechnicianId>,
+    pub excluded_periods: Vec<Period>,
+}
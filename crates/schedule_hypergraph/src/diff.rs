@@ -0,0 +1,228 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::AssignmentView;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::TechnicianId;
+
+/// The changes from one `ScheduleGraph` to another in domain types.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleDiff
+{
+    pub added_work_orders: Vec<WorkOrderNumber>,
+    pub removed_work_orders: Vec<WorkOrderNumber>,
+    pub added_technicians: Vec<TechnicianId>,
+    pub removed_technicians: Vec<TechnicianId>,
+    pub added_assignments: Vec<AssignmentView>,
+    pub removed_assignments: Vec<AssignmentView>,
+}
+
+impl ScheduleDiff
+{
+    pub fn is_empty(&self) -> bool
+    {
+        self == &ScheduleDiff::default()
+    }
+}
+
+/// Public API to compare two graphs, e.g. the plans of two weeks.
+impl ScheduleGraph
+{
+    /// The changes needed to go from `self` to `other`. Node and edge
+    /// indices and `AssignmentId`s are not compared, so the graphs can be
+    /// built independently. The reported assignments carry the
+    /// `AssignmentId` of the graph that they belong to.
+    ///
+    /// A moved assignment shows up as a removed and an added assignment.
+    pub fn diff(&self, other: &ScheduleGraph) -> ScheduleDiff
+    {
+        let work_orders = self.work_order_numbers().into_iter().collect::<BTreeSet<_>>();
+        let other_work_orders = other.work_order_numbers().into_iter().collect::<BTreeSet<_>>();
+        let technicians = self.technician_ids();
+        let other_technicians = other.technician_ids();
+
+        type Matches = (Vec<AssignmentView>, Vec<AssignmentView>);
+        let mut assignment_matches = HashMap::<AssignmentView, Matches>::new();
+        for assignment in self.all_assignments() {
+            assignment_matches.entry(without_id(&assignment)).or_default().0.push(assignment);
+        }
+        for assignment in other.all_assignments() {
+            assignment_matches.entry(without_id(&assignment)).or_default().1.push(assignment);
+        }
+
+        let mut added_assignments = vec![];
+        let mut removed_assignments = vec![];
+        for (assignments, other_assignments) in assignment_matches.into_values() {
+            let matched = assignments.len().min(other_assignments.len());
+            removed_assignments.extend(assignments.into_iter().skip(matched));
+            added_assignments.extend(other_assignments.into_iter().skip(matched));
+        }
+        let order = |assignment: &AssignmentView| {
+            (
+                assignment.work_order,
+                assignment.activity,
+                assignment.days.clone(),
+                assignment.assignment_id,
+            )
+        };
+        added_assignments.sort_by_key(order);
+        removed_assignments.sort_by_key(order);
+
+        ScheduleDiff {
+            added_work_orders: other_work_orders.difference(&work_orders).copied().collect(),
+            removed_work_orders: work_orders.difference(&other_work_orders).copied().collect(),
+            added_technicians: other_technicians.difference(&technicians).copied().collect(),
+            removed_technicians: technicians.difference(&other_technicians).copied().collect(),
+            added_assignments,
+            removed_assignments,
+        }
+    }
+
+    fn technician_ids(&self) -> BTreeSet<TechnicianId>
+    {
+        self.nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Technician(technician_id) => Some(*technician_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn all_assignments(&self) -> impl Iterator<Item = AssignmentView>
+    {
+        self.edges().filter_map(|(edge_index, _)| self.assignment_view(edge_index))
+    }
+}
+
+/// The key that assignments of two graphs are matched on.
+fn without_id(assignment: &AssignmentView) -> AssignmentView
+{
+    AssignmentView {
+        assignment_id: AssignmentId::default(),
+        ..assignment.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_diff()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let next_date = basic_start_date + Duration::days(1);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability_start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = next_date.and_hms_opt(17, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+
+        let mut next_week_graph = schedule_graph.clone();
+        assert!(schedule_graph.diff(&next_week_graph).is_empty());
+
+        // The ids are not compared, so an identical plan built from scratch
+        // has no changes either.
+        let mut rebuilt_graph = schedule_graph.clone();
+        let assignment_id = rebuilt_graph.assignments_for_technician(TechnicianId::new(1001)).unwrap()[0].assignment_id;
+        rebuilt_graph.remove_assignment(assignment_id).unwrap();
+        rebuilt_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        assert!(schedule_graph.diff(&rebuilt_graph).is_empty());
+
+        let new_work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334466).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        next_week_graph.add_work_order(&new_work_order).unwrap();
+        next_week_graph
+            .move_assignment(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![next_date],
+                shift,
+            )
+            .unwrap();
+
+        let schedule_diff = schedule_graph.diff(&next_week_graph);
+
+        assert_eq!(schedule_diff.added_work_orders, vec![WorkOrderNumber::new(1122334466).unwrap()]);
+        assert!(schedule_diff.removed_work_orders.is_empty());
+        assert!(schedule_diff.added_technicians.is_empty());
+        assert_eq!(schedule_diff.added_assignments.len(), 1);
+        assert_eq!(schedule_diff.added_assignments[0].days, vec![next_date]);
+        assert_eq!(schedule_diff.removed_assignments.len(), 1);
+        assert_eq!(schedule_diff.removed_assignments[0].days, vec![basic_start_date]);
+        assert_eq!(schedule_diff.removed_assignments[0].assignment_id, assignment_id);
+        assert_ne!(schedule_diff.added_assignments[0].assignment_id, assignment_id);
+
+        let reverse_diff = next_week_graph.diff(&schedule_graph);
+        assert_eq!(reverse_diff.removed_work_orders, vec![WorkOrderNumber::new(1122334466).unwrap()]);
+        assert_eq!(reverse_diff.added_assignments, schedule_diff.removed_assignments);
+    }
+}
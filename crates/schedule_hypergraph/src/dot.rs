@@ -0,0 +1,183 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+
+/// Options for `ScheduleGraph::to_dot`.
+#[derive(Clone, Debug)]
+pub struct DotOptions
+{
+    /// Only hyperedges whose `EdgeType` satisfies the filter are rendered.
+    pub edge_filter: fn(&EdgeType) -> bool,
+    /// Leave out nodes that are not part of any rendered hyperedge. Every
+    /// period creates a `Day` node per day, so this keeps the output small.
+    pub hide_isolated_nodes: bool,
+}
+
+impl Default for DotOptions
+{
+    fn default() -> Self
+    {
+        Self {
+            edge_filter: |_| true,
+            hide_isolated_nodes: false,
+        }
+    }
+}
+
+/// Public API to export the graph to Graphviz DOT for debugging.
+impl ScheduleGraph
+{
+    /// Renders the graph in DOT format. Every hyperedge is drawn as a
+    /// diamond shaped node connected to each of its members, and the nodes
+    /// are colored by their `Node` variant.
+    pub fn to_dot(&self, options: DotOptions) -> String
+    {
+        let hyperedges = self
+            .edges()
+            .filter(|(_, hyperedge)| (options.edge_filter)(hyperedge.edge_type()))
+            .collect::<Vec<_>>();
+
+        let connected_nodes = hyperedges
+            .iter()
+            .flat_map(|(_, hyperedge)| hyperedge.nodes().iter().copied())
+            .collect::<BTreeSet<NodeIndex>>();
+
+        let mut dot = String::new();
+        writeln!(dot, "graph ScheduleGraph {{").unwrap();
+        writeln!(dot, "    node [style=filled];").unwrap();
+
+        for (node_index, node) in self.nodes().iter().enumerate() {
+            if options.hide_isolated_nodes && !connected_nodes.contains(&node_index) {
+                continue;
+            }
+            let (label, color) = match node {
+                Node::Technician(technician_id) => (format!("Technician\\n{technician_id}"), "lightblue"),
+                Node::WorkOrder(work_order_number) => (format!("WorkOrder\\n{work_order_number}"), "orange"),
+                Node::Activity(activity) => (
+                    format!("Activity\\n{}/{}", activity.work_order_number(), activity.activity_number()),
+                    "gold",
+                ),
+                Node::Period(period) => (format!("Period\\n{}", period.start_date()), "palegreen"),
+                Node::Skill(skill) => (format!("Skill\\n{skill}"), "plum"),
+                Node::Day(day) => (format!("Day\\n{day}"), "lightgrey"),
+                Node::WorkPackage(work_package_id) => (format!("WorkPackage\\n{work_package_id}"), "salmon"),
+                Node::Crew(crew_id) => (format!("Crew\\n{crew_id}"), "lightcyan"),
+                Node::FunctionalLocation(functional_location_id) => (format!("FunctionalLocation\\n{functional_location_id}"), "khaki"),
+                Node::Equipment(equipment_id) => (format!("Equipment\\n{equipment_id}"), "tan"),
+                Node::Material(material_id) => (format!("Material\\n{material_id}"), "wheat"),
+                Node::ContractorPool(contractor_pool_id) => (format!("ContractorPool\\n{contractor_pool_id}"), "thistle"),
+                Node::ShutdownWindow(shutdown_window_id) => (format!("ShutdownWindow\\n{shutdown_window_id}"), "orange"),
+            };
+            writeln!(dot, "    n{node_index} [label=\"{label}\", fillcolor=\"{color}\"];").unwrap();
+        }
+
+        for (edge_index, hyperedge) in hyperedges {
+            writeln!(
+                dot,
+                "    e{edge_index} [shape=diamond, label=\"{}\", fillcolor=\"white\"];",
+                edge_label(hyperedge.edge_type())
+            )
+            .unwrap();
+            for node_index in hyperedge.nodes() {
+                writeln!(dot, "    e{edge_index} -- n{node_index};").unwrap();
+            }
+        }
+
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+}
+
+pub(crate) fn edge_label(edge_type: &EdgeType) -> String
+{
+    match edge_type {
+        EdgeType::Assign(Some(shift)) => format!("Assign\\n{}-{}", shift.start().format("%H:%M"), shift.finish().format("%H:%M")),
+        EdgeType::Assign(None) => "Assign".to_string(),
+        EdgeType::Available(_) => "Available".to_string(),
+        EdgeType::Exclude => "Exclude".to_string(),
+        EdgeType::BasicStart => "BasicStart".to_string(),
+        EdgeType::Contains => "Contains".to_string(),
+        EdgeType::Requires => "Requires".to_string(),
+        EdgeType::StartStart => "StartStart".to_string(),
+        EdgeType::FinishStart => "FinishStart".to_string(),
+        EdgeType::Postpone(time_delta) => format!("Postpone\\n{}h", time_delta.num_hours()),
+        EdgeType::HasSkill(skill_level) => format!("HasSkill\\n{skill_level:?}"),
+        EdgeType::Risk(_) => "Risk".to_string(),
+        EdgeType::Priority(priority) => format!("Priority\\n{priority:?}"),
+        EdgeType::Revision(revision) => format!("Revision\\n{revision}"),
+        EdgeType::Due => "Due".to_string(),
+        EdgeType::Forecast => "Forecast".to_string(),
+        EdgeType::DependsOn => "DependsOn".to_string(),
+        EdgeType::MemberOf(membership) => format!("MemberOf\\n{membership}"),
+        EdgeType::LocatedAt => "LocatedAt".to_string(),
+        EdgeType::On => "On".to_string(),
+        EdgeType::RequiresMaterial => "RequiresMaterial".to_string(),
+        EdgeType::Coordinates(coordinates) => format!("Coordinates\\n{:.5}, {:.5}", coordinates.latitude, coordinates.longitude),
+        EdgeType::ActualWork(actual_work) if actual_work.final_confirmation => format!("ActualWork\\n{}h final", actual_work.hours),
+        EdgeType::ActualWork(actual_work) => format!("ActualWork\\n{}h", actual_work.hours),
+        EdgeType::EffectiveFrom(effective_from, technician_change) => format!("EffectiveFrom\\n{effective_from}\\n{technician_change:?}"),
+        EdgeType::Purchased(purchased_hours) => format!("Purchased\\n{}h", purchased_hours.hours),
+        EdgeType::Contracted(contracted_hours) => format!("Contracted\\n{}h", contracted_hours.hours),
+        EdgeType::Outage => "Outage".to_string(),
+        EdgeType::During => "During".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::DotOptions;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+
+    #[test]
+    fn test_to_dot()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let skill_node_index = schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        let work_order_node_index = schedule_graph.add_work_order(&work_order).unwrap().work_order_node_index;
+
+        let dot = schedule_graph.to_dot(DotOptions::default());
+
+        assert!(dot.starts_with("graph ScheduleGraph {"));
+        assert!(dot.contains(&format!(
+            "n{work_order_node_index} [label=\"WorkOrder\\n1122334455\", fillcolor=\"orange\"];"
+        )));
+        assert!(dot.contains(&format!("n{skill_node_index} [label=\"Skill\\nMtnMech\", fillcolor=\"plum\"];")));
+        assert!(dot.contains("[shape=diamond, label=\"BasicStart\""));
+        assert!(dot.contains("[shape=diamond, label=\"Requires\""));
+
+        let dot = schedule_graph.to_dot(DotOptions {
+            edge_filter: |edge_type| matches!(edge_type, EdgeType::Requires),
+            hide_isolated_nodes: true,
+        });
+
+        assert!(dot.contains("[shape=diamond, label=\"Requires\""));
+        assert!(!dot.contains("label=\"Contains\""));
+        assert!(!dot.contains("Day\\n"));
+        assert!(!dot.contains("WorkOrder\\n"));
+        assert!(dot.contains("Activity\\n1122334455/10"));
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use crate::dot::edge_label;
+use crate::events::GraphEntity;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::ScheduleGraph;
+
+/// Options for `ScheduleGraph::dual_graph`.
+#[derive(Clone, Debug)]
+pub struct DualGraphOptions
+{
+    /// Only hyperedges whose `EdgeType` satisfies the filter become nodes of
+    /// the dual graph.
+    pub edge_filter: fn(&EdgeType) -> bool,
+    /// Only members that satisfy the filter connect two hyperedges. Every
+    /// assignment in a period shares the `Period` node, so e.g. restricting
+    /// the members to technicians keeps the dual graph meaningful.
+    pub member_filter: fn(&GraphEntity) -> bool,
+}
+
+impl Default for DualGraphOptions
+{
+    fn default() -> Self
+    {
+        Self {
+            edge_filter: |_| true,
+            member_filter: |_| true,
+        }
+    }
+}
+
+/// The dual of the hypergraph: the hyperedges are the nodes, and two
+/// hyperedges are connected when they share members.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DualGraph
+{
+    /// For each hyperedge the number of members it shares with each of its
+    /// neighbours.
+    adjacency: BTreeMap<EdgeIndex, BTreeMap<EdgeIndex, usize>>,
+}
+
+impl DualGraph
+{
+    pub fn edge_indices(&self) -> impl Iterator<Item = EdgeIndex> + '_
+    {
+        self.adjacency.keys().copied()
+    }
+
+    /// The hyperedges sharing members with the hyperedge and the number of
+    /// shared members.
+    pub fn neighbours(&self, edge_index: EdgeIndex) -> Option<&BTreeMap<EdgeIndex, usize>>
+    {
+        self.adjacency.get(&edge_index)
+    }
+
+    pub fn degree(&self, edge_index: EdgeIndex) -> usize
+    {
+        self.adjacency.get(&edge_index).map_or(0, BTreeMap::len)
+    }
+
+    /// Every hyperedge with its degree, with the most entangled first.
+    pub fn most_entangled(&self) -> Vec<(EdgeIndex, usize)>
+    {
+        let mut degrees = self
+            .adjacency
+            .iter()
+            .map(|(edge_index, neighbours)| (*edge_index, neighbours.len()))
+            .collect::<Vec<_>>();
+        degrees.sort_by_key(|(edge_index, degree)| (std::cmp::Reverse(*degree), *edge_index));
+        degrees
+    }
+
+    /// The connected components with more than one hyperedge. Conflicts
+    /// between the hyperedges of different clusters cannot interact, so
+    /// each cluster can be resolved on its own.
+    pub fn clusters(&self) -> Vec<BTreeSet<EdgeIndex>>
+    {
+        let mut visited = BTreeSet::<EdgeIndex>::new();
+        let mut clusters = vec![];
+        for edge_index in self.adjacency.keys() {
+            if !visited.insert(*edge_index) {
+                continue;
+            }
+            let mut cluster = BTreeSet::from([*edge_index]);
+            let mut stack = vec![*edge_index];
+            while let Some(current) = stack.pop() {
+                for neighbour in self.adjacency[&current].keys() {
+                    if visited.insert(*neighbour) {
+                        cluster.insert(*neighbour);
+                        stack.push(*neighbour);
+                    }
+                }
+            }
+            if cluster.len() > 1 {
+                clusters.push(cluster);
+            }
+        }
+        clusters
+    }
+}
+
+/// Public API for meta-analysis on the dual of the graph.
+impl ScheduleGraph
+{
+    /// Builds the `DualGraph` from the incidence list: the hyperedges
+    /// incident to the same member are pairwise connected.
+    pub fn dual_graph(&self, options: DualGraphOptions) -> DualGraph
+    {
+        let mut dual_graph = DualGraph::default();
+        for (edge_index, hyperedge) in self.edges() {
+            if (options.edge_filter)(hyperedge.edge_type()) {
+                dual_graph.adjacency.insert(edge_index, BTreeMap::new());
+            }
+        }
+
+        for (node_index, incident_edges) in self.incidence_list().iter().enumerate() {
+            if !(options.member_filter)(&GraphEntity::from(&self.nodes()[node_index])) {
+                continue;
+            }
+            let incident_edges = incident_edges
+                .iter()
+                .filter(|edge_index| dual_graph.adjacency.contains_key(edge_index))
+                .copied()
+                .collect::<BTreeSet<_>>();
+            for edge_index in &incident_edges {
+                let neighbours = dual_graph.adjacency.get_mut(edge_index).expect("filtered on the adjacency");
+                for other_edge_index in &incident_edges {
+                    if other_edge_index != edge_index {
+                        *neighbours.entry(*other_edge_index).or_default() += 1;
+                    }
+                }
+            }
+        }
+        dual_graph
+    }
+
+    /// Renders the `DualGraph` in DOT format. The width of a connection is
+    /// the number of shared members, which shows the interaction density.
+    pub fn dual_graph_to_dot(&self, dual_graph: &DualGraph) -> String
+    {
+        let mut dot = String::new();
+        writeln!(dot, "graph DualGraph {{").unwrap();
+        for edge_index in dual_graph.edge_indices() {
+            writeln!(
+                dot,
+                "    e{edge_index} [shape=diamond, label=\"{}\"];",
+                edge_label(self.hyperedges()[edge_index].edge_type())
+            )
+            .unwrap();
+        }
+        for (edge_index, neighbours) in &dual_graph.adjacency {
+            for (other_edge_index, shared_members) in neighbours.range(edge_index + 1..) {
+                writeln!(dot, "    e{edge_index} -- e{other_edge_index} [penwidth={shared_members}];").unwrap();
+            }
+        }
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::DualGraphOptions;
+    use crate::events::GraphEntity;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_dual_graph()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                basic_start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let end = basic_start_date.and_hms_opt(16, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        }
+
+        // 1001 connects the first two assignments, 1002 is alone on the
+        // third.
+        let first = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), period)
+            .unwrap();
+        let second = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), period)
+            .unwrap();
+        let third = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1002), WorkOrderNumber::new(1122334477).unwrap(), period)
+            .unwrap();
+        let [first, second, third] = [first, second, third].map(|assignment_id| schedule_graph.assignment_edge_index(assignment_id).unwrap());
+
+        let dual_graph = schedule_graph.dual_graph(DualGraphOptions {
+            edge_filter: |edge_type| matches!(edge_type, EdgeType::Assign(_)),
+            member_filter: |graph_entity| matches!(graph_entity, GraphEntity::Technician(_)),
+        });
+
+        assert_eq!(dual_graph.edge_indices().collect::<Vec<_>>(), vec![first, second, third]);
+        assert_eq!(dual_graph.neighbours(first), Some(&BTreeMap::from([(second, 1)])));
+        assert_eq!(dual_graph.degree(third), 0);
+        assert_eq!(dual_graph.most_entangled(), vec![(first, 1), (second, 1), (third, 0)]);
+        assert_eq!(dual_graph.clusters(), vec![BTreeSet::from([first, second])]);
+
+        let dot = schedule_graph.dual_graph_to_dot(&dual_graph);
+        assert!(dot.contains(&format!("e{first} -- e{second} [penwidth=1];")));
+
+        // Sharing the period connects every assignment.
+        let dual_graph = schedule_graph.dual_graph(DualGraphOptions {
+            edge_filter: |edge_type| matches!(edge_type, EdgeType::Assign(_)),
+            ..DualGraphOptions::default()
+        });
+        assert_eq!(dual_graph.neighbours(first), Some(&BTreeMap::from([(second, 2), (third, 1)])));
+        assert_eq!(dual_graph.clusters().len(), 1);
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillLevel;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+/// The payload of an `EdgeType::EffectiveFrom` hyperedge. A change holds
+/// from its day until a later change of the same kind supersedes it.
+#[derive(Hash, Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TechnicianChange
+{
+    /// FORMAT
+    /// `vec![$technician, $skill]`
+    SkillGained(SkillLevel),
+    /// FORMAT
+    /// `vec![$technician, $skill]`
+    SkillLost,
+    /// FORMAT
+    /// `vec![$technician]`
+    ///
+    /// The technician is not available from the day on.
+    ContractEnded,
+}
+
+/// A `TechnicianChange` as returned by `ScheduleGraph::technician_changes`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatedTechnicianChange
+{
+    pub effective_from: NaiveDate,
+    pub skill: Option<Skill>,
+    pub technician_change: TechnicianChange,
+}
+
+/// Public API for effective-dated changes of technicians. A change is
+/// recorded as an `EdgeType::EffectiveFrom` hyperedge instead of mutating
+/// the technician, so the technician of any day can be looked up after the
+/// fact.
+///
+/// The queries that are scoped by days respect the changes: activity
+/// assignments, strict availability, the `CapacityCalendar` and the
+/// `ValidationReport`. The skills of a `Technician` and the levels of
+/// `ScheduleGraph::add_assign_skill_to_worker` hold before the first
+/// change.
+impl ScheduleGraph
+{
+    /// The technician has the skill at the level from the day on.
+    pub fn gain_skill_from(
+        &mut self,
+        technician_id: TechnicianId,
+        skill: Skill,
+        skill_level: SkillLevel,
+        effective_from: NaiveDate,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        self.add_technician_change(technician_id, Some(skill), effective_from, TechnicianChange::SkillGained(skill_level))
+    }
+
+    /// The technician no longer has the skill from the day on.
+    pub fn lose_skill_from(&mut self, technician_id: TechnicianId, skill: Skill, effective_from: NaiveDate)
+    -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        self.add_technician_change(technician_id, Some(skill), effective_from, TechnicianChange::SkillLost)
+    }
+
+    /// Moves the technician from one work center to another from the day
+    /// on. The `Skill` of an activity is the work center that executes it.
+    pub fn move_work_center_from(
+        &mut self,
+        technician_id: TechnicianId,
+        from_work_center: Skill,
+        to_work_center: Skill,
+        skill_level: SkillLevel,
+        effective_from: NaiveDate,
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            graph.lose_skill_from(technician_id, from_work_center, effective_from)?;
+            graph.gain_skill_from(technician_id, to_work_center, skill_level, effective_from)?;
+            Ok(())
+        })
+    }
+
+    /// The technician is not available from the day on. An earlier end of
+    /// the contract is replaced, e.g. when the contract is extended.
+    pub fn end_contract(&mut self, technician_id: TechnicianId, effective_from: NaiveDate) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            let technician_node_index = graph.technician_node_index(technician_id)?;
+            let previous_edge_indices = graph.incidence_list()[technician_node_index]
+                .iter()
+                .copied()
+                .filter(|&edge_index| {
+                    matches!(
+                        graph.hyperedges()[edge_index].edge_type(),
+                        EdgeType::EffectiveFrom(_, TechnicianChange::ContractEnded)
+                    )
+                })
+                .collect::<Vec<_>>();
+            for edge_index in previous_edge_indices {
+                graph.remove_edge(edge_index)?;
+            }
+
+            graph.add_technician_change(technician_id, None, effective_from, TechnicianChange::ContractEnded)
+        })
+    }
+
+    /// The day from which the technician is no longer available, if the
+    /// contract ends.
+    pub fn contract_end(&self, technician_id: TechnicianId) -> Result<Option<NaiveDate>, ScheduleGraphErrors>
+    {
+        let technician_node_index = self.technician_node_index(technician_id)?;
+        Ok(self.node_contract_end(technician_node_index))
+    }
+
+    /// Every change of the technician, ordered by the day it takes effect
+    /// and then by the order it was recorded.
+    pub fn technician_changes(&self, technician_id: TechnicianId) -> Result<Vec<DatedTechnicianChange>, ScheduleGraphErrors>
+    {
+        let technician_node_index = self.technician_node_index(technician_id)?;
+        Ok(self.node_technician_changes(technician_node_index))
+    }
+
+    /// `ScheduleGraph::technician_skill_level` on the day.
+    pub fn technician_skill_level_on(
+        &self,
+        technician_id: TechnicianId,
+        skill: Skill,
+        day: NaiveDate,
+    ) -> Result<Option<SkillLevel>, ScheduleGraphErrors>
+    {
+        let technician_node_index = self.technician_node_index(technician_id)?;
+        let latest_change = self
+            .node_technician_changes(technician_node_index)
+            .into_iter()
+            .rfind(|dated_technician_change| dated_technician_change.effective_from <= day && dated_technician_change.skill == Some(skill));
+
+        match latest_change.map(|dated_technician_change| dated_technician_change.technician_change) {
+            Some(TechnicianChange::SkillGained(skill_level)) => Ok(Some(skill_level)),
+            Some(TechnicianChange::SkillLost) => Ok(None),
+            _ => self.technician_skill_level(technician_id, skill),
+        }
+    }
+
+    /// The skills of the technician on the day.
+    pub fn technician_skills_on(&self, technician_id: TechnicianId, day: NaiveDate) -> Result<BTreeSet<Skill>, ScheduleGraphErrors>
+    {
+        let technician_node_index = self.technician_node_index(technician_id)?;
+        let skills = self.node_skills(technician_node_index, |edge_type| {
+            matches!(edge_type, EdgeType::Available(_) | EdgeType::HasSkill(_))
+        });
+        Ok(self.skills_on(technician_node_index, skills, day))
+    }
+
+    /// Whether the contract of the technician has not ended on the day.
+    pub fn technician_employed_on(&self, technician_id: TechnicianId, day: NaiveDate) -> Result<bool, ScheduleGraphErrors>
+    {
+        Ok(self.contract_end(technician_id)?.is_none_or(|contract_end| day < contract_end))
+    }
+
+    /// Applies the changes of the technician up to and including the day
+    /// to the skills.
+    pub(crate) fn skills_on(&self, technician_node_index: NodeIndex, mut skills: BTreeSet<Skill>, day: NaiveDate) -> BTreeSet<Skill>
+    {
+        for dated_technician_change in self.node_technician_changes(technician_node_index) {
+            let Some(skill) = dated_technician_change.skill else {
+                continue;
+            };
+            if dated_technician_change.effective_from > day {
+                break;
+            }
+            match dated_technician_change.technician_change {
+                TechnicianChange::SkillGained(_) => skills.insert(skill),
+                TechnicianChange::SkillLost => skills.remove(&skill),
+                TechnicianChange::ContractEnded => false,
+            };
+        }
+        skills
+    }
+
+    pub(crate) fn node_contract_end(&self, technician_node_index: NodeIndex) -> Option<NaiveDate>
+    {
+        self.incidence_list()[technician_node_index]
+            .iter()
+            .find_map(|&edge_index| match self.hyperedges()[edge_index].edge_type() {
+                EdgeType::EffectiveFrom(effective_from, TechnicianChange::ContractEnded) => Some(*effective_from),
+                _ => None,
+            })
+    }
+
+    fn node_technician_changes(&self, technician_node_index: NodeIndex) -> Vec<DatedTechnicianChange>
+    {
+        let mut technician_changes = self.incidence_list()[technician_node_index]
+            .iter()
+            .filter_map(|&edge_index| {
+                let hyperedge = &self.hyperedges()[edge_index];
+                let EdgeType::EffectiveFrom(effective_from, technician_change) = hyperedge.edge_type() else {
+                    return None;
+                };
+                let skill = hyperedge.nodes().get(1).and_then(|node_index| match &self.nodes()[*node_index] {
+                    Node::Skill(skill) => Some(*skill),
+                    _ => None,
+                });
+                Some((
+                    edge_index,
+                    DatedTechnicianChange {
+                        effective_from: *effective_from,
+                        skill,
+                        technician_change: *technician_change,
+                    },
+                ))
+            })
+            .collect::<Vec<_>>();
+        technician_changes.sort_by_key(|(edge_index, dated_technician_change)| (dated_technician_change.effective_from, *edge_index));
+        technician_changes
+            .into_iter()
+            .map(|(_, dated_technician_change)| dated_technician_change)
+            .collect()
+    }
+
+    fn add_technician_change(
+        &mut self,
+        technician_id: TechnicianId,
+        skill: Option<Skill>,
+        effective_from: NaiveDate,
+        technician_change: TechnicianChange,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let mut nodes = vec![self.technician_node_index(technician_id)?];
+        if let Some(skill) = skill {
+            nodes.push(self.skill_node_index(skill)?);
+        }
+
+        Ok(self.journaled(|graph| graph.add_edge(EdgeType::EffectiveFrom(effective_from, technician_change), nodes)))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeSet;
+
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillLevel;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_effective_dating()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_ELEC).with_skill_level(SkillLevel::Journeyman)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = start_date.and_hms_opt(7, 0, 0).unwrap();
+        let end = (start_date + Duration::days(6)).and_hms_opt(19, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        // The mechanic is retrained as an electrician on Wednesday and
+        // leaves on Saturday.
+        let wednesday = start_date + Duration::days(2);
+        let saturday = start_date + Duration::days(5);
+        schedule_graph
+            .move_work_center_from(
+                TechnicianId::new(1001),
+                Skill::MTN_MECH,
+                Skill::MTN_ELEC,
+                SkillLevel::Journeyman,
+                wednesday,
+            )
+            .unwrap();
+        schedule_graph
+            .end_contract(TechnicianId::new(1001), start_date + Duration::days(6))
+            .unwrap();
+        schedule_graph.end_contract(TechnicianId::new(1001), saturday).unwrap();
+        assert_eq!(schedule_graph.contract_end(TechnicianId::new(1001)), Ok(Some(saturday)));
+        assert_eq!(schedule_graph.technician_changes(TechnicianId::new(1001)).unwrap().len(), 3);
+
+        assert_eq!(
+            schedule_graph.technician_skills_on(TechnicianId::new(1001), start_date),
+            Ok(BTreeSet::from([Skill::MTN_MECH]))
+        );
+        assert_eq!(
+            schedule_graph.technician_skills_on(TechnicianId::new(1001), wednesday),
+            Ok(BTreeSet::from([Skill::MTN_ELEC]))
+        );
+        assert_eq!(
+            schedule_graph.technician_skill_level_on(TechnicianId::new(1001), Skill::MTN_MECH, wednesday),
+            Ok(None)
+        );
+        assert_eq!(
+            schedule_graph.technician_skill_level_on(TechnicianId::new(1001), Skill::MTN_ELEC, wednesday),
+            Ok(Some(SkillLevel::Journeyman))
+        );
+        assert_eq!(schedule_graph.technician_employed_on(TechnicianId::new(1001), saturday), Ok(false));
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift
+            ),
+            Err(ScheduleGraphErrors::TechnicianUnqualified {
+                technician_id: TechnicianId::new(1001)
+            })
+        );
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![wednesday, saturday],
+                shift
+            ),
+            Err(ScheduleGraphErrors::TechnicianUnavailable {
+                technician_id: TechnicianId::new(1001),
+                start: saturday.and_hms_opt(8, 0, 0).unwrap(),
+                finish: saturday.and_hms_opt(16, 0, 0).unwrap(),
+            })
+        );
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![wednesday],
+                shift,
+            )
+            .unwrap();
+
+        // The capacity follows the technician across the work centers.
+        let capacity_calendar = schedule_graph.capacity_calendar(&[period]).unwrap();
+        let period_capacity = &capacity_calendar.0[&period];
+        assert_eq!(
+            period_capacity.skills[&Skill::MTN_MECH].technicians[&TechnicianId::new(1001)].days.len(),
+            2
+        );
+        assert_eq!(
+            period_capacity.skills[&Skill::MTN_ELEC].technicians[&TechnicianId::new(1001)].days.len(),
+            3
+        );
+        assert!(schedule_graph.validate().is_valid());
+    }
+}
@@ -0,0 +1,354 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Priority;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::capacity::CapacityCalendar;
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+/// A work order assignment that an `EjectionChain` moves to another period.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Displacement
+{
+    pub assignment_id: AssignmentId,
+    pub work_order_number: WorkOrderNumber,
+    pub technician_id: TechnicianId,
+    pub from: Period,
+    pub to: Period,
+}
+
+/// The assignments to move so that the work order fits in the period. Each
+/// displacement makes room for the work that was moved into its `from`
+/// period, the work order itself for the first one, and the last one lands
+/// in a period with room to spare.
+///
+/// The chain is only a proposal, nothing is changed until it is committed
+/// with `ScheduleGraph::apply_ejection_chain`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EjectionChain
+{
+    pub work_order_number: WorkOrderNumber,
+    pub technician_id: TechnicianId,
+    pub period: Period,
+    pub displacements: Vec<Displacement>,
+}
+
+/// A chain under construction, with the planned work of every period after
+/// its displacements.
+struct PartialChain
+{
+    demand: BTreeMap<Period, BTreeMap<Skill, Work>>,
+    displacements: Vec<Displacement>,
+    period: Period,
+    priority: Priority,
+}
+
+/// Public API to displace lower priority work for urgent work orders.
+impl ScheduleGraph
+{
+    /// The shortest `EjectionChain` of at most `max_length` displacements
+    /// that fits the work order in the period for the technician. A period
+    /// fits when the planned work of no skill exceeds its capacity, see
+    /// `planned_work_demand` and `capacity_calendar`.
+    ///
+    /// Only work order assignments of a strictly lower `Priority` than the
+    /// work that displaces them are moved, and they keep their technician.
+    /// A work order is never moved into a period that excludes it or into a
+    /// period that the chain already passed through. Chains of the same
+    /// length are tried in the order of the periods and assignments, and a
+    /// chain is only returned if `apply_ejection_chain` accepts it, so
+    /// frozen days, intake limits and dependencies are respected.
+    ///
+    /// `None` if no chain is short enough.
+    pub fn ejection_chain(
+        &self,
+        technician_id: TechnicianId,
+        work_order_number: WorkOrderNumber,
+        period: Period,
+        max_length: usize,
+    ) -> Result<Option<EjectionChain>, ScheduleGraphErrors>
+    {
+        let firm_work_load = |work_order_number| -> Result<BTreeMap<Skill, Work>, ScheduleGraphErrors> {
+            if self.work_order_commitment(work_order_number)? == Commitment::Forecast {
+                return Ok(BTreeMap::new());
+            }
+            self.work_order_work_load(work_order_number)
+        };
+
+        self.technician_node_index(technician_id)?;
+        self.period_node_index(period)?;
+        let priority = self.work_order_priority(work_order_number)?;
+
+        let mut periods = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Period(period) => Some(*period),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        periods.sort();
+        let capacity_calendar = self.capacity_calendar(&periods)?;
+
+        let mut demand = self.planned_work_demand(&periods)?;
+        add_work_load(demand.entry(period).or_default(), &firm_work_load(work_order_number)?);
+
+        let mut partial_chains = VecDeque::from([PartialChain {
+            demand,
+            displacements: vec![],
+            period,
+            priority,
+        }]);
+        while let Some(partial_chain) = partial_chains.pop_front() {
+            if !overloaded(&capacity_calendar, &partial_chain.demand, partial_chain.period) {
+                let ejection_chain = EjectionChain {
+                    work_order_number,
+                    technician_id,
+                    period,
+                    displacements: partial_chain.displacements,
+                };
+                if self.clone().apply_ejection_chain(&ejection_chain).is_ok() {
+                    return Ok(Some(ejection_chain));
+                }
+                continue;
+            }
+            if partial_chain.displacements.len() == max_length {
+                continue;
+            }
+
+            let visited_periods = partial_chain
+                .displacements
+                .iter()
+                .flat_map(|displacement| [displacement.from, displacement.to])
+                .chain([period])
+                .collect::<BTreeSet<_>>();
+            let mut assignments = self.assignments_for_period(partial_chain.period)?;
+            assignments.sort_by_key(|assignment| assignment.assignment_id);
+            for assignment in assignments {
+                if assignment.activity.is_some()
+                    || assignment.work_order == work_order_number
+                    || partial_chain
+                        .displacements
+                        .iter()
+                        .any(|displacement| displacement.assignment_id == assignment.assignment_id)
+                    || self.work_order_priority(assignment.work_order)? <= partial_chain.priority
+                {
+                    continue;
+                }
+
+                let work_load = firm_work_load(assignment.work_order)?;
+                let mut demand = partial_chain.demand.clone();
+                remove_work_load(demand.entry(partial_chain.period).or_default(), &work_load);
+                if overloaded(&capacity_calendar, &demand, partial_chain.period) {
+                    continue;
+                }
+
+                let excluded_periods = self.periods_excluding(assignment.work_order)?;
+                for to in periods
+                    .iter()
+                    .filter(|to| !visited_periods.contains(to) && !excluded_periods.contains(to))
+                {
+                    let mut demand = demand.clone();
+                    add_work_load(demand.entry(*to).or_default(), &work_load);
+                    let mut displacements = partial_chain.displacements.clone();
+                    displacements.push(Displacement {
+                        assignment_id: assignment.assignment_id,
+                        work_order_number: assignment.work_order,
+                        technician_id: assignment.technicians[0],
+                        from: partial_chain.period,
+                        to: *to,
+                    });
+                    partial_chains.push_back(PartialChain {
+                        demand,
+                        displacements,
+                        period: *to,
+                        priority: self.work_order_priority(assignment.work_order)?,
+                    });
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Commits the chain as a single transaction. The displaced work orders
+    /// are assigned to their new periods before the work order is assigned
+    /// to the period of the chain, and on an error the graph is rolled back.
+    ///
+    /// Returns the assignment of the work order followed by the new
+    /// assignments of the displaced work orders, in the order of the chain.
+    pub fn apply_ejection_chain(&mut self, ejection_chain: &EjectionChain) -> Result<Vec<AssignmentId>, ScheduleGraphErrors>
+    {
+        self.transaction(|graph| {
+            let mut displaced_assignment_ids = vec![];
+            for displacement in &ejection_chain.displacements {
+                graph.remove_assignment(displacement.assignment_id)?;
+                displaced_assignment_ids.push(graph.add_assignment_work_order(
+                    displacement.technician_id,
+                    displacement.work_order_number,
+                    displacement.to,
+                )?);
+            }
+            let assignment_id =
+                graph.add_assignment_work_order(ejection_chain.technician_id, ejection_chain.work_order_number, ejection_chain.period)?;
+
+            Ok([assignment_id].into_iter().chain(displaced_assignment_ids).collect())
+        })
+    }
+}
+
+fn overloaded(capacity_calendar: &CapacityCalendar, demand: &BTreeMap<Period, BTreeMap<Skill, Work>>, period: Period) -> bool
+{
+    demand.get(&period).into_iter().flatten().any(|(skill, work)| {
+        let capacity = capacity_calendar
+            .0
+            .get(&period)
+            .and_then(|period_capacity| period_capacity.skills.get(skill))
+            .map_or(Work::ZERO, |skill_capacity| skill_capacity.total_hours);
+        *work > capacity
+    })
+}
+
+fn add_work_load(skill_demand: &mut BTreeMap<Skill, Work>, work_load: &BTreeMap<Skill, Work>)
+{
+    for (skill, work) in work_load {
+        *skill_demand.entry(*skill).or_default() += *work;
+    }
+}
+
+fn remove_work_load(skill_demand: &mut BTreeMap<Skill, Work>, work_load: &BTreeMap<Skill, Work>)
+{
+    for (skill, work) in work_load {
+        *skill_demand.entry(*skill).or_default() -= *work;
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Priority;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::Displacement;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_ejection_chain()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let first_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let second_period = Period::from_start_date(first_period.end_date());
+        let third_period = Period::from_start_date(second_period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+
+        // One mechanic with 8 hours in each period.
+        for (technician_id, period) in [(1001, first_period), (1002, second_period), (1003, third_period)] {
+            schedule_graph.add_period(period).unwrap();
+            let start = period.start_date().and_hms_opt(8, 0, 0).unwrap();
+            let end = period.start_date().and_hms_opt(16, 0, 0).unwrap();
+            let technician = Technician::builder(TechnicianId::new(technician_id))
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        }
+
+        let work_order = |work_order_number, hours, priority| {
+            WorkOrder::new(
+                WorkOrderNumber::new(work_order_number).unwrap(),
+                first_period.start_date(),
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_work(Work::from_hours(hours))],
+            )
+            .unwrap()
+            .with_priority(priority)
+        };
+        schedule_graph.add_work_order(&work_order(1122334455, 6.0, Priority::Medium)).unwrap();
+        schedule_graph.add_work_order(&work_order(1122334466, 6.0, Priority::Low)).unwrap();
+        schedule_graph.add_work_order(&work_order(1122334477, 4.0, Priority::VeryHigh)).unwrap();
+        schedule_graph
+            .add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &third_period)
+            .unwrap();
+
+        let medium_assignment_id = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), first_period)
+            .unwrap();
+        let low_assignment_id = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), second_period)
+            .unwrap();
+
+        // The medium priority work order can only make room in the second
+        // period, which pushes the low priority work order to the third.
+        let urgent_work_order_number = WorkOrderNumber::new(1122334477).unwrap();
+        assert_eq!(
+            schedule_graph
+                .ejection_chain(TechnicianId::new(1001), urgent_work_order_number, first_period, 1)
+                .unwrap(),
+            None
+        );
+        let ejection_chain = schedule_graph
+            .ejection_chain(TechnicianId::new(1001), urgent_work_order_number, first_period, 2)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            ejection_chain.displacements,
+            vec![
+                Displacement {
+                    assignment_id: medium_assignment_id,
+                    work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                    technician_id: TechnicianId::new(1001),
+                    from: first_period,
+                    to: second_period,
+                },
+                Displacement {
+                    assignment_id: low_assignment_id,
+                    work_order_number: WorkOrderNumber::new(1122334466).unwrap(),
+                    technician_id: TechnicianId::new(1001),
+                    from: second_period,
+                    to: third_period,
+                },
+            ]
+        );
+
+        // Nothing has moved until the chain is committed.
+        assert!(schedule_graph.assignment(medium_assignment_id).is_ok());
+
+        let assignment_ids = schedule_graph.apply_ejection_chain(&ejection_chain).unwrap();
+        assert_eq!(assignment_ids.len(), 3);
+        assert!(schedule_graph.assignment(medium_assignment_id).is_err());
+        let periods = [first_period, second_period, third_period];
+        let planned_work_demand = schedule_graph.planned_work_demand(&periods).unwrap();
+        for (period, hours) in periods.iter().zip([4.0, 6.0, 6.0]) {
+            assert_eq!(planned_work_demand[period][&Skill::MTN_MECH], Work::from_hours(hours));
+        }
+
+        // A work order of the same priority is never displaced.
+        schedule_graph.add_work_order(&work_order(1122334488, 6.0, Priority::VeryHigh)).unwrap();
+        assert_eq!(
+            schedule_graph
+                .ejection_chain(TechnicianId::new(1001), WorkOrderNumber::new(1122334488).unwrap(), first_period, 3)
+                .unwrap(),
+            None
+        );
+    }
+}
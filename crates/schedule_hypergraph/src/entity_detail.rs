@@ -0,0 +1,411 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillLevel;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Priority;
+use scheduling_environment::work_order::RiskClassification;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::conflicts::ScheduleConflict;
+use crate::dependencies::DependencyViolation;
+use crate::effective_dating::DatedTechnicianChange;
+use crate::events::GraphEntity;
+use crate::provenance::Authorship;
+use crate::provenance::DecisionSource;
+use crate::safety::SafetyOverride;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::PrecedenceViolation;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::timestamps::Timestamps;
+use crate::validation::AvailabilityViolation;
+use crate::validation::SkillMismatch;
+use crate::validation::WorkOverrun;
+
+/// Everything known about a node of the graph, so that a UI can render a
+/// detail pane from a single call, see `ScheduleGraph::describe`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityDetail
+{
+    pub graph_entity: GraphEntity,
+    /// Only work orders, technicians and periods have attributes.
+    pub attributes: Option<EntityAttributes>,
+    /// The hyperedges of the node ordered by `EdgeIndex`.
+    pub relations: Vec<Relation>,
+    /// The entries of the `ValidationReport` that involve the entity.
+    pub violations: Vec<Violation>,
+    pub history: EntityHistory,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityAttributes
+{
+    WorkOrder
+    {
+        priority: Priority,
+        revision: Option<String>,
+        due_date: Option<NaiveDate>,
+        commitment: Commitment,
+        risk_classification: RiskClassification,
+        carry_over_count: usize,
+    },
+    Technician
+    {
+        /// The skills before the first effective-dated change.
+        skills: BTreeMap<Skill, SkillLevel>,
+        technician_changes: Vec<DatedTechnicianChange>,
+        contract_end: Option<NaiveDate>,
+    },
+    Period
+    {
+        start_date: NaiveDate,
+        /// The first day after the period.
+        end_date: NaiveDate,
+        archived: bool,
+        closed: bool,
+    },
+}
+
+/// A hyperedge of the node decoded into domain types.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Relation
+{
+    pub edge_index: EdgeIndex,
+    pub edge_type: EdgeType,
+    /// The other nodes of the hyperedge in the order of the hyperedge.
+    pub entities: Vec<GraphEntity>,
+    pub decision_source: Option<DecisionSource>,
+}
+
+/// An entry of the `ValidationReport`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Violation
+{
+    SkillMismatch(SkillMismatch),
+    AvailabilityViolation(AvailabilityViolation),
+    WorkOverrun(WorkOverrun),
+    PrecedenceViolation(WorkOrderNumber, PrecedenceViolation),
+    DependencyViolation(DependencyViolation),
+    ScheduleConflict(ScheduleConflict),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityHistory
+{
+    pub timestamps: Timestamps,
+    /// Who made the assignments of the entity.
+    pub authorship: Authorship,
+    /// Only work orders have safety overrides.
+    pub safety_overrides: Vec<SafetyOverride>,
+}
+
+/// Public API to inspect a single node of the graph.
+impl ScheduleGraph
+{
+    /// Assembles the attributes, relations, violations and history of the
+    /// entity. The whole graph is validated to find the violations.
+    pub fn describe(&self, graph_entity: &GraphEntity) -> Result<EntityDetail, ScheduleGraphErrors>
+    {
+        let node_index = self.entity_node_index(graph_entity)?;
+
+        let mut edge_indices = self.incidence_list()[node_index].clone();
+        edge_indices.sort();
+        let relations = edge_indices
+            .iter()
+            .map(|edge_index| {
+                let hyperedge = &self.hyperedges()[*edge_index];
+                Relation {
+                    edge_index: *edge_index,
+                    edge_type: hyperedge.edge_type().clone(),
+                    entities: hyperedge
+                        .nodes()
+                        .iter()
+                        .filter(|other_node_index| **other_node_index != node_index)
+                        .map(|other_node_index| GraphEntity::from(&self.nodes()[*other_node_index]))
+                        .collect(),
+                    decision_source: self.decision_sources().get(edge_index).cloned(),
+                }
+            })
+            .collect();
+
+        let safety_overrides = match graph_entity {
+            GraphEntity::WorkOrder(work_order_number) => self
+                .safety_overrides()
+                .iter()
+                .filter(|safety_override| safety_override.work_order_number == *work_order_number)
+                .cloned()
+                .collect(),
+            _ => vec![],
+        };
+
+        Ok(EntityDetail {
+            graph_entity: graph_entity.clone(),
+            attributes: self.entity_attributes(graph_entity)?,
+            relations,
+            violations: self.entity_violations(graph_entity),
+            history: EntityHistory {
+                timestamps: self.timestamps(graph_entity)?,
+                authorship: self.authorship_of(edge_indices),
+                safety_overrides,
+            },
+        })
+    }
+
+    fn entity_attributes(&self, graph_entity: &GraphEntity) -> Result<Option<EntityAttributes>, ScheduleGraphErrors>
+    {
+        let entity_attributes = match graph_entity {
+            GraphEntity::WorkOrder(work_order_number) => EntityAttributes::WorkOrder {
+                priority: self.work_order_priority(*work_order_number)?,
+                revision: self.work_order_revision(*work_order_number)?.map(str::to_string),
+                due_date: self.work_order_due_date(*work_order_number)?,
+                commitment: self.work_order_commitment(*work_order_number)?,
+                risk_classification: self.risk_classification(*work_order_number)?,
+                carry_over_count: self.carry_over_count(*work_order_number),
+            },
+            GraphEntity::Technician(technician_id) => {
+                let technician_node_index = self.technician_node_index(*technician_id)?;
+                let mut skills = BTreeMap::new();
+                for skill in self.node_skills(technician_node_index, |edge_type| {
+                    matches!(edge_type, EdgeType::Available(_) | EdgeType::HasSkill(_))
+                }) {
+                    if let Some(skill_level) = self.technician_skill_level(*technician_id, skill)? {
+                        skills.insert(skill, skill_level);
+                    }
+                }
+                EntityAttributes::Technician {
+                    skills,
+                    technician_changes: self.technician_changes(*technician_id)?,
+                    contract_end: self.contract_end(*technician_id)?,
+                }
+            }
+            GraphEntity::Period(period) => EntityAttributes::Period {
+                start_date: period.start_date(),
+                end_date: period.end_date(),
+                archived: self.is_period_archived(period),
+                closed: self.is_period_closed(*period),
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(entity_attributes))
+    }
+
+    fn entity_violations(&self, graph_entity: &GraphEntity) -> Vec<Violation>
+    {
+        let involves_work_order = |work_order_number: WorkOrderNumber| *graph_entity == GraphEntity::WorkOrder(work_order_number);
+        let involves_period = |period: &Period| *graph_entity == GraphEntity::Period(*period);
+        let involves_day = |day: NaiveDate| matches!(graph_entity, GraphEntity::Period(period) if period.contains(&day));
+
+        let validation_report = self.validate();
+        let mut violations = vec![];
+        for skill_mismatch in validation_report.skill_mismatches {
+            if involves_work_order(skill_mismatch.work_order_number) || *graph_entity == GraphEntity::Technician(skill_mismatch.technician_id) {
+                violations.push(Violation::SkillMismatch(skill_mismatch));
+            }
+        }
+        for availability_violation in validation_report.availability_violations {
+            if involves_work_order(availability_violation.work_order_number)
+                || *graph_entity == GraphEntity::Technician(availability_violation.technician_id)
+                || involves_day(availability_violation.start.date())
+            {
+                violations.push(Violation::AvailabilityViolation(availability_violation));
+            }
+        }
+        for work_overrun in validation_report.work_overruns {
+            if involves_work_order(work_overrun.work_order_number) {
+                violations.push(Violation::WorkOverrun(work_overrun));
+            }
+        }
+        for (work_order_number, precedence_violations) in validation_report.precedence_violations {
+            if involves_work_order(work_order_number) {
+                violations.extend(
+                    precedence_violations
+                        .into_iter()
+                        .map(|precedence_violation| Violation::PrecedenceViolation(work_order_number, precedence_violation)),
+                );
+            }
+        }
+        for dependency_violation in validation_report.dependency_violations {
+            if involves_work_order(dependency_violation.predecessor) || involves_work_order(dependency_violation.successor) {
+                violations.push(Violation::DependencyViolation(dependency_violation));
+            }
+        }
+        for schedule_conflict in validation_report.schedule_conflicts {
+            let involved = match &schedule_conflict {
+                ScheduleConflict::DoubleBooking {
+                    technician_id,
+                    first,
+                    second,
+                    start,
+                    ..
+                } => {
+                    *graph_entity == GraphEntity::Technician(*technician_id)
+                        || involves_work_order(first.0)
+                        || involves_work_order(second.0)
+                        || involves_day(start.date())
+                }
+                ScheduleConflict::ExcludedPeriod {
+                    work_order_number, period, ..
+                } => involves_work_order(*work_order_number) || involves_period(period),
+            };
+            if involved {
+                violations.push(Violation::ScheduleConflict(schedule_conflict));
+            }
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillLevel;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Priority;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::EntityAttributes;
+    use super::Violation;
+    use crate::conflicts::ScheduleConflict;
+    use crate::events::GraphEntity;
+    use crate::provenance::DecisionSource;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_describe()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap()
+        .with_priority(Priority::High);
+        schedule_graph.add_work_order(&work_order).unwrap();
+        schedule_graph
+            .add_work_order(
+                &WorkOrder::new(
+                    WorkOrderNumber::new(1122334466).unwrap(),
+                    start_date,
+                    vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        schedule_graph
+            .add_assign_skill_to_worker(TechnicianId::new(1001), Skill::MTN_MECH, SkillLevel::Master)
+            .unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph.set_decision_source(Some(DecisionSource::User {
+            user_id: "planner".to_string(),
+        }));
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            schedule_graph
+                .add_assignment_activity(
+                    vec![TechnicianId::new(1001)],
+                    work_order_number,
+                    ActivityNumber::new(10),
+                    vec![start_date],
+                    shift,
+                )
+                .unwrap();
+        }
+
+        let entity_detail = schedule_graph
+            .describe(&GraphEntity::WorkOrder(WorkOrderNumber::new(1122334455).unwrap()))
+            .unwrap();
+        assert!(matches!(
+            entity_detail.attributes,
+            Some(EntityAttributes::WorkOrder {
+                priority: Priority::High,
+                carry_over_count: 0,
+                ..
+            })
+        ));
+        let contains = entity_detail
+            .relations
+            .iter()
+            .find(|relation| relation.edge_type == EdgeType::Contains)
+            .unwrap();
+        assert_eq!(
+            contains.entities,
+            vec![GraphEntity::Activity(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10))]
+        );
+        assert!(matches!(
+            entity_detail.violations.as_slice(),
+            [Violation::ScheduleConflict(ScheduleConflict::DoubleBooking { .. })]
+        ));
+
+        let entity_detail = schedule_graph.describe(&GraphEntity::Technician(TechnicianId::new(1001))).unwrap();
+        let Some(EntityAttributes::Technician { skills, contract_end, .. }) = entity_detail.attributes else {
+            panic!("expected technician attributes");
+        };
+        assert_eq!(skills[&Skill::MTN_MECH], SkillLevel::Master);
+        assert_eq!(contract_end, None);
+        assert_eq!(entity_detail.history.authorship.user, 2);
+        assert_eq!(entity_detail.violations.len(), 1);
+
+        let entity_detail = schedule_graph.describe(&GraphEntity::Period(period)).unwrap();
+        assert!(matches!(
+            entity_detail.attributes,
+            Some(EntityAttributes::Period {
+                archived: false,
+                closed: false,
+                ..
+            })
+        ));
+        assert_eq!(entity_detail.violations.len(), 1);
+
+        let entity_detail = schedule_graph.describe(&GraphEntity::Skill(Skill::MTN_MECH)).unwrap();
+        assert_eq!(entity_detail.attributes, None);
+        assert!(entity_detail.violations.is_empty());
+
+        assert_eq!(
+            schedule_graph.describe(&GraphEntity::WorkOrder(WorkOrderNumber::new(1122334477).unwrap())),
+            Err(ScheduleGraphErrors::WorkOrderMissing {
+                work_order_number: WorkOrderNumber::new(1122334477).unwrap(),
+            })
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::AssignmentView;
+use crate::schedule_graph::ContractorPoolId;
+use crate::schedule_graph::CrewId;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::EquipmentId;
+use crate::schedule_graph::FunctionalLocationId;
+use crate::schedule_graph::MaterialId;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ShutdownWindowId;
+use crate::schedule_graph::TechnicianId;
+use crate::schedule_graph::WorkPackageId;
+
+/// A node of the graph in domain types.
+#[derive(Hash, Clone, Debug, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GraphEntity
+{
+    Technician(TechnicianId),
+    WorkOrder(WorkOrderNumber),
+    Activity(WorkOrderNumber, ActivityNumber),
+    Period(Period),
+    Skill(Skill),
+    Day(NaiveDate),
+    WorkPackage(WorkPackageId),
+    Crew(CrewId),
+    FunctionalLocation(FunctionalLocationId),
+    Equipment(EquipmentId),
+    Material(MaterialId),
+    ContractorPool(ContractorPoolId),
+    ShutdownWindow(ShutdownWindowId),
+}
+
+impl From<&Node> for GraphEntity
+{
+    fn from(node: &Node) -> Self
+    {
+        match node {
+            Node::Technician(technician_id) => GraphEntity::Technician(*technician_id),
+            Node::WorkOrder(work_order_number) => GraphEntity::WorkOrder(*work_order_number),
+            Node::Activity(activity) => GraphEntity::Activity(activity.work_order_number(), activity.activity_number()),
+            Node::Period(period) => GraphEntity::Period(*period),
+            Node::Skill(skill) => GraphEntity::Skill(*skill),
+            Node::Day(day) => GraphEntity::Day(*day),
+            Node::WorkPackage(work_package_id) => GraphEntity::WorkPackage(*work_package_id),
+            Node::Crew(crew_id) => GraphEntity::Crew(*crew_id),
+            Node::FunctionalLocation(functional_location_id) => GraphEntity::FunctionalLocation(functional_location_id.clone()),
+            Node::Equipment(equipment_id) => GraphEntity::Equipment(*equipment_id),
+            Node::Material(material_id) => GraphEntity::Material(*material_id),
+            Node::ContractorPool(contractor_pool_id) => GraphEntity::ContractorPool(*contractor_pool_id),
+            Node::ShutdownWindow(shutdown_window_id) => GraphEntity::ShutdownWindow(*shutdown_window_id),
+        }
+    }
+}
+
+/// Emitted for every mutation of the graph.
+///
+/// `EdgeType::Assign` hyperedges are reported as `AssignmentAdded` and
+/// `AssignmentRemoved` instead of `EdgeAdded` and `EdgeRemoved`. Nodes are
+/// only removed when a mutation is undone.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GraphEvent
+{
+    NodeAdded(GraphEntity),
+    NodeRemoved(GraphEntity),
+    EdgeAdded
+    {
+        edge_index: EdgeIndex,
+        edge_type: EdgeType,
+    },
+    EdgeRemoved
+    {
+        edge_index: EdgeIndex,
+        edge_type: EdgeType,
+    },
+    AssignmentAdded(AssignmentView),
+    AssignmentRemoved(AssignmentView),
+}
+
+/// Receives the `GraphEvent`s of a `ScheduleGraph`. Listeners are called
+/// synchronously while the graph is mutated, so they should hand the event
+/// off instead of doing expensive work.
+pub trait GraphListener: Send + Sync
+{
+    fn on_event(&self, event: &GraphEvent);
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct Listeners(Vec<Arc<dyn GraphListener>>);
+
+impl fmt::Debug for Listeners
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "Listeners({})", self.0.len())
+    }
+}
+
+/// Public API to subscribe to the mutations of the graph.
+impl ScheduleGraph
+{
+    /// Registers a listener for every following mutation. A clone of the
+    /// graph shares the listeners of the original.
+    ///
+    /// A rolled back `transaction` does not emit events for the rollback.
+    pub fn add_listener(&mut self, listener: Arc<dyn GraphListener>)
+    {
+        self.listeners_mut().0.push(listener);
+    }
+
+    /// Builds the event lazily so that a graph without listeners does no
+    /// extra work.
+    pub(crate) fn emit(&self, event: impl FnOnce(&ScheduleGraph) -> GraphEvent)
+    {
+        let listeners = &self.listeners().0;
+        if listeners.is_empty() {
+            return;
+        }
+
+        let event = event(self);
+        for listener in listeners {
+            listener.on_event(&event);
+        }
+    }
+
+    pub(crate) fn edge_added_event(&self, edge_index: EdgeIndex) -> GraphEvent
+    {
+        match self.assignment_view(edge_index) {
+            Some(assignment) => GraphEvent::AssignmentAdded(assignment),
+            None => GraphEvent::EdgeAdded {
+                edge_index,
+                edge_type: self.hyperedges()[edge_index].edge_type().clone(),
+            },
+        }
+    }
+
+    pub(crate) fn edge_removed_event(&self, edge_index: EdgeIndex) -> GraphEvent
+    {
+        match self.assignment_view(edge_index) {
+            Some(assignment) => GraphEvent::AssignmentRemoved(assignment),
+            None => GraphEvent::EdgeRemoved {
+                edge_index,
+                edge_type: self.hyperedges()[edge_index].edge_type().clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::GraphEntity;
+    use super::GraphEvent;
+    use super::GraphListener;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[derive(Default)]
+    struct EventCollector(Mutex<Vec<GraphEvent>>);
+
+    impl GraphListener for EventCollector
+    {
+        fn on_event(&self, event: &GraphEvent)
+        {
+            self.0.lock().unwrap().push(event.clone());
+        }
+    }
+
+    #[test]
+    fn test_graph_events()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let availability_start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = basic_start_date.and_hms_opt(17, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let event_collector = Arc::new(EventCollector::default());
+        schedule_graph.add_listener(event_collector.clone());
+        schedule_graph.enable_journal();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let events = std::mem::take(&mut *event_collector.0.lock().unwrap());
+        assert_eq!(
+            events[0],
+            GraphEvent::NodeAdded(GraphEntity::WorkOrder(WorkOrderNumber::new(1122334455).unwrap()))
+        );
+        assert!(events.contains(&GraphEvent::NodeAdded(GraphEntity::Activity(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            ActivityNumber::new(10)
+        ))));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GraphEvent::EdgeAdded {
+                edge_type: EdgeType::Requires,
+                ..
+            }
+        )));
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        schedule_graph
+            .unassign_activity(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+            )
+            .unwrap();
+
+        let events = std::mem::take(&mut *event_collector.0.lock().unwrap());
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], GraphEvent::AssignmentAdded(assignment) if assignment.technicians == vec![TechnicianId::new(1001)]));
+        assert!(matches!(&events[1], GraphEvent::AssignmentRemoved(assignment) if assignment.technicians == vec![TechnicianId::new(1001)]));
+
+        // Undoing emits the inverse events.
+        schedule_graph.undo();
+        schedule_graph.undo();
+        schedule_graph.undo();
+        let events = std::mem::take(&mut *event_collector.0.lock().unwrap());
+        assert!(matches!(events[0], GraphEvent::AssignmentAdded(_)));
+        assert!(matches!(events[1], GraphEvent::AssignmentRemoved(_)));
+        assert_eq!(
+            events.last(),
+            Some(&GraphEvent::NodeRemoved(GraphEntity::WorkOrder(
+                WorkOrderNumber::new(1122334455).unwrap()
+            )))
+        );
+    }
+}
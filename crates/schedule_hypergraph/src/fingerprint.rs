@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use crate::schedule_graph::ScheduleGraph;
+
+/// A content hash of a `ScheduleGraph`, see `ScheduleGraph::fingerprint`.
+#[derive(Hash, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fingerprint(pub u64);
+
+impl std::fmt::Display for Fingerprint
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// Public API to identify the content of a graph, e.g. as a cache key, to
+/// detect that another agent changed the plan, or to verify that a replica
+/// matches the primary after applying the same patches.
+impl ScheduleGraph
+{
+    /// Hashes the canonical form of the graph, see
+    /// `ScheduleGraph::canonicalize`, so graphs with the same content have
+    /// the same fingerprint regardless of the order they were built in and
+    /// of their removed hyperedges. The `Timestamps`, the `AssignmentId`s of
+    /// the hyperedges, the undo history and the `SystemClock` are not part
+    /// of the content.
+    ///
+    /// The hash is a 64-bit FNV-1a of the `Debug` representation of the
+    /// content, so it is stable across runs and machines, but not
+    /// necessarily across versions of the crate.
+    pub fn fingerprint(&self) -> Fingerprint
+    {
+        let mut canonical_graph = self.fork();
+        canonical_graph.canonicalize();
+
+        let mut fingerprint_hasher = FingerprintHasher::default();
+        write!(
+            fingerprint_hasher,
+            "{:?}{:?}{:?}{:?}{:?}{:?}{:?}{:?}{:?}",
+            canonical_graph.nodes(),
+            canonical_graph.hyperedges(),
+            canonical_graph.labor_rule_overrides(),
+            canonical_graph.decision_sources(),
+            canonical_graph.intake_limits(),
+            canonical_graph.skill_hour_entries(),
+            canonical_graph.period_close_outs(),
+            canonical_graph.period_baselines(),
+            canonical_graph.config(),
+        )
+        .expect("the hasher never fails");
+        Fingerprint(fingerprint_hasher.0)
+    }
+}
+
+/// 64-bit FNV-1a over the formatted bytes.
+struct FingerprintHasher(u64);
+
+impl Default for FingerprintHasher
+{
+    fn default() -> Self
+    {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Write for FingerprintHasher
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result
+    {
+        for byte in s.bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+
+    #[test]
+    fn test_fingerprint()
+    {
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        let work_order = |work_order_number| {
+            WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap()
+        };
+
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph
+            .add_work_order(&work_order(WorkOrderNumber::new(1122334455).unwrap()))
+            .unwrap();
+        schedule_graph
+            .add_work_order(&work_order(WorkOrderNumber::new(1122334466).unwrap()))
+            .unwrap();
+
+        let mut other_schedule_graph = ScheduleGraph::new();
+        other_schedule_graph.add_period(period).unwrap();
+        other_schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        other_schedule_graph
+            .add_work_order(&work_order(WorkOrderNumber::new(1122334466).unwrap()))
+            .unwrap();
+        let exclusion_edge_index = other_schedule_graph
+            .add_exclusion(&WorkOrderNumber::new(1122334466).unwrap(), &period)
+            .unwrap();
+        other_schedule_graph
+            .add_work_order(&work_order(WorkOrderNumber::new(1122334455).unwrap()))
+            .unwrap();
+
+        let fingerprint = schedule_graph.fingerprint();
+        assert_eq!(schedule_graph.fingerprint(), fingerprint);
+        assert_eq!(schedule_graph.fork().fingerprint(), fingerprint);
+        assert_eq!(fingerprint.to_string().len(), 16);
+        assert_ne!(other_schedule_graph.fingerprint(), fingerprint);
+
+        other_schedule_graph.remove_edge(exclusion_edge_index).unwrap();
+        assert_eq!(other_schedule_graph.fingerprint(), fingerprint);
+
+        schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &period).unwrap();
+        assert_ne!(schedule_graph.fingerprint(), fingerprint);
+    }
+}
@@ -0,0 +1,179 @@
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::Technician;
+use scheduling_environment::work_order::Activity;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::ActivityRelation;
+use scheduling_environment::work_order::RiskClassification;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrder;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::Shift;
+use crate::schedule_graph::TechnicianId;
+
+pub const PERIOD_COUNT: usize = 3;
+pub const TECHNICIAN_COUNT: usize = 12;
+pub const WORK_ORDER_COUNT: usize = 80;
+/// Every `ASSIGNED_WORK_ORDER_STEP`th work order has its first activity
+/// assigned on its basic start date.
+pub const ASSIGNED_WORK_ORDER_STEP: usize = 8;
+/// Every `EXCLUDED_WORK_ORDER_STEP`th work order, starting from the fifth,
+/// is excluded from the last period. None of them are assigned.
+pub const EXCLUDED_WORK_ORDER_STEP: usize = 10;
+
+pub const FIRST_WORK_ORDER_NUMBER: u64 = 2_000_000_000;
+pub const FIRST_TECHNICIAN_ID: TechnicianId = TechnicianId::new(1001);
+
+/// Start date of the first period of the fixture. It is a Monday.
+pub fn start_date() -> NaiveDate
+{
+    NaiveDate::from_ymd_opt(2025, 1, 6).unwrap()
+}
+
+/// The `PERIOD_COUNT` consecutive bi-weekly periods of the fixture.
+pub fn periods() -> Vec<Period>
+{
+    let mut periods = vec![Period::from_start_date(start_date())];
+    while periods.len() < PERIOD_COUNT {
+        periods.push(Period::from_start_date(periods.last().unwrap().end_date()));
+    }
+    periods
+}
+
+pub fn work_order_numbers() -> impl Iterator<Item = WorkOrderNumber>
+{
+    (0..WORK_ORDER_COUNT as u64).map(|offset| WorkOrderNumber::new(FIRST_WORK_ORDER_NUMBER + offset).unwrap())
+}
+
+pub fn technician_ids() -> impl Iterator<Item = TechnicianId>
+{
+    (0..TECHNICIAN_COUNT).map(|offset| TechnicianId::new(FIRST_TECHNICIAN_ID.get() + offset))
+}
+
+/// Day shift used for the assignments of the fixture.
+pub fn day_shift() -> Shift
+{
+    Shift::new(
+        NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+        NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+        false,
+    )
+    .unwrap()
+}
+
+/// A realistic mid-size instance shared by the integration tests of every
+/// crate, so that downstream teams do not have to maintain their own.
+///
+/// * `PERIOD_COUNT` bi-weekly periods starting on `start_date()`.
+/// * `TECHNICIAN_COUNT` technicians available for the whole horizon. Half of
+///   them are mechanics, a third are both mechanics and electricians, and the
+///   rest are electricians.
+/// * `WORK_ORDER_COUNT` work orders with one to three activities, spread over
+///   the horizon, where every fifth is HSE-critical.
+/// * A few assignments and exclusions, see `ASSIGNED_WORK_ORDER_STEP` and
+///   `EXCLUDED_WORK_ORDER_STEP`. No technician is double booked.
+///
+/// The instance is built with the same methods as any other graph and is
+/// deterministic.
+pub fn mid_size_instance() -> ScheduleGraph
+{
+    let mut schedule_graph = ScheduleGraph::new();
+
+    schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+    schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+
+    let periods = periods();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+    let horizon_days = (periods.last().unwrap().end_date() - start_date()).num_days();
+
+    for (offset, technician_id) in technician_ids().enumerate() {
+        let availability_start = start_date().and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = (periods.last().unwrap().end_date() - Duration::days(1)).and_hms_opt(23, 59, 59).unwrap();
+
+        let technician_builder = Technician::builder(technician_id)
+            .add_availability(availability_start, availability_end)
+            .unwrap();
+        let technician_builder = match offset % 6 {
+            0..=2 => technician_builder.add_skill(Skill::MTN_MECH),
+            3 | 4 => technician_builder.add_skill(Skill::MTN_MECH).add_skill(Skill::MTN_ELEC),
+            _ => technician_builder.add_skill(Skill::MTN_ELEC),
+        };
+
+        schedule_graph
+            .add_technician(technician_builder.build(), Availability::new(availability_start, availability_end))
+            .unwrap();
+    }
+
+    for (offset, work_order_number) in work_order_numbers().enumerate() {
+        let basic_start_date = start_date() + Duration::days((offset as i64 * 5) % horizon_days);
+
+        let activities = (0..1 + offset % 3)
+            .map(|activity_offset| {
+                let skill = if (offset + activity_offset) % 3 == 0 {
+                    Skill::MTN_ELEC
+                } else {
+                    Skill::MTN_MECH
+                };
+                let number_of_people = 1 + (offset % 2) as u64;
+                let duration_hours = 8 * (1 + (offset + activity_offset) % 2) as i64;
+                Activity::new(ActivityNumber::new(10 * (activity_offset as u64 + 1)), number_of_people, skill)
+                    .with_work(Work::from_duration(Duration::hours(duration_hours)) * number_of_people)
+                    .with_duration(Duration::hours(duration_hours))
+            })
+            .collect::<Vec<_>>();
+        let activity_relations = (1..activities.len())
+            .map(|relation_offset| match (offset + relation_offset) % 4 {
+                0 => ActivityRelation::StartStart,
+                1 => ActivityRelation::Postpone(Duration::hours(24)),
+                _ => ActivityRelation::FinishStart,
+            })
+            .collect();
+
+        let work_order = WorkOrder::new(work_order_number, basic_start_date, activities)
+            .unwrap()
+            .with_activity_relations(activity_relations)
+            .unwrap()
+            .with_risk_classification(RiskClassification {
+                hse_critical: offset % 5 == 0,
+                simops_restricted: offset % 20 == 0,
+            });
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        if offset % ASSIGNED_WORK_ORDER_STEP == 0 {
+            let first_activity = &work_order.activities()[0];
+            let technician_id = technician_ids()
+                .enumerate()
+                .filter(|(technician_offset, _)| match first_activity.skill() {
+                    Skill::MTN_MECH => technician_offset % 6 <= 4,
+                    _ => technician_offset % 6 >= 3,
+                })
+                .map(|(_, technician_id)| technician_id)
+                .nth(offset / ASSIGNED_WORK_ORDER_STEP % 4)
+                .unwrap();
+
+            schedule_graph
+                .add_assignment_activity(
+                    vec![technician_id],
+                    work_order_number,
+                    first_activity.activity_number(),
+                    vec![basic_start_date],
+                    day_shift(),
+                )
+                .unwrap();
+        }
+
+        if offset % EXCLUDED_WORK_ORDER_STEP == 5 {
+            schedule_graph.add_exclusion(&work_order_number, periods.last().unwrap()).unwrap();
+        }
+    }
+
+    schedule_graph
+}
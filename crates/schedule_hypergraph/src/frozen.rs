@@ -0,0 +1,265 @@
+use chrono::Days;
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::config::GraphConfig;
+use crate::safety::SafetyBypass;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The end of the frozen window, see `ScheduleGraph::freeze_until`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FreezeHorizon
+{
+    /// The day is the last frozen day.
+    Day(NaiveDate),
+    /// The last day of the period is the last frozen day.
+    Period(Period),
+}
+
+impl From<NaiveDate> for FreezeHorizon
+{
+    fn from(day: NaiveDate) -> Self
+    {
+        FreezeHorizon::Day(day)
+    }
+}
+
+impl From<Period> for FreezeHorizon
+{
+    fn from(period: Period) -> Self
+    {
+        FreezeHorizon::Period(period)
+    }
+}
+
+/// Public API for the frozen horizon. The plan of the days in the frozen
+/// window is handed over to execution, so assignments and exclusions that
+/// touch one of those days are rejected with
+/// `ScheduleGraphErrors::FrozenPeriod`.
+///
+/// A work order assignment or an exclusion touches every day of its period.
+/// The days of archived periods, see `ScheduleGraph::roll_horizon`, are
+/// frozen regardless of the frozen window.
+/// HSE-critical work orders can be exempted from the frozen window with the
+/// `SafetyPolicy`.
+/// `ScheduleGraph::remove_edge`, undo and redo are not checked, they are the
+/// tools to repair a frozen plan.
+impl ScheduleGraph
+{
+    /// Freezes every day up to and including the horizon. A later call
+    /// replaces the horizon, also with an earlier one.
+    pub fn freeze_until(&mut self, freeze_horizon: impl Into<FreezeHorizon>)
+    {
+        let frozen_until = match freeze_horizon.into() {
+            FreezeHorizon::Day(day) => day,
+            FreezeHorizon::Period(period) => period.end_date() - Days::new(1),
+        };
+        self.config_mut().frozen_until = Some(frozen_until);
+    }
+
+    pub fn unfreeze(&mut self)
+    {
+        self.config_mut().frozen_until = None;
+    }
+
+    /// The last frozen day.
+    pub fn frozen_until(&self) -> Option<NaiveDate>
+    {
+        self.config().frozen_until
+    }
+
+    pub(crate) fn check_frozen_days(&self, days: &[NaiveDate]) -> Result<(), ScheduleGraphErrors>
+    {
+        let GraphConfig { frozen_until, .. } = self.config();
+        if let Some(day) = days.iter().find(|day| frozen_until.is_some_and(|frozen_until| **day <= frozen_until)) {
+            return Err(ScheduleGraphErrors::FrozenPeriod { day: *day });
+        }
+        self.check_archived_days(days)
+    }
+
+    /// `ScheduleGraph::check_frozen_days` for work of the work order. An
+    /// HSE-critical work order may be placed in the frozen window if the
+    /// `SafetyPolicy` allows it, the archived days stay frozen.
+    pub(crate) fn check_frozen_days_of(&self, work_order_number: WorkOrderNumber, days: &[NaiveDate]) -> Result<(), ScheduleGraphErrors>
+    {
+        if self.safety_exempt(work_order_number, SafetyBypass::FrozenWindow) {
+            self.check_archived_days(days)
+        } else {
+            self.check_frozen_days(days)
+        }
+    }
+
+    fn check_archived_days(&self, days: &[NaiveDate]) -> Result<(), ScheduleGraphErrors>
+    {
+        let GraphConfig { horizon_start, .. } = self.config();
+        if let Some(day) = days.iter().find(|day| horizon_start.is_some_and(|horizon_start| **day < horizon_start)) {
+            return Err(ScheduleGraphErrors::FrozenPeriod { day: *day });
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_frozen_period(&self, period: &Period) -> Result<(), ScheduleGraphErrors>
+    {
+        self.check_frozen_days(&[period.start_date()])
+    }
+
+    /// Checks the days and periods that the hyperedge connects.
+    pub(crate) fn check_frozen_edge(&self, edge_index: EdgeIndex) -> Result<(), ScheduleGraphErrors>
+    {
+        for node_index in self.hyperedges()[edge_index].nodes() {
+            match &self.nodes()[*node_index] {
+                Node::Day(day) => self.check_frozen_days(&[*day])?,
+                Node::Period(period) => self.check_frozen_period(period)?,
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_freeze_until()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = (start_date + Duration::days(27)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let frozen_assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(2)],
+                shift,
+            )
+            .unwrap();
+
+        schedule_graph.freeze_until(start_date + Duration::days(2));
+        assert_eq!(schedule_graph.frozen_until(), Some(start_date + Duration::days(2)));
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(2)],
+                shift
+            ),
+            Err(ScheduleGraphErrors::FrozenPeriod {
+                day: start_date + Duration::days(2)
+            })
+        );
+        assert_eq!(
+            schedule_graph.move_assignment(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(5)],
+                shift
+            ),
+            Err(ScheduleGraphErrors::FrozenPeriod {
+                day: start_date + Duration::days(2)
+            })
+        );
+        assert_eq!(
+            schedule_graph.unassign_activity(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10)
+            ),
+            Err(ScheduleGraphErrors::FrozenPeriod {
+                day: start_date + Duration::days(2)
+            })
+        );
+        assert_eq!(
+            schedule_graph.remove_assignment(frozen_assignment_id),
+            Err(ScheduleGraphErrors::FrozenPeriod {
+                day: start_date + Duration::days(2)
+            })
+        );
+        assert_eq!(
+            schedule_graph.add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), period),
+            Err(ScheduleGraphErrors::FrozenPeriod { day: start_date })
+        );
+        assert_eq!(
+            schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334466).unwrap(), &period),
+            Err(ScheduleGraphErrors::FrozenPeriod { day: start_date })
+        );
+        assert!(schedule_graph.assignment(frozen_assignment_id).is_ok());
+
+        // The days after the frozen window are still open.
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(3)],
+                shift,
+            )
+            .unwrap();
+
+        schedule_graph.freeze_until(period);
+        assert_eq!(schedule_graph.frozen_until(), Some(period.end_date() - Duration::days(1)));
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), next_period)
+            .unwrap();
+        schedule_graph
+            .add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &next_period)
+            .unwrap();
+
+        schedule_graph.unfreeze();
+        schedule_graph.remove_assignment(frozen_assignment_id).unwrap();
+    }
+}
@@ -0,0 +1,422 @@
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+
+use crate::schedule_graph::AssignmentView;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The periods that `ScheduleGraph::roll_horizon` archived and appended.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HorizonRoll
+{
+    pub archived_periods: Vec<Period>,
+    pub added_periods: Vec<Period>,
+}
+
+/// The history that `ScheduleGraph::prune_archived_periods` removed from
+/// the graph, so that it can be stored outside of it.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HorizonArchive
+{
+    /// The first day of the horizon when the archive was pruned.
+    pub horizon_start: NaiveDate,
+    pub assignments: Vec<AssignmentView>,
+    /// The pruned hyperedges with the nodes that they connected, including
+    /// the assignments. They are kept for serialization of the archive.
+    pub(crate) hyperedges: Vec<(EdgeType, Vec<Node>)>,
+    pub baselines: Vec<(Period, Vec<AssignmentView>)>,
+}
+
+/// Public API for the rolling planning horizon. The periods that start
+/// before the horizon are archived. Their days are frozen, see
+/// `ScheduleGraph::freeze_until`, and their history can be pruned from the
+/// graph so that a long-running graph does not grow without bounds.
+impl ScheduleGraph
+{
+    /// Archives the periods that start before `new_first_period` and
+    /// appends periods of `GraphConfig::period_length` at the end, so that
+    /// the horizon keeps the number of periods that it had before.
+    pub fn roll_horizon(&mut self, new_first_period: Period) -> Result<HorizonRoll, ScheduleGraphErrors>
+    {
+        self.period_node_index(new_first_period)?;
+        let horizon_start = new_first_period.start_date();
+        if self
+            .config()
+            .horizon_start
+            .is_some_and(|previous_horizon_start| horizon_start < previous_horizon_start)
+        {
+            return Err(ScheduleGraphErrors::HorizonBackwards);
+        }
+
+        let periods = self.periods();
+        let horizon_length = periods.iter().filter(|period| !self.is_period_archived(period)).count();
+        let mut horizon_roll = HorizonRoll {
+            archived_periods: periods
+                .iter()
+                .filter(|period| !self.is_period_archived(period) && period.start_date() < horizon_start)
+                .copied()
+                .collect(),
+            added_periods: vec![],
+        };
+
+        let mut active_periods = periods.iter().filter(|period| period.start_date() >= horizon_start).count();
+        let mut end_date = periods.iter().map(Period::end_date).max().expect("the new first period is in the graph");
+        while active_periods < horizon_length {
+            self.add_period_starting(end_date)?;
+            let added_period = Period::new(end_date, self.config().period_length);
+            horizon_roll.added_periods.push(added_period);
+            end_date = added_period.end_date();
+            active_periods += 1;
+        }
+
+        self.config_mut().horizon_start = Some(horizon_start);
+        Ok(horizon_roll)
+    }
+
+    pub fn is_period_archived(&self, period: &Period) -> bool
+    {
+        self.config()
+            .horizon_start
+            .is_some_and(|horizon_start| period.start_date() < horizon_start)
+    }
+
+    /// Removes the assignments, availabilities, exclusions, confirmed work
+    /// and day containment of the archived periods together with their
+    /// baselines, and returns them. Hyperedges that also connect a day of
+    /// the horizon are kept, and so are attributes of work orders like
+    /// `EdgeType::Due` that happen to fall on an archived day.
+    ///
+    /// The day and period nodes stay, as a `NodeIndex` stays valid for the
+    /// lifetime of the graph. The graph is compacted, see
+    /// `ScheduleGraph::compact`, so `EdgeIndex`s are renumbered while
+    /// `AssignmentId`s are kept.
+    ///
+    /// Returns `None` if the horizon was never rolled.
+    pub fn prune_archived_periods(&mut self) -> Option<HorizonArchive>
+    {
+        let horizon_start = self.config().horizon_start?;
+
+        let archived_node = |node: &Node| match node {
+            Node::Day(day) => Some(*day < horizon_start),
+            Node::Period(period) => Some(period.start_date() < horizon_start),
+            _ => None,
+        };
+        let pruned_edge_indices = self
+            .edges()
+            .filter(|(_, hyperedge)| {
+                matches!(
+                    hyperedge.edge_type(),
+                    EdgeType::Assign(_) | EdgeType::Available(_) | EdgeType::Contains | EdgeType::Exclude | EdgeType::ActualWork(_)
+                )
+            })
+            .filter(|(_, hyperedge)| {
+                let archived_nodes = hyperedge
+                    .nodes()
+                    .iter()
+                    .filter_map(|node_index| archived_node(&self.nodes()[*node_index]))
+                    .collect::<Vec<_>>();
+                !archived_nodes.is_empty() && archived_nodes.into_iter().all(|archived| archived)
+            })
+            .map(|(edge_index, _)| edge_index)
+            .collect::<Vec<_>>();
+
+        let mut horizon_archive = HorizonArchive {
+            horizon_start,
+            ..HorizonArchive::default()
+        };
+        for edge_index in &pruned_edge_indices {
+            horizon_archive.assignments.extend(self.assignment_view(*edge_index));
+            let hyperedge = &self.hyperedges()[*edge_index];
+            let nodes = hyperedge.nodes().iter().map(|node_index| self.nodes()[*node_index].clone()).collect();
+            horizon_archive.hyperedges.push((hyperedge.edge_type().clone(), nodes));
+        }
+
+        self.journaled(|graph| {
+            for edge_index in pruned_edge_indices {
+                graph.remove_edge(edge_index).expect("the hyperedge is not removed");
+            }
+        });
+        self.compact();
+
+        let archived_baselines = self
+            .period_baselines()
+            .keys()
+            .filter(|period| period.start_date() < horizon_start)
+            .copied()
+            .collect::<Vec<_>>();
+        for period in archived_baselines {
+            let baseline = self.period_baselines_mut().remove(&period).expect("the baseline is in the graph");
+            horizon_archive.baselines.push((period, baseline));
+        }
+        Some(horizon_archive)
+    }
+
+    fn periods(&self) -> Vec<Period>
+    {
+        self.nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Period(period) => Some(*period),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::PeriodLength;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::HorizonRoll;
+    use crate::config::GraphConfig;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_roll_horizon()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.set_config(GraphConfig {
+            period_length: PeriodLength::Weekly,
+            ..GraphConfig::default()
+        });
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let periods = (0..4)
+            .map(|week| Period::new(start_date + Duration::weeks(week), PeriodLength::Weekly))
+            .collect::<Vec<_>>();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        for period in &periods[..3] {
+            schedule_graph.add_period_starting(period.start_date()).unwrap();
+        }
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = (start_date + Duration::days(20)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let archived_assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift,
+            )
+            .unwrap();
+        let assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(8)],
+                shift,
+            )
+            .unwrap();
+        schedule_graph.commit_baseline(periods[0]).unwrap();
+
+        assert_eq!(
+            schedule_graph.roll_horizon(periods[1]),
+            Ok(HorizonRoll {
+                archived_periods: vec![periods[0]],
+                added_periods: vec![periods[3]],
+            })
+        );
+        assert!(schedule_graph.is_period_archived(&periods[0]));
+        assert!(!schedule_graph.is_period_archived(&periods[1]));
+        assert_eq!(schedule_graph.roll_horizon(periods[0]), Err(ScheduleGraphErrors::HorizonBackwards));
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(1)],
+                shift
+            ),
+            Err(ScheduleGraphErrors::FrozenPeriod {
+                day: start_date + Duration::days(1)
+            })
+        );
+
+        assert_eq!(ScheduleGraph::new().prune_archived_periods(), None);
+        let horizon_archive = schedule_graph.prune_archived_periods().unwrap();
+        assert_eq!(horizon_archive.horizon_start, periods[1].start_date());
+        assert_eq!(
+            horizon_archive
+                .assignments
+                .iter()
+                .map(|assignment| assignment.assignment_id)
+                .collect::<Vec<_>>(),
+            vec![archived_assignment_id]
+        );
+        assert_eq!(horizon_archive.baselines.len(), 1);
+        assert_eq!(
+            schedule_graph.assignment(archived_assignment_id),
+            Err(ScheduleGraphErrors::AssignmentMissing {
+                assignment_id: archived_assignment_id,
+            })
+        );
+        assert!(schedule_graph.assignment(assignment_id).is_ok());
+        assert_eq!(schedule_graph.baseline(periods[0]), None);
+        assert!(schedule_graph.validate().is_valid());
+    }
+
+    #[test]
+    fn test_roll_horizon_rejects_missing_periods_and_archived_changes()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.set_config(GraphConfig {
+            period_length: PeriodLength::Weekly,
+            ..GraphConfig::default()
+        });
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let periods = (0..3)
+            .map(|week| Period::new(start_date + Duration::weeks(week), PeriodLength::Weekly))
+            .collect::<Vec<_>>();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        for period in &periods[..2] {
+            schedule_graph.add_period_starting(period.start_date()).unwrap();
+        }
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = (start_date + Duration::days(13)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift,
+            )
+            .unwrap();
+
+        assert_eq!(
+            schedule_graph.roll_horizon(periods[2]),
+            Err(ScheduleGraphErrors::PeriodMissing {
+                period: periods[2],
+                periods: periods[..2].to_vec(),
+            })
+        );
+        assert_eq!(schedule_graph.config().horizon_start, None);
+
+        schedule_graph.roll_horizon(periods[1]).unwrap();
+
+        // The assignment is in an archived period, so it can no longer be
+        // unassigned.
+        assert_eq!(
+            schedule_graph.unassign_activity(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10)
+            ),
+            Err(ScheduleGraphErrors::FrozenPeriod { day: start_date })
+        );
+        assert!(schedule_graph.assignment(assignment_id).is_ok());
+    }
+
+    #[test]
+    fn test_roll_horizon_after_period_length_change()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let periods = [
+            Period::new(start_date, PeriodLength::BiWeekly),
+            Period::new(start_date + Duration::weeks(2), PeriodLength::BiWeekly),
+        ];
+        for period in &periods {
+            schedule_graph.add_period_starting(period.start_date()).unwrap();
+        }
+
+        // The periods that are appended have the new length and start where
+        // the last bi-weekly period ends.
+        schedule_graph.config_mut().period_length = PeriodLength::Monthly;
+        let monthly_period = Period::new(NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(), PeriodLength::Monthly);
+        assert_eq!(
+            schedule_graph.roll_horizon(periods[1]),
+            Ok(HorizonRoll {
+                archived_periods: vec![periods[0]],
+                added_periods: vec![monthly_period],
+            })
+        );
+        assert_eq!(schedule_graph.period_days(&monthly_period).unwrap().len(), 28);
+
+        // A period of the new length on a day that is already in the
+        // horizon overlaps, and the graph is left as it was.
+        schedule_graph.config_mut().period_length = PeriodLength::Weekly;
+        let nodes = schedule_graph.nodes().len();
+        assert_eq!(
+            schedule_graph.add_period_starting(monthly_period.start_date() + Duration::weeks(1)),
+            Err(ScheduleGraphErrors::PeriodOverlap {
+                period: Period::new(monthly_period.start_date() + Duration::weeks(1), PeriodLength::Weekly),
+                existing: monthly_period,
+            })
+        );
+        assert_eq!(schedule_graph.nodes().len(), nodes);
+
+        let weekly_period = Period::new(monthly_period.end_date(), PeriodLength::Weekly);
+        assert_eq!(
+            schedule_graph.roll_horizon(monthly_period),
+            Ok(HorizonRoll {
+                archived_periods: vec![periods[1]],
+                added_periods: vec![weekly_period],
+            })
+        );
+        assert!(schedule_graph.validate().is_valid());
+    }
+}
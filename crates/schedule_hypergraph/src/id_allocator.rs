@@ -0,0 +1,199 @@
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// Mints the numbers of work orders that are created by the system instead
+/// of imported, e.g. by a preventive maintenance generator or by splitting
+/// a work order. Implement it to take the numbers from another source, like
+/// a number range reserved in SAP.
+pub trait IdAllocator
+{
+    /// A work order number that is not in the graph.
+    fn allocate_work_order_number(&mut self, schedule_graph: &ScheduleGraph) -> Result<WorkOrderNumber, ScheduleGraphErrors>;
+}
+
+/// Allocates `prefix` followed by a counter of `counter_digits` digits. The
+/// default is the ten digit range `9000000000..=9999999999`, which is kept
+/// free of SAP work order numbers, so that generated work orders never
+/// collide with imported ones. The range is exhausted at the first number
+/// that is not a valid `WorkOrderNumber`.
+///
+/// The counter is stored on the graph and serialized with it, so the same
+/// sequence of mutations generates the same numbers across runs. A number
+/// is never handed out twice, even when the work order is undone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrefixedIdAllocator
+{
+    pub prefix: u64,
+    pub counter_digits: u32,
+    /// The counter of the next number.
+    pub counter: u64,
+}
+
+impl Default for PrefixedIdAllocator
+{
+    fn default() -> Self
+    {
+        Self {
+            prefix: 9,
+            counter_digits: 9,
+            counter: 0,
+        }
+    }
+}
+
+impl IdAllocator for PrefixedIdAllocator
+{
+    /// Numbers that are already in the graph are skipped.
+    fn allocate_work_order_number(&mut self, schedule_graph: &ScheduleGraph) -> Result<WorkOrderNumber, ScheduleGraphErrors>
+    {
+        let counter_range = 10_u64.checked_pow(self.counter_digits).ok_or(ScheduleGraphErrors::IdRangeExhausted)?;
+        let first_number = self.prefix.checked_mul(counter_range).ok_or(ScheduleGraphErrors::IdRangeExhausted)?;
+        while self.counter < counter_range {
+            let work_order_number = WorkOrderNumber::new(first_number + self.counter).map_err(|_| ScheduleGraphErrors::IdRangeExhausted)?;
+            self.counter += 1;
+            if schedule_graph.work_order_node_index(work_order_number).is_err() {
+                return Ok(work_order_number);
+            }
+        }
+        Err(ScheduleGraphErrors::IdRangeExhausted)
+    }
+}
+
+/// Public API to mint work order numbers with the allocator of the graph.
+impl ScheduleGraph
+{
+    pub fn set_id_allocator(&mut self, prefixed_id_allocator: PrefixedIdAllocator)
+    {
+        *self.id_allocator_mut() = prefixed_id_allocator;
+    }
+
+    /// The number is only reserved, the work order still has to be added.
+    pub fn allocate_work_order_number(&mut self) -> Result<WorkOrderNumber, ScheduleGraphErrors>
+    {
+        let mut prefixed_id_allocator = self.id_allocator().clone();
+        let work_order_number = prefixed_id_allocator.allocate_work_order_number(self)?;
+        *self.id_allocator_mut() = prefixed_id_allocator;
+        Ok(work_order_number)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::IdAllocator;
+    use super::PrefixedIdAllocator;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_allocate_work_order_number()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+        let work_order_number = schedule_graph.allocate_work_order_number().unwrap();
+        assert_eq!(work_order_number, WorkOrderNumber::new(9_000_000_000).unwrap());
+        schedule_graph
+            .add_work_order(
+                &WorkOrder::new(
+                    work_order_number,
+                    basic_start_date,
+                    vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        // A number that was imported is skipped.
+        schedule_graph
+            .add_work_order(
+                &WorkOrder::new(
+                    WorkOrderNumber::new(9_000_000_001).unwrap(),
+                    basic_start_date,
+                    vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.allocate_work_order_number(),
+            Ok(WorkOrderNumber::new(9_000_000_002).unwrap())
+        );
+
+        let mut prefixed_id_allocator = PrefixedIdAllocator {
+            prefix: 99_999_999,
+            counter_digits: 2,
+            counter: 98,
+        };
+        assert_eq!(
+            prefixed_id_allocator.allocate_work_order_number(&schedule_graph),
+            Ok(WorkOrderNumber::new(9_999_999_998).unwrap())
+        );
+        assert_eq!(
+            prefixed_id_allocator.allocate_work_order_number(&schedule_graph),
+            Ok(WorkOrderNumber::new(9_999_999_999).unwrap())
+        );
+        assert_eq!(
+            prefixed_id_allocator.allocate_work_order_number(&schedule_graph),
+            Err(ScheduleGraphErrors::IdRangeExhausted)
+        );
+
+        schedule_graph.set_id_allocator(prefixed_id_allocator);
+        assert_eq!(schedule_graph.allocate_work_order_number(), Err(ScheduleGraphErrors::IdRangeExhausted));
+    }
+
+    #[test]
+    fn test_allocate_work_order_number_outside_of_the_range()
+    {
+        let schedule_graph = ScheduleGraph::new();
+
+        // The counter range and the first number overflow a `u64`.
+        for mut prefixed_id_allocator in [
+            PrefixedIdAllocator {
+                prefix: 9,
+                counter_digits: 20,
+                counter: 0,
+            },
+            PrefixedIdAllocator {
+                prefix: u64::MAX,
+                counter_digits: 1,
+                counter: 0,
+            },
+        ] {
+            assert_eq!(
+                prefixed_id_allocator.allocate_work_order_number(&schedule_graph),
+                Err(ScheduleGraphErrors::IdRangeExhausted)
+            );
+        }
+
+        // Eleven digit numbers are not valid work order numbers, and the
+        // counter is not advanced past them.
+        let mut prefixed_id_allocator = PrefixedIdAllocator {
+            prefix: 10,
+            counter_digits: 9,
+            counter: 0,
+        };
+        assert_eq!(
+            prefixed_id_allocator.allocate_work_order_number(&schedule_graph),
+            Err(ScheduleGraphErrors::IdRangeExhausted)
+        );
+        assert_eq!(prefixed_id_allocator.counter, 0);
+
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.set_id_allocator(prefixed_id_allocator.clone());
+        assert_eq!(schedule_graph.allocate_work_order_number(), Err(ScheduleGraphErrors::IdRangeExhausted));
+        assert_eq!(schedule_graph.id_allocator(), &prefixed_id_allocator);
+    }
+}
@@ -0,0 +1,470 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrder;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::confirmations::Confirmation;
+use crate::events::GraphEntity;
+use crate::provenance::DecisionSource;
+use crate::schedule_graph::AddedWorkOrder;
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::Shift;
+use crate::schedule_graph::TechnicianId;
+
+/// How the rows of an import are applied to the graph.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportOptions
+{
+    /// Keep importing after a row fails, so that every problem of the
+    /// source is reported at once. By default the import stops at the first
+    /// row that fails.
+    pub accumulate_errors: bool,
+    /// Validate the rows without changing the graph.
+    pub dry_run: bool,
+}
+
+/// A row of the source that could not be imported. `row` is the index of
+/// the row in the imported slice, `column` the field of the row that the
+/// error points at, if it points at a single field, and `entity` the work
+/// order or activity that the row is about.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportError
+{
+    pub row: usize,
+    pub column: Option<&'static str>,
+    pub entity: GraphEntity,
+    pub error: ScheduleGraphErrors,
+}
+
+impl fmt::Display for ImportError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "row {}", self.row)?;
+        if let Some(column) = self.column {
+            write!(f, ", column {column}")?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+/// The outcome of an import. The rows are imported together, so
+/// `imported` is empty if there are any `errors`. On a dry run without
+/// errors `imported` holds what the rows would have created, but the
+/// graph is left as it was.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportReport<T>
+{
+    pub imported: Vec<T>,
+    pub errors: Vec<ImportError>,
+}
+
+impl<T> ImportReport<T>
+{
+    pub fn is_ok(&self) -> bool
+    {
+        self.errors.is_empty()
+    }
+}
+
+/// A line of a plan baseline exported from another planning tool.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlannedAssignment
+{
+    pub technicians: Vec<TechnicianId>,
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub days: Vec<NaiveDate>,
+    pub shift: Shift,
+}
+
+/// Public API to load the data of source systems. Data from source systems
+/// is often of poor quality, so an import can report every row that fails
+/// instead of only the first one, see `ImportOptions`.
+impl ScheduleGraph
+{
+    /// Adds the work orders with `ScheduleGraph::add_work_order`.
+    pub fn import_work_orders(&mut self, work_orders: &[WorkOrder], import_options: ImportOptions) -> ImportReport<AddedWorkOrder>
+    {
+        self.import_rows(
+            work_orders,
+            import_options,
+            |work_order| GraphEntity::WorkOrder(work_order.work_order_number()),
+            |graph, work_order| graph.add_work_order(work_order),
+        )
+    }
+
+    /// Adds the confirmations with `ScheduleGraph::ingest_confirmations`,
+    /// one row at a time, so a final confirmation closes the activity for
+    /// the rows that follow it.
+    pub fn import_confirmations(&mut self, confirmations: &[Confirmation], import_options: ImportOptions) -> ImportReport<EdgeIndex>
+    {
+        self.import_rows(
+            confirmations,
+            import_options,
+            |confirmation| GraphEntity::Activity(confirmation.work_order_number, confirmation.activity_number),
+            |graph, confirmation| {
+                graph
+                    .ingest_confirmations(std::slice::from_ref(confirmation))
+                    .map(|edge_indices| edge_indices[0])
+            },
+        )
+    }
+
+    /// Adds the planned assignments with
+    /// `ScheduleGraph::add_assignment_activity`. The assignments are
+    /// annotated with `DecisionSource::Import`.
+    pub fn import_plan(&mut self, planned_assignments: &[PlannedAssignment], import_options: ImportOptions) -> ImportReport<AssignmentId>
+    {
+        let decision_source = self.current_decision_source_mut().replace(DecisionSource::Import);
+        let mut import_report = self.import_rows(
+            planned_assignments,
+            import_options,
+            |planned_assignment| GraphEntity::Activity(planned_assignment.work_order_number, planned_assignment.activity_number),
+            |graph, planned_assignment| {
+                graph.add_assignment_activity(
+                    planned_assignment.technicians.clone(),
+                    planned_assignment.work_order_number,
+                    planned_assignment.activity_number,
+                    planned_assignment.days.clone(),
+                    planned_assignment.shift,
+                )
+            },
+        );
+        *self.current_decision_source_mut() = decision_source;
+
+        // A planned assignment has several days.
+        for import_error in &mut import_report.errors {
+            if import_error.column == Some("day") {
+                import_error.column = Some("days");
+            }
+        }
+        import_report
+    }
+
+    /// Imports the rows in a single `ScheduleGraph::transaction`, which is
+    /// rolled back if a row fails or on a dry run. Every row is applied to
+    /// the graph before the next one is imported, so rows are also checked
+    /// against the rows before them, e.g. for duplicates.
+    fn import_rows<R, T>(
+        &mut self,
+        rows: &[R],
+        import_options: ImportOptions,
+        entity: impl Fn(&R) -> GraphEntity,
+        import_row: impl Fn(&mut ScheduleGraph, &R) -> Result<T, ScheduleGraphErrors>,
+    ) -> ImportReport<T>
+    {
+        let mut import_report = ImportReport {
+            imported: vec![],
+            errors: vec![],
+        };
+        let _ = self.transaction(|graph| {
+            for (row, record) in rows.iter().enumerate() {
+                match import_row(graph, record) {
+                    Ok(imported) => import_report.imported.push(imported),
+                    Err(error) => {
+                        import_report.errors.push(ImportError {
+                            row,
+                            column: error_column(&error),
+                            entity: entity(record),
+                            error,
+                        });
+                        if !import_options.accumulate_errors {
+                            break;
+                        }
+                    }
+                }
+            }
+            if import_report.is_ok() && !import_options.dry_run {
+                Ok(())
+            } else {
+                Err(())
+            }
+        });
+        if !import_report.is_ok() {
+            import_report.imported.clear();
+        }
+        import_report
+    }
+}
+
+/// The field of an imported row that the error points at.
+fn error_column(error: &ScheduleGraphErrors) -> Option<&'static str>
+{
+    match error {
+        ScheduleGraphErrors::WorkOrderDuplicate { .. } | ScheduleGraphErrors::WorkOrderMissing { .. } => Some("work_order_number"),
+        ScheduleGraphErrors::ActivityMissing { .. } | ScheduleGraphErrors::ActivityClosed { .. } => Some("activity_number"),
+        ScheduleGraphErrors::WorkerMissing { .. } | ScheduleGraphErrors::TechnicianUnavailable { .. } => Some("technician_id"),
+        ScheduleGraphErrors::DayMissing { .. } | ScheduleGraphErrors::FrozenPeriod { .. } => Some("day"),
+        ScheduleGraphErrors::SkillMissing { .. } | ScheduleGraphErrors::WorkOrderActivityMissingSkills { .. } => Some("skill"),
+        ScheduleGraphErrors::ConfirmationInvalid => Some("hours"),
+        ScheduleGraphErrors::InvalidShift => Some("shift"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::ImportError;
+    use super::ImportOptions;
+    use super::PlannedAssignment;
+    use crate::confirmations::Confirmation;
+    use crate::events::GraphEntity;
+    use crate::provenance::DecisionSource;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    fn schedule_graph(basic_start_date: NaiveDate) -> ScheduleGraph
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let start = basic_start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = basic_start_date.and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        schedule_graph
+    }
+
+    fn work_order(work_order_number: u64, basic_start_date: NaiveDate, skill: Skill) -> WorkOrder
+    {
+        WorkOrder::new(
+            WorkOrderNumber::new(work_order_number).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, skill)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_import_work_orders()
+    {
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let mut schedule_graph = schedule_graph(basic_start_date);
+
+        let work_orders = vec![
+            work_order(1122334455, basic_start_date, Skill::MTN_MECH),
+            work_order(1122334455, basic_start_date, Skill::MTN_MECH),
+            work_order(1122334466, basic_start_date, Skill::MTN_ELEC),
+            work_order(1122334477, basic_start_date, Skill::MTN_MECH),
+        ];
+        let expected_errors = vec![
+            ImportError {
+                row: 1,
+                column: Some("work_order_number"),
+                entity: GraphEntity::WorkOrder(WorkOrderNumber::new(1122334455).unwrap()),
+                error: ScheduleGraphErrors::WorkOrderDuplicate {
+                    work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                },
+            },
+            ImportError {
+                row: 2,
+                column: Some("skill"),
+                entity: GraphEntity::WorkOrder(WorkOrderNumber::new(1122334466).unwrap()),
+                error: ScheduleGraphErrors::WorkOrderActivityMissingSkills {
+                    work_order_number: WorkOrderNumber::new(1122334466).unwrap(),
+                    skills: vec![Skill::MTN_ELEC],
+                },
+            },
+        ];
+
+        // Fail fast stops at the duplicate, and nothing is imported.
+        let nodes = schedule_graph.nodes().len();
+        let import_report = schedule_graph.import_work_orders(&work_orders, ImportOptions::default());
+        assert_eq!(import_report.errors, expected_errors[..1]);
+        assert!(import_report.imported.is_empty());
+        assert_eq!(
+            import_report.errors[0].to_string(),
+            "row 1, column work_order_number: work order 1122334455 already exists"
+        );
+
+        let import_options = ImportOptions {
+            accumulate_errors: true,
+            dry_run: false,
+        };
+        let import_report = schedule_graph.import_work_orders(&work_orders, import_options);
+        assert_eq!(import_report.errors, expected_errors);
+        assert_eq!(schedule_graph.nodes().len(), nodes);
+
+        // A dry run of the valid rows reports what they would create.
+        let valid_work_orders = [
+            work_order(1122334455, basic_start_date, Skill::MTN_MECH),
+            work_order(1122334477, basic_start_date, Skill::MTN_MECH),
+        ];
+        let import_report = schedule_graph.import_work_orders(
+            &valid_work_orders,
+            ImportOptions {
+                accumulate_errors: true,
+                dry_run: true,
+            },
+        );
+        assert!(import_report.is_ok());
+        assert_eq!(import_report.imported.len(), 2);
+        assert_eq!(schedule_graph.nodes().len(), nodes);
+
+        let import_report = schedule_graph.import_work_orders(&valid_work_orders, import_options);
+        assert!(import_report.is_ok());
+        assert!(schedule_graph.work_order_node_index(WorkOrderNumber::new(1122334477).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_import_confirmations()
+    {
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let mut schedule_graph = schedule_graph(basic_start_date);
+        schedule_graph
+            .add_work_order(&work_order(1122334455, basic_start_date, Skill::MTN_MECH))
+            .unwrap();
+
+        let confirmation = Confirmation {
+            technician_id: TechnicianId::new(1001),
+            work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            activity_number: ActivityNumber::new(10),
+            day: basic_start_date,
+            hours: Work::from_hours(4.0),
+            final_confirmation: true,
+        };
+        let outside_horizon = basic_start_date - chrono::Days::new(1);
+        let confirmations = vec![
+            Confirmation {
+                hours: Work::ZERO,
+                ..confirmation.clone()
+            },
+            Confirmation {
+                technician_id: TechnicianId::new(1002),
+                ..confirmation.clone()
+            },
+            Confirmation {
+                day: outside_horizon,
+                ..confirmation.clone()
+            },
+            confirmation.clone(),
+            confirmation.clone(),
+        ];
+
+        let import_report = schedule_graph.import_confirmations(
+            &confirmations,
+            ImportOptions {
+                accumulate_errors: true,
+                dry_run: false,
+            },
+        );
+        let rows_and_columns = import_report
+            .errors
+            .iter()
+            .map(|import_error| (import_error.row, import_error.column))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows_and_columns,
+            vec![
+                (0, Some("hours")),
+                (1, Some("technician_id")),
+                (2, Some("day")),
+                (4, Some("activity_number")),
+            ]
+        );
+        assert_eq!(
+            import_report.errors[3].error,
+            ScheduleGraphErrors::ActivityClosed {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                activity_number: ActivityNumber::new(10),
+            }
+        );
+        assert_eq!(
+            schedule_graph.actual_hours(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+            Ok(Work::ZERO)
+        );
+
+        let import_report = schedule_graph.import_confirmations(&confirmations[3..4], ImportOptions::default());
+        assert_eq!(import_report.imported.len(), 1);
+        assert_eq!(
+            schedule_graph.actual_hours(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+            Ok(Work::from_hours(4.0))
+        );
+    }
+
+    #[test]
+    fn test_import_plan()
+    {
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let mut schedule_graph = schedule_graph(basic_start_date);
+        schedule_graph
+            .add_work_order(&work_order(1122334455, basic_start_date, Skill::MTN_MECH))
+            .unwrap();
+        let user = DecisionSource::User {
+            user_id: "planner".to_string(),
+        };
+        schedule_graph.set_decision_source(Some(user.clone()));
+
+        let planned_assignment = PlannedAssignment {
+            technicians: vec![TechnicianId::new(1001)],
+            work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            activity_number: ActivityNumber::new(10),
+            days: vec![basic_start_date],
+            shift: Shift::new(
+                NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+                false,
+            )
+            .unwrap(),
+        };
+        let planned_assignments = vec![
+            PlannedAssignment {
+                activity_number: ActivityNumber::new(20),
+                ..planned_assignment.clone()
+            },
+            planned_assignment.clone(),
+        ];
+
+        let import_report = schedule_graph.import_plan(&planned_assignments, ImportOptions::default());
+        assert_eq!(
+            import_report.errors,
+            vec![ImportError {
+                row: 0,
+                column: Some("activity_number"),
+                entity: GraphEntity::Activity(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(20)),
+                error: ScheduleGraphErrors::ActivityMissing {
+                    work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                    activity_number: ActivityNumber::new(20),
+                    activity_numbers: vec![ActivityNumber::new(10)],
+                },
+            }]
+        );
+
+        let import_report = schedule_graph.import_plan(&planned_assignments[1..], ImportOptions::default());
+        assert!(import_report.is_ok());
+        assert_eq!(
+            schedule_graph.assignment_decision_source(import_report.imported[0]),
+            Ok(Some(&DecisionSource::Import))
+        );
+        assert_eq!(schedule_graph.authorship().import, 1);
+
+        // The decision source of the caller is restored after the import.
+        assert_eq!(schedule_graph.current_decision_source_mut(), &mut Some(user));
+    }
+}
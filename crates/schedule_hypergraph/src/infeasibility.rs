@@ -0,0 +1,183 @@
+use std::collections::BTreeSet;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// A constraint that prevents a work order from being placed in a period.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InfeasibilityReason
+{
+    /// The work order has an `EdgeType::Exclude` on the period.
+    Excluded(Period),
+    /// No technician with the skill is available in the period. For an
+    /// activity with `ResourceAlternative`s none of their skills are
+    /// available either.
+    NoSkillCapacity
+    {
+        period: Period, skill: Skill
+    },
+}
+
+/// Public API to explain why a work order cannot be placed.
+impl ScheduleGraph
+{
+    /// Returns `None` if there is a period that the work order can be placed
+    /// in. Otherwise a set of constraints that together make the work order
+    /// infeasible is returned. The set is irreducible: it holds exactly one
+    /// constraint per period, so relaxing any of them opens up a period.
+    ///
+    /// TODO [ ] - Include capacity shortage and deadlines when `Activity` has
+    /// work hours and `WorkOrder` has a due date.
+    pub fn diagnose_infeasibility(&self, work_order_number: WorkOrderNumber) -> Result<Option<Vec<InfeasibilityReason>>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        let mut excluded_periods = BTreeSet::new();
+        // The skills of each activity's `ResourceAlternative`s, its own
+        // skill first. One of them is enough to perform the activity.
+        let mut alternative_skills = vec![];
+        for edge_index in &self.incidence_list()[work_order_node_index] {
+            let hyperedge = &self.hyperedges()[*edge_index];
+            match hyperedge.edge_type() {
+                EdgeType::Exclude => excluded_periods.extend(hyperedge.nodes().iter().filter_map(|node_index| match &self.nodes()[*node_index] {
+                    Node::Period(period) => Some(*period),
+                    _ => None,
+                })),
+                EdgeType::Contains => {
+                    let activity_node_index = hyperedge.nodes()[1];
+                    let mut skills = vec![];
+                    for activity_edge_index in &self.incidence_list()[activity_node_index] {
+                        let activity_hyperedge = &self.hyperedges()[*activity_edge_index];
+                        if let (EdgeType::Requires, Node::Skill(skill)) =
+                            (activity_hyperedge.edge_type(), &self.nodes()[activity_hyperedge.nodes()[1]])
+                        {
+                            skills.push(*skill);
+                        }
+                    }
+                    if let Node::Activity(activity) = &self.nodes()[activity_node_index] {
+                        skills.extend(activity.alternatives().iter().map(|alternative| alternative.skill));
+                    }
+                    alternative_skills.push(skills);
+                }
+                _ => (),
+            }
+        }
+
+        let mut periods = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Period(period) => Some(*period),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        periods.sort();
+        let capacity_calendar = self.capacity_calendar(&periods)?;
+
+        let mut reasons = vec![];
+        for period in periods {
+            if excluded_periods.contains(&period) {
+                reasons.push(InfeasibilityReason::Excluded(period));
+                continue;
+            }
+
+            let period_capacity = &capacity_calendar.0[&period];
+            let has_capacity = |skill: &Skill| {
+                period_capacity
+                    .skills
+                    .get(skill)
+                    .is_some_and(|skill_capacity| skill_capacity.total_hours > Work::ZERO)
+            };
+            let missing_skill = alternative_skills
+                .iter()
+                .filter(|skills| !skills.iter().any(has_capacity))
+                .find_map(|skills| skills.first());
+            match missing_skill {
+                Some(skill) => reasons.push(InfeasibilityReason::NoSkillCapacity { period, skill: *skill }),
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(reasons))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::InfeasibilityReason;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_diagnose_infeasibility()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let first_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let second_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(first_period).unwrap();
+        schedule_graph.add_period(second_period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            first_period.start_date(),
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_ELEC),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        schedule_graph
+            .add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &first_period)
+            .unwrap();
+
+        let add_technician = |schedule_graph: &mut ScheduleGraph, technician_id, skill| {
+            let start = second_period.start_date().and_hms_opt(8, 0, 0).unwrap();
+            let end = (second_period.start_date() + Duration::days(4)).and_hms_opt(16, 0, 0).unwrap();
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(skill)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        };
+        add_technician(&mut schedule_graph, TechnicianId::new(1001), Skill::MTN_MECH);
+
+        assert_eq!(
+            schedule_graph.diagnose_infeasibility(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(Some(vec![
+                InfeasibilityReason::Excluded(first_period),
+                InfeasibilityReason::NoSkillCapacity {
+                    period: second_period,
+                    skill: Skill::MTN_ELEC,
+                },
+            ]))
+        );
+
+        add_technician(&mut schedule_graph, TechnicianId::new(1002), Skill::MTN_ELEC);
+
+        assert_eq!(schedule_graph.diagnose_infeasibility(WorkOrderNumber::new(1122334455).unwrap()), Ok(None));
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::safety::SafetyBypass;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::Shift;
+use crate::schedule_graph::TechnicianId;
+
+/// Cap on the work that may be introduced into a period after the cap is
+/// set. This models the intake rules agreed between planning and execution
+/// for frozen and near-term periods.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntakeLimit
+{
+    pub max_new_work_orders: Option<usize>,
+    /// Hours of activity assignments, counted per technician.
+    pub max_new_hours: Option<Work>,
+}
+
+/// A number of work orders or of hours, the two things that an
+/// `IntakeLimit` caps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntakeQuantity
+{
+    WorkOrders(usize),
+    Hours(Work),
+}
+
+impl fmt::Display for IntakeQuantity
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            IntakeQuantity::WorkOrders(work_orders) => write!(f, "{work_orders} new work orders"),
+            IntakeQuantity::Hours(hours) => write!(f, "{hours} new hours"),
+        }
+    }
+}
+
+/// The `IntakeLimit` of a period together with what was already in the
+/// period when the limit was set.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct IntakeState
+{
+    intake_limit: IntakeLimit,
+    baseline_work_orders: BTreeSet<WorkOrderNumber>,
+    baseline_hours: Work,
+}
+
+/// Public API to limit the intake of work into periods.
+impl ScheduleGraph
+{
+    /// Limits the work that may be assigned to the period from now on. The
+    /// work already assigned to the period does not count towards the limit.
+    pub fn set_intake_limit(&mut self, period: Period, intake_limit: IntakeLimit) -> Result<(), ScheduleGraphErrors>
+    {
+        let (baseline_work_orders, baseline_hours) = self.period_intake(period)?;
+
+        self.intake_limits_mut().insert(
+            period,
+            IntakeState {
+                intake_limit,
+                baseline_work_orders,
+                baseline_hours,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn remove_intake_limit(&mut self, period: Period) -> Option<IntakeLimit>
+    {
+        self.intake_limits_mut().remove(&period).map(|intake_state| intake_state.intake_limit)
+    }
+
+    pub fn intake_limit(&self, period: Period) -> Option<&IntakeLimit>
+    {
+        self.intake_limits().get(&period).map(|intake_state| &intake_state.intake_limit)
+    }
+
+    /// Work orders and hours introduced into the period since its
+    /// `IntakeLimit` was set. Returns `None` if the period has no limit.
+    pub fn new_intake(&self, period: Period) -> Result<Option<(usize, Work)>, ScheduleGraphErrors>
+    {
+        let Some(intake_state) = self.intake_limits().get(&period) else {
+            return Ok(None);
+        };
+        let (work_orders, hours) = self.period_intake(period)?;
+
+        Ok(Some((
+            work_orders.difference(&intake_state.baseline_work_orders).count(),
+            hours - intake_state.baseline_hours,
+        )))
+    }
+
+    /// Checks that adding the work order with the hours per period keeps
+    /// every `IntakeLimit`. The `released_hours` are removed by the same
+    /// mutation, e.g. when an assignment is moved. HSE-critical work orders
+    /// pass if the `SafetyPolicy` exempts them from the limits.
+    pub(crate) fn check_intake_limits(
+        &self,
+        work_order_number: WorkOrderNumber,
+        added_hours: &BTreeMap<Period, Work>,
+        released_hours: &BTreeMap<Period, Work>,
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        if !self.safety_exempt(work_order_number, SafetyBypass::IntakeLimit)
+            && let Some(intake_limit_exceeded) = self.intake_limit_exceeded(work_order_number, added_hours, released_hours)?
+        {
+            return Err(intake_limit_exceeded);
+        }
+        Ok(())
+    }
+
+    /// The `ScheduleGraphErrors::IntakeLimitExceeded` of the first period
+    /// whose `IntakeLimit` breaks when the work order is added with the
+    /// hours per period, see `ScheduleGraph::check_intake_limits`.
+    pub(crate) fn intake_limit_exceeded(
+        &self,
+        work_order_number: WorkOrderNumber,
+        added_hours: &BTreeMap<Period, Work>,
+        released_hours: &BTreeMap<Period, Work>,
+    ) -> Result<Option<ScheduleGraphErrors>, ScheduleGraphErrors>
+    {
+        for (period, hours) in added_hours {
+            let Some(intake_state) = self.intake_limits().get(period) else {
+                continue;
+            };
+            let (mut work_orders, current_hours) = self.period_intake(*period)?;
+            work_orders.insert(work_order_number);
+
+            let new_work_orders = work_orders.difference(&intake_state.baseline_work_orders).count();
+            if let Some(max_new_work_orders) = intake_state.intake_limit.max_new_work_orders
+                && new_work_orders > max_new_work_orders
+            {
+                return Ok(Some(ScheduleGraphErrors::IntakeLimitExceeded {
+                    period: *period,
+                    limit: IntakeQuantity::WorkOrders(max_new_work_orders),
+                    requested: IntakeQuantity::WorkOrders(new_work_orders),
+                }));
+            }
+
+            let new_hours = current_hours + *hours - released_hours.get(period).copied().unwrap_or_default() - intake_state.baseline_hours;
+            if let Some(max_new_hours) = intake_state.intake_limit.max_new_hours
+                && new_hours > max_new_hours
+            {
+                return Ok(Some(ScheduleGraphErrors::IntakeLimitExceeded {
+                    period: *period,
+                    limit: IntakeQuantity::Hours(max_new_hours),
+                    requested: IntakeQuantity::Hours(new_hours),
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Hours of the shift on the days for each period with an
+    /// `IntakeLimit` that contains at least one of the days.
+    pub(crate) fn hours_per_limited_period(&self, days: &[NaiveDate], shift: &Shift, number_of_technicians: usize) -> BTreeMap<Period, Work>
+    {
+        let mut hours_per_period = BTreeMap::new();
+        for period in self.intake_limits().keys() {
+            let period_days = self.period_days(period).unwrap_or_default();
+            let days_in_period = days.iter().filter(|day| period_days.contains(day)).count();
+            if days_in_period > 0 {
+                hours_per_period.insert(*period, hours(shift) * (days_in_period * number_of_technicians) as u64);
+            }
+        }
+        hours_per_period
+    }
+
+    /// Hours per limited period of the current assignments of the technician
+    /// to the activity.
+    pub(crate) fn assigned_hours_per_limited_period(
+        &self,
+        technician_id: TechnicianId,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<BTreeMap<Period, Work>, ScheduleGraphErrors>
+    {
+        let mut hours_per_period = BTreeMap::<Period, Work>::new();
+        for assignment in self.assignments_for_technician(technician_id)? {
+            if assignment.work_order != work_order_number || assignment.activity != Some(activity_number) {
+                continue;
+            }
+            let Some(shift) = assignment.shift else {
+                continue;
+            };
+            for (period, hours) in self.hours_per_limited_period(&assignment.days, &shift, 1) {
+                *hours_per_period.entry(period).or_default() += hours;
+            }
+        }
+        Ok(hours_per_period)
+    }
+
+    fn period_intake(&self, period: Period) -> Result<(BTreeSet<WorkOrderNumber>, Work), ScheduleGraphErrors>
+    {
+        let period_days = self.period_days(&period)?;
+
+        let mut work_orders = BTreeSet::new();
+        let mut total_hours = Work::ZERO;
+        for assignment in self.assignments_for_period(period)? {
+            work_orders.insert(assignment.work_order);
+            if let Some(shift) = assignment.shift {
+                let days_in_period = assignment.days.iter().filter(|day| period_days.contains(day)).count();
+                total_hours += hours(&shift) * (days_in_period * assignment.technicians.len()) as u64;
+            }
+        }
+        Ok((work_orders, total_hours))
+    }
+}
+
+fn hours(shift: &Shift) -> Work
+{
+    Work::from_duration(shift.duration())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::IntakeLimit;
+    use super::IntakeQuantity;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_intake_limits()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                basic_start_date,
+                vec![
+                    Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                    Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+                ],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let availability_start = basic_start_date.and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = (basic_start_date + Duration::days(4)).and_hms_opt(23, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+
+        schedule_graph
+            .set_intake_limit(
+                period,
+                IntakeLimit {
+                    max_new_work_orders: Some(1),
+                    max_new_hours: Some(Work::from_hours(6.0)),
+                },
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.new_intake(period), Ok(Some((0, Work::ZERO))));
+
+        let next_date = basic_start_date + Duration::days(1);
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![next_date],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.new_intake(period), Ok(Some((1, Work::from_hours(4.0)))));
+
+        // A third work order exceeds the work order limit.
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334477).unwrap(),
+                ActivityNumber::new(10),
+                vec![next_date],
+                shift
+            ),
+            Err(ScheduleGraphErrors::IntakeLimitExceeded {
+                period,
+                limit: IntakeQuantity::WorkOrders(1),
+                requested: IntakeQuantity::WorkOrders(2),
+            })
+        );
+        assert_eq!(
+            schedule_graph.add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334477).unwrap(), period),
+            Err(ScheduleGraphErrors::IntakeLimitExceeded {
+                period,
+                limit: IntakeQuantity::WorkOrders(1),
+                requested: IntakeQuantity::WorkOrders(2),
+            })
+        );
+
+        // Two more days of the new work order exceeds the hour limit.
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(20),
+                vec![next_date, next_date + Duration::days(1)],
+                shift
+            ),
+            Err(ScheduleGraphErrors::IntakeLimitExceeded {
+                period,
+                limit: IntakeQuantity::Hours(Work::from_hours(6.0)),
+                requested: IntakeQuantity::Hours(Work::from_hours(12.0)),
+            })
+        );
+
+        // Moving an assignment releases its hours.
+        schedule_graph
+            .move_assignment(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![next_date + Duration::days(2)],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.new_intake(period), Ok(Some((1, Work::from_hours(4.0)))));
+
+        schedule_graph.remove_intake_limit(period);
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334477).unwrap(),
+                ActivityNumber::new(10),
+                vec![next_date],
+                shift,
+            )
+            .unwrap();
+    }
+}
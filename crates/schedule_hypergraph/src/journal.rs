@@ -0,0 +1,462 @@
+use chrono::NaiveDateTime;
+
+use crate::labor_rules::LaborRuleOverride;
+use crate::provenance::DecisionSource;
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::HyperEdge;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+
+/// A primitive mutation of the graph. Every public mutation of the graph is
+/// made up of these, and each of them can be reverted and reapplied.
+#[derive(Clone, Debug)]
+pub(crate) enum Mutation
+{
+    NodeAdded(Node),
+    EdgeAdded(HyperEdge),
+    EdgeRemoved(EdgeIndex, Option<LaborRuleOverride>),
+    LaborRuleOverrideRecorded(EdgeIndex, LaborRuleOverride),
+    DecisionSourceRecorded(EdgeIndex, DecisionSource),
+    AssignmentIdMinted(EdgeIndex, AssignmentId),
+}
+
+/// The mutations of a single call to a public mutating method, with the
+/// time of the `SystemClock` when the first of them was recorded.
+#[derive(Clone, Debug)]
+pub(crate) struct JournalStep
+{
+    recorded_at: NaiveDateTime,
+    mutations: Vec<Mutation>,
+}
+
+/// Undo and redo history of the mutations of a `ScheduleGraph`.
+///
+/// A step is a single call to a public mutating method, so undoing an
+/// `add_work_order` removes the work order with all of its activities.
+#[derive(Clone, Debug)]
+pub struct MutationJournal
+{
+    /// When the history starts, the graph before this time is unknown.
+    started_at: NaiveDateTime,
+    undo_steps: Vec<JournalStep>,
+    redo_steps: Vec<JournalStep>,
+    current_step: Option<JournalStep>,
+    /// Public mutating methods call each other, only the outermost call
+    /// closes the step.
+    depth: usize,
+}
+
+impl MutationJournal
+{
+    pub(crate) fn new(started_at: NaiveDateTime) -> Self
+    {
+        Self {
+            started_at,
+            undo_steps: vec![],
+            redo_steps: vec![],
+            current_step: None,
+            depth: 0,
+        }
+    }
+
+    pub(crate) fn record(&mut self, mutation: Mutation, now: NaiveDateTime)
+    {
+        self.current_step
+            .get_or_insert_with(|| JournalStep {
+                recorded_at: now,
+                mutations: vec![],
+            })
+            .mutations
+            .push(mutation);
+        self.redo_steps.clear();
+        if self.depth == 0 {
+            self.close_step();
+        }
+    }
+
+    pub(crate) fn begin_step(&mut self)
+    {
+        self.depth += 1;
+    }
+
+    pub(crate) fn end_step(&mut self)
+    {
+        self.depth -= 1;
+        if self.depth == 0 {
+            self.close_step();
+        }
+    }
+
+    /// Drops the history, which then starts at `now`.
+    pub(crate) fn clear(&mut self, now: NaiveDateTime)
+    {
+        self.started_at = now;
+        self.undo_steps.clear();
+        self.redo_steps.clear();
+    }
+
+    /// The number of recorded mutations in both the undo and redo history.
+    pub(crate) fn mutation_count(&self) -> usize
+    {
+        self.undo_steps
+            .iter()
+            .chain(&self.redo_steps)
+            .chain(&self.current_step)
+            .map(|step| step.mutations.len())
+            .sum()
+    }
+
+    fn close_step(&mut self)
+    {
+        if let Some(step) = self.current_step.take() {
+            self.undo_steps.push(step);
+        }
+    }
+}
+
+/// Public API to undo and redo mutations of the graph.
+impl ScheduleGraph
+{
+    /// Starts recording mutations. Nothing is recorded by default as the
+    /// journal keeps a copy of every added node and hyperedge.
+    pub fn enable_journal(&mut self)
+    {
+        if self.journal().is_none() {
+            *self.journal_mut() = Some(MutationJournal::new(self.system_clock().now()));
+        }
+    }
+
+    pub fn disable_journal(&mut self)
+    {
+        *self.journal_mut() = None;
+    }
+
+    pub fn can_undo(&self) -> bool
+    {
+        self.journal().is_some_and(|journal| !journal.undo_steps.is_empty())
+    }
+
+    pub fn can_redo(&self) -> bool
+    {
+        self.journal().is_some_and(|journal| !journal.redo_steps.is_empty())
+    }
+
+    /// Reverts the latest step. Returns `false` if there is nothing to undo.
+    pub fn undo(&mut self) -> bool
+    {
+        let Some(step) = self.journal_mut().as_mut().and_then(|journal| journal.undo_steps.pop()) else {
+            return false;
+        };
+
+        for mutation in step.mutations.iter().rev() {
+            self.revert(mutation);
+        }
+
+        if let Some(journal) = self.journal_mut() {
+            journal.redo_steps.push(step);
+        }
+        true
+    }
+
+    /// Reapplies the latest undone step. Returns `false` if there is nothing
+    /// to redo.
+    pub fn redo(&mut self) -> bool
+    {
+        let Some(step) = self.journal_mut().as_mut().and_then(|journal| journal.redo_steps.pop()) else {
+            return false;
+        };
+
+        for mutation in &step.mutations {
+            self.reapply(mutation.clone());
+        }
+
+        if let Some(journal) = self.journal_mut() {
+            journal.undo_steps.push(step);
+        }
+        true
+    }
+
+    /// The graph as it was at the timestamp, reconstructed by undoing the
+    /// steps of the journal that were recorded after it. The steps can be
+    /// redone on the returned graph to move forward in time again. The
+    /// returned graph does not share the listeners of this graph.
+    ///
+    /// `None` if the journal is disabled or its history starts after the
+    /// timestamp, see `enable_journal`.
+    pub fn as_of(&self, timestamp: NaiveDateTime) -> Option<ScheduleGraph>
+    {
+        if self.journal()?.started_at > timestamp {
+            return None;
+        }
+
+        let mut schedule_graph = self.clone();
+        *schedule_graph.listeners_mut() = Default::default();
+        while schedule_graph
+            .journal()
+            .and_then(|journal| journal.undo_steps.last())
+            .is_some_and(|step| step.recorded_at > timestamp)
+        {
+            schedule_graph.undo();
+        }
+        Some(schedule_graph)
+    }
+
+    /// The assignments that were added from `start` until `end`, excluded,
+    /// ordered by `AssignmentId`. Assignments that were removed again are
+    /// included, so this compares the plan between two points in time.
+    ///
+    /// `None` if the journal is disabled or its history starts after
+    /// `start`.
+    pub fn assignments_added_between(&self, start: NaiveDateTime, end: NaiveDateTime) -> Option<Vec<AssignmentId>>
+    {
+        let mut assignment_ids = self
+            .journal_steps_between(start, end)?
+            .filter_map(|mutation| match mutation {
+                Mutation::AssignmentIdMinted(_, assignment_id) => Some(*assignment_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assignment_ids.sort();
+        Some(assignment_ids)
+    }
+
+    /// The assignments that were removed from `start` until `end`,
+    /// excluded, ordered by `AssignmentId`, see
+    /// `assignments_added_between`.
+    pub fn assignments_removed_between(&self, start: NaiveDateTime, end: NaiveDateTime) -> Option<Vec<AssignmentId>>
+    {
+        let mut assignment_ids = self
+            .journal_steps_between(start, end)?
+            .filter_map(|mutation| match mutation {
+                Mutation::EdgeRemoved(edge_index, _) => self.edge_assignment_id(*edge_index),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assignment_ids.sort();
+        Some(assignment_ids)
+    }
+
+    fn journal_steps_between(&self, start: NaiveDateTime, end: NaiveDateTime) -> Option<impl Iterator<Item = &Mutation>>
+    {
+        let journal = self.journal().filter(|journal| journal.started_at <= start)?;
+        Some(
+            journal
+                .undo_steps
+                .iter()
+                .filter(move |step| start <= step.recorded_at && step.recorded_at < end)
+                .flat_map(|step| &step.mutations),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::FixedClock;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_undo_redo()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let availability_start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = basic_start_date.and_hms_opt(17, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        schedule_graph.enable_journal();
+        assert!(!schedule_graph.can_undo());
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        schedule_graph.remove_assignment(assignment_id).unwrap();
+
+        let nodes = schedule_graph.nodes().to_vec();
+        let hyperedges = schedule_graph.hyperedges().to_vec();
+        let incidence_list = schedule_graph.incidence_list().to_vec();
+
+        // Undo the removal of the assignment.
+        assert!(schedule_graph.undo());
+        assert_eq!(
+            schedule_graph.technicians_for_work_order(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(vec![TechnicianId::new(1001)])
+        );
+
+        // Undo the assignment and the work order.
+        assert!(schedule_graph.undo());
+        assert!(schedule_graph.undo());
+        assert!(!schedule_graph.undo());
+        assert_eq!(
+            schedule_graph.technicians_for_work_order(WorkOrderNumber::new(1122334455).unwrap()),
+            Err(ScheduleGraphErrors::WorkOrderMissing {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            })
+        );
+
+        while schedule_graph.redo() {}
+        assert_eq!(schedule_graph.nodes(), nodes);
+        assert_eq!(schedule_graph.hyperedges(), hyperedges);
+        assert_eq!(schedule_graph.incidence_list(), incidence_list);
+        assert_eq!(
+            schedule_graph.technicians_for_work_order(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(vec![])
+        );
+
+        // The redone assignment keeps its `AssignmentId`.
+        assert!(schedule_graph.undo());
+        assert_eq!(
+            schedule_graph.assignment(assignment_id).unwrap().technicians,
+            vec![TechnicianId::new(1001)]
+        );
+        assert!(schedule_graph.redo());
+
+        // A new mutation discards the steps that could be redone.
+        assert!(schedule_graph.undo());
+        schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &period).unwrap();
+        assert!(!schedule_graph.can_redo());
+        assert_eq!(
+            schedule_graph.add_work_order(&work_order),
+            Err(ScheduleGraphErrors::WorkOrderDuplicate {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_as_of()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(monday);
+        let at = |day: u32| NaiveDate::from_ymd_opt(2025, 1, day).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+        schedule_graph.set_system_clock(FixedClock(at(12)));
+        schedule_graph.enable_journal();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        let availability_start = monday.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = monday.and_hms_opt(17, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+        let work_order_number = WorkOrderNumber::new(1122334455).unwrap();
+        let work_order = WorkOrder::new(
+            work_order_number,
+            monday,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        // Monday
+        schedule_graph.set_system_clock(FixedClock(at(13)));
+        let monday_assignment_id = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), work_order_number, period)
+            .unwrap();
+        // Tuesday
+        schedule_graph.set_system_clock(FixedClock(at(14)));
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let tuesday_assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                work_order_number,
+                ActivityNumber::new(10),
+                vec![monday],
+                shift,
+            )
+            .unwrap();
+        // Thursday
+        schedule_graph.set_system_clock(FixedClock(at(16)));
+        schedule_graph.remove_assignment(monday_assignment_id).unwrap();
+
+        assert_eq!(
+            schedule_graph.assignments_added_between(at(13), at(15)),
+            Some(vec![monday_assignment_id, tuesday_assignment_id])
+        );
+        assert_eq!(
+            schedule_graph.assignments_added_between(at(14), at(17)),
+            Some(vec![tuesday_assignment_id])
+        );
+        assert_eq!(
+            schedule_graph.assignments_removed_between(at(13), at(17)),
+            Some(vec![monday_assignment_id])
+        );
+        assert_eq!(schedule_graph.assignments_added_between(at(11), at(17)), None);
+
+        let as_of_monday = schedule_graph.as_of(at(13)).unwrap();
+        assert!(as_of_monday.assignment(monday_assignment_id).is_ok());
+        assert!(as_of_monday.assignment(tuesday_assignment_id).is_err());
+        let as_of_wednesday = schedule_graph.as_of(at(15)).unwrap();
+        assert!(as_of_wednesday.assignment(monday_assignment_id).is_ok());
+        assert!(as_of_wednesday.assignment(tuesday_assignment_id).is_ok());
+        assert!(schedule_graph.assignment(monday_assignment_id).is_err());
+
+        let mut as_of_start = schedule_graph.as_of(at(12)).unwrap();
+        assert!(as_of_start.technicians_for_work_order(work_order_number).is_ok());
+        assert!(schedule_graph.as_of(at(11)).is_none());
+        while as_of_start.redo() {}
+        assert_eq!(as_of_start.nodes(), schedule_graph.nodes());
+        assert_eq!(as_of_start.hyperedges(), schedule_graph.hyperedges());
+
+        schedule_graph.disable_journal();
+        assert!(schedule_graph.as_of(at(13)).is_none());
+    }
+}
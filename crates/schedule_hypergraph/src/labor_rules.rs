@@ -0,0 +1,726 @@
+use std::collections::BTreeMap;
+
+use chrono::Datelike;
+use chrono::Days;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::Shift;
+use crate::schedule_graph::TechnicianId;
+
+/// Working time rules of a site or jurisdiction. Each site selects its rule
+/// set in `GraphConfig::labor_rules`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LaborRules
+{
+    pub max_hours_per_day: Work,
+    /// Weeks are ISO weeks starting on Monday.
+    pub max_hours_per_week: Work,
+    pub min_rest_between_shifts: Duration,
+}
+
+impl LaborRules
+{
+    pub fn new(max_hours_per_day: Work, max_hours_per_week: Work, min_rest_between_shifts: Duration) -> Self
+    {
+        Self {
+            max_hours_per_day,
+            max_hours_per_week,
+            min_rest_between_shifts,
+        }
+    }
+}
+
+impl Default for LaborRules
+{
+    /// 12 hours per day, 48 hours per week and 11 hours of rest.
+    fn default() -> Self
+    {
+        Self::new(Work::from_hours(12.0), Work::from_hours(48.0), Duration::hours(11))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LaborRuleViolation
+{
+    DailyHoursExceeded
+    {
+        technician_id: TechnicianId,
+        day: NaiveDate,
+        hours: Work,
+    },
+    WeeklyHoursExceeded
+    {
+        technician_id: TechnicianId,
+        week_start: NaiveDate,
+        hours: Work,
+    },
+    InsufficientRest
+    {
+        technician_id: TechnicianId,
+        shift_finish: NaiveDateTime,
+        next_shift_start: NaiveDateTime,
+    },
+}
+
+/// Recorded when an assignment is added in spite of violating the
+/// `LaborRules`, e.g. for emergency work.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LaborRuleOverride
+{
+    pub justification: String,
+    pub violations: Vec<LaborRuleViolation>,
+}
+
+/// A day on which technicians can work an activity, see
+/// `ScheduleGraph::find_slots`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slot
+{
+    pub day: NaiveDate,
+    pub technicians: Vec<TechnicianId>,
+    /// The violations of the `LaborRules` that assigning the technicians
+    /// would introduce. Only slots found with `allow_violations` have any.
+    pub violations: Vec<LaborRuleViolation>,
+}
+
+/// Public API to evaluate `LaborRules` on the assignments of the graph.
+impl ScheduleGraph
+{
+    /// Evaluates the `LaborRules` of the `GraphConfig` for every technician
+    /// in the graph.
+    pub fn labor_rule_violations(&self) -> Vec<LaborRuleViolation>
+    {
+        let mut technician_ids = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Technician(technician_id) => Some(*technician_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        technician_ids.sort();
+
+        technician_ids
+            .into_iter()
+            .flat_map(|technician_id| {
+                self.technician_labor_rule_violations(technician_id)
+                    .expect("technician ids are taken from the graph")
+            })
+            .collect()
+    }
+
+    /// Evaluates the `LaborRules` for the activity assignments of a single
+    /// technician.
+    ///
+    /// Assignments that start on the same day count as a single shift when
+    /// the rest between shifts is evaluated.
+    pub fn technician_labor_rule_violations(&self, technician_id: TechnicianId) -> Result<Vec<LaborRuleViolation>, ScheduleGraphErrors>
+    {
+        let intervals = self.technician_intervals(technician_id)?;
+
+        Ok(violations(technician_id, &intervals, &self.config().labor_rules))
+    }
+
+    /// The violations that assigning the technician to the `Shift` on the
+    /// days would introduce or worsen. Schedulers use this to discard
+    /// candidates before they are added to the graph.
+    pub fn candidate_labor_rule_violations(
+        &self,
+        technician_id: TechnicianId,
+        days: &[NaiveDate],
+        shift: &Shift,
+    ) -> Result<Vec<LaborRuleViolation>, ScheduleGraphErrors>
+    {
+        let labor_rules = &self.config().labor_rules;
+        let mut intervals = self.technician_intervals(technician_id)?;
+        let current_violations = violations(technician_id, &intervals, labor_rules);
+
+        intervals.extend(days.iter().map(|day| shift.interval_on(*day)));
+
+        Ok(violations(technician_id, &intervals, labor_rules)
+            .into_iter()
+            .filter(|violation| !current_violations.contains(violation))
+            .collect())
+    }
+
+    /// Adds the activity assignment only if it respects the `LaborRules` for
+    /// every technician. An assignment that violates the rules is added if a
+    /// justification is given, and the override is recorded in the graph.
+    pub fn add_assignment_activity_with_labor_rules(
+        &mut self,
+        technicians: Vec<TechnicianId>,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        days: Vec<NaiveDate>,
+        shift: Shift,
+        justification: Option<String>,
+    ) -> Result<AssignmentId, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            let mut violations = vec![];
+            for technician_id in &technicians {
+                violations.extend(graph.candidate_labor_rule_violations(*technician_id, &days, &shift)?);
+            }
+
+            if !violations.is_empty() && justification.is_none() {
+                return Err(ScheduleGraphErrors::LaborRulesViolated { violations });
+            }
+
+            let assignment_id = graph.add_assignment_activity(technicians, work_order_number, activity_number, days, shift)?;
+
+            if let Some(justification) = justification
+                && !violations.is_empty()
+            {
+                let edge_index = graph.assignment_edge_index(assignment_id)?;
+                graph.record_labor_rule_override(edge_index, LaborRuleOverride { justification, violations });
+            }
+
+            Ok(assignment_id)
+        })
+    }
+
+    /// The `LaborRuleOverride` that the assignment was added with, if any.
+    pub fn assignment_labor_rule_override(&self, assignment_id: AssignmentId) -> Result<Option<&LaborRuleOverride>, ScheduleGraphErrors>
+    {
+        let edge_index = self.assignment_edge_index(assignment_id)?;
+        Ok(self.labor_rule_overrides().get(&edge_index))
+    }
+
+    /// The days on which enough technicians can work the activity on the
+    /// `Shift`, with the technicians to assign. A technician is a
+    /// candidate on a day if they have the skill of the activity, the
+    /// assignment would be accepted by `add_assignment_activity` and the
+    /// shift does not overlap another of their assignments. Candidates
+    /// whose shift would violate the `LaborRules` are left out, so the
+    /// slots respect the rest rules before anything is added.
+    ///
+    /// With `allow_violations` a day that can only be staffed by breaking
+    /// the rules is returned as well, with the violations, and the
+    /// technicians that break the fewest rules are picked. Committing such
+    /// a slot needs a justification, see
+    /// `add_assignment_activity_with_labor_rules`.
+    pub fn find_slots(
+        &self,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        days: &[NaiveDate],
+        shift: &Shift,
+        allow_violations: bool,
+    ) -> Result<Vec<Slot>, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+        let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+            return Err(self.activity_missing(work_order_number, activity_number));
+        };
+        let number_of_people = activity.number_of_people() as usize;
+        let required_skills = self.node_skills(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires));
+
+        let mut technician_ids = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Technician(technician_id) => Some(*technician_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        technician_ids.sort();
+
+        let mut slots = vec![];
+        for day in days {
+            let (start, finish) = shift.interval_on(*day);
+            let mut candidates = vec![];
+            for technician_id in &technician_ids {
+                if !required_skills.is_subset(&self.technician_skills_on(*technician_id, *day)?)
+                    || self
+                        .activity_assignment_nodes(&[*technician_id], work_order_number, activity_number, &[*day], shift)
+                        .is_err()
+                    || self
+                        .technician_intervals(*technician_id)?
+                        .iter()
+                        .any(|(assigned_start, assigned_finish)| *assigned_start < finish && start < *assigned_finish)
+                {
+                    continue;
+                }
+                let violations = self.candidate_labor_rule_violations(*technician_id, &[*day], shift)?;
+                if violations.is_empty() || allow_violations {
+                    candidates.push((violations, *technician_id));
+                }
+            }
+            if candidates.len() < number_of_people {
+                continue;
+            }
+
+            // The sort is stable, so technicians without violations keep
+            // the order of their ids.
+            candidates.sort_by_key(|(violations, _)| violations.len());
+            let (violations, technicians): (Vec<_>, Vec<_>) = candidates.into_iter().take(number_of_people).unzip();
+            slots.push(Slot {
+                day: *day,
+                technicians,
+                violations: violations.into_iter().flatten().collect(),
+            });
+        }
+        Ok(slots)
+    }
+
+    /// Assigns the activity to the first of the days that `find_slots`
+    /// finds a slot on. Only slots that respect the `LaborRules` are used,
+    /// unless a justification is given, in which case the first slot
+    /// without violations is still preferred and the override is recorded
+    /// for a slot with violations. `None` if there is no slot.
+    pub fn schedule_activity(
+        &mut self,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        days: &[NaiveDate],
+        shift: Shift,
+        justification: Option<String>,
+    ) -> Result<Option<AssignmentId>, ScheduleGraphErrors>
+    {
+        let slots = self.find_slots(work_order_number, activity_number, days, &shift, justification.is_some())?;
+        let Some(slot) = slots.iter().find(|slot| slot.violations.is_empty()).or(slots.first()) else {
+            return Ok(None);
+        };
+
+        self.add_assignment_activity_with_labor_rules(
+            slot.technicians.clone(),
+            work_order_number,
+            activity_number,
+            vec![slot.day],
+            shift,
+            justification,
+        )
+        .map(Some)
+    }
+
+    fn technician_intervals(&self, technician_id: TechnicianId) -> Result<Vec<(NaiveDateTime, NaiveDateTime)>, ScheduleGraphErrors>
+    {
+        Ok(self
+            .assignments_for_technician(technician_id)?
+            .iter()
+            .flat_map(|assignment| assignment.intervals())
+            .collect())
+    }
+}
+
+fn violations(technician_id: TechnicianId, intervals: &[(NaiveDateTime, NaiveDateTime)], labor_rules: &LaborRules) -> Vec<LaborRuleViolation>
+{
+    let mut hours_per_day = BTreeMap::<NaiveDate, Work>::new();
+    for (start, finish) in intervals {
+        let mut day = start.date();
+        while day.and_time(NaiveTime::MIN) < *finish {
+            let day_start = day.and_time(NaiveTime::MIN).max(*start);
+            let day_finish = (day + Days::new(1)).and_time(NaiveTime::MIN).min(*finish);
+            *hours_per_day.entry(day).or_default() += hours(day_finish - day_start);
+            day = day + Days::new(1);
+        }
+    }
+
+    let mut violations = vec![];
+    let mut hours_per_week = BTreeMap::<NaiveDate, Work>::new();
+    for (day, hours) in &hours_per_day {
+        if *hours > labor_rules.max_hours_per_day {
+            violations.push(LaborRuleViolation::DailyHoursExceeded {
+                technician_id,
+                day: *day,
+                hours: *hours,
+            });
+        }
+        let week_start = *day - Days::new(day.weekday().num_days_from_monday() as u64);
+        *hours_per_week.entry(week_start).or_default() += *hours;
+    }
+
+    for (week_start, hours) in hours_per_week {
+        if hours > labor_rules.max_hours_per_week {
+            violations.push(LaborRuleViolation::WeeklyHoursExceeded {
+                technician_id,
+                week_start,
+                hours,
+            });
+        }
+    }
+
+    let mut shifts = BTreeMap::<NaiveDate, (NaiveDateTime, NaiveDateTime)>::new();
+    for (start, finish) in intervals {
+        shifts
+            .entry(start.date())
+            .and_modify(|(shift_start, shift_finish)| {
+                *shift_start = (*shift_start).min(*start);
+                *shift_finish = (*shift_finish).max(*finish);
+            })
+            .or_insert((*start, *finish));
+    }
+
+    let shifts = shifts.into_values().collect::<Vec<_>>();
+    for window in shifts.windows(2) {
+        let (_, shift_finish) = window[0];
+        let (next_shift_start, _) = window[1];
+        if next_shift_start - shift_finish < labor_rules.min_rest_between_shifts {
+            violations.push(LaborRuleViolation::InsufficientRest {
+                technician_id,
+                shift_finish,
+                next_shift_start,
+            });
+        }
+    }
+
+    violations
+}
+
+fn hours(duration: Duration) -> Work
+{
+    Work::from_duration(duration)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::LaborRuleOverride;
+    use super::LaborRuleViolation;
+    use super::LaborRules;
+    use super::Slot;
+    use crate::config::GraphConfig;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_labor_rule_violations()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        // 2025-01-13 is a Monday
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let tuesday = monday + Duration::days(1);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            monday,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability_start = monday.and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = (monday + Duration::days(6)).and_hms_opt(23, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+
+        // 06:00-20:00 on Monday is 14 hours, and the overnight shift starting
+        // on Tuesday leaves 6 hours of rest after Monday.
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                Shift::new(time(6), time(20), false).unwrap(),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+                vec![tuesday],
+                Shift::new(time(2), time(10), false).unwrap(),
+            )
+            .unwrap();
+
+        let violations = schedule_graph.labor_rule_violations();
+
+        assert_eq!(
+            violations,
+            vec![
+                LaborRuleViolation::DailyHoursExceeded {
+                    technician_id: TechnicianId::new(1001),
+                    day: monday,
+                    hours: Work::from_hours(14.0),
+                },
+                LaborRuleViolation::InsufficientRest {
+                    technician_id: TechnicianId::new(1001),
+                    shift_finish: monday.and_time(time(20)),
+                    next_shift_start: tuesday.and_time(time(2)),
+                },
+            ]
+        );
+
+        schedule_graph.set_config(GraphConfig {
+            labor_rules: LaborRules::new(Work::from_hours(24.0), Work::from_hours(20.0), Duration::hours(5)),
+            ..GraphConfig::default()
+        });
+        assert_eq!(
+            schedule_graph.labor_rule_violations(),
+            vec![LaborRuleViolation::WeeklyHoursExceeded {
+                technician_id: TechnicianId::new(1001),
+                week_start: monday,
+                hours: Work::from_hours(22.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_add_assignment_activity_with_labor_rules()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let tuesday = monday + Duration::days(1);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            monday,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability_start = monday.and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = (monday + Duration::days(6)).and_hms_opt(23, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+
+        schedule_graph
+            .add_assignment_activity_with_labor_rules(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                Shift::new(time(8), time(20), false).unwrap(),
+                None,
+            )
+            .unwrap();
+
+        // Starting 02:00 on Tuesday leaves 6 hours of rest after Monday.
+        let early_shift = Shift::new(time(2), time(10), false).unwrap();
+        let expected_violations = vec![LaborRuleViolation::InsufficientRest {
+            technician_id: TechnicianId::new(1001),
+            shift_finish: monday.and_time(time(20)),
+            next_shift_start: tuesday.and_time(time(2)),
+        }];
+        assert_eq!(
+            schedule_graph.candidate_labor_rule_violations(TechnicianId::new(1001), &[tuesday], &early_shift),
+            Ok(expected_violations.clone())
+        );
+        assert_eq!(
+            schedule_graph.add_assignment_activity_with_labor_rules(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+                vec![tuesday],
+                early_shift,
+                None
+            ),
+            Err(ScheduleGraphErrors::LaborRulesViolated {
+                violations: expected_violations.clone()
+            })
+        );
+        assert_eq!(schedule_graph.assignments_for_technician(TechnicianId::new(1001)).unwrap().len(), 1);
+
+        let assignment_id = schedule_graph
+            .add_assignment_activity_with_labor_rules(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+                vec![tuesday],
+                early_shift,
+                Some("Emergency repair of the export pump".to_string()),
+            )
+            .unwrap();
+
+        assert_eq!(
+            schedule_graph.assignment_labor_rule_override(assignment_id),
+            Ok(Some(&LaborRuleOverride {
+                justification: "Emergency repair of the export pump".to_string(),
+                violations: expected_violations,
+            }))
+        );
+
+        schedule_graph
+            .unassign_activity(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+            )
+            .unwrap();
+        assert!(schedule_graph.labor_rule_overrides().is_empty());
+        assert_eq!(
+            schedule_graph.assignment_labor_rule_override(assignment_id),
+            Err(ScheduleGraphErrors::AssignmentMissing { assignment_id })
+        );
+    }
+
+    #[test]
+    fn test_find_slots()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let tuesday = monday + Duration::days(1);
+        let wednesday = monday + Duration::days(2);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+
+        let work_order_number = WorkOrderNumber::new(1122334455).unwrap();
+        let work_order = WorkOrder::new(
+            work_order_number,
+            monday,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 2, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(30), 2, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability_start = monday.and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = (monday + Duration::days(6)).and_hms_opt(23, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability_start, availability_end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph
+                .add_technician(technician, Availability::new(availability_start, availability_end))
+                .unwrap();
+        }
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+
+        schedule_graph
+            .add_assignment_activity_with_labor_rules(
+                vec![TechnicianId::new(1001)],
+                work_order_number,
+                ActivityNumber::new(10),
+                vec![monday],
+                Shift::new(time(8), time(20), false).unwrap(),
+                None,
+            )
+            .unwrap();
+
+        // Only 1002 can start 02:00 on Tuesday without breaking the rest
+        // rule, which is not enough for activity 20.
+        let early_shift = Shift::new(time(2), time(10), false).unwrap();
+        let expected_violations = vec![LaborRuleViolation::InsufficientRest {
+            technician_id: TechnicianId::new(1001),
+            shift_finish: monday.and_time(time(20)),
+            next_shift_start: tuesday.and_time(time(2)),
+        }];
+        assert_eq!(
+            schedule_graph.find_slots(work_order_number, ActivityNumber::new(20), &[tuesday, wednesday], &early_shift, false),
+            Ok(vec![Slot {
+                day: wednesday,
+                technicians: vec![TechnicianId::new(1001), TechnicianId::new(1002)],
+                violations: vec![],
+            }])
+        );
+        assert_eq!(
+            schedule_graph.find_slots(work_order_number, ActivityNumber::new(20), &[tuesday], &early_shift, true),
+            Ok(vec![Slot {
+                day: tuesday,
+                technicians: vec![TechnicianId::new(1002), TechnicianId::new(1001)],
+                violations: expected_violations.clone(),
+            }])
+        );
+
+        assert_eq!(
+            schedule_graph.schedule_activity(work_order_number, ActivityNumber::new(20), &[tuesday], early_shift, None),
+            Ok(None)
+        );
+
+        // The slot without violations is preferred even with a
+        // justification.
+        let assignment_id = schedule_graph
+            .schedule_activity(
+                work_order_number,
+                ActivityNumber::new(20),
+                &[tuesday, wednesday],
+                early_shift,
+                Some("Emergency repair of the export pump".to_string()),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(schedule_graph.assignment_labor_rule_override(assignment_id), Ok(None));
+        assert_eq!(schedule_graph.assignments_for_technician(TechnicianId::new(1002)).unwrap().len(), 1);
+
+        let assignment_id = schedule_graph
+            .schedule_activity(
+                work_order_number,
+                ActivityNumber::new(30),
+                &[tuesday],
+                early_shift,
+                Some("Emergency repair of the export pump".to_string()),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            schedule_graph.assignment_labor_rule_override(assignment_id),
+            Ok(Some(&LaborRuleOverride {
+                justification: "Emergency repair of the export pump".to_string(),
+                violations: expected_violations,
+            }))
+        );
+
+        // Both technicians now work 02:00 to 10:00 on Wednesday.
+        assert_eq!(
+            schedule_graph.find_slots(work_order_number, ActivityNumber::new(10), &[wednesday], &early_shift, true),
+            Ok(vec![])
+        );
+    }
+}
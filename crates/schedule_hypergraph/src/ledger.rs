@@ -0,0 +1,259 @@
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkillHourEntryKind
+{
+    /// Hours set aside for work that is not assigned yet.
+    Reservation,
+    /// Hours that were actually spent.
+    Actual,
+}
+
+/// A line of the skill-hour ledger. Entries are only ever appended, so the
+/// ledger can be queried as it looked on an earlier date.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkillHourEntry
+{
+    pub period: Period,
+    pub skill: Skill,
+    pub kind: SkillHourEntryKind,
+    pub recorded_on: NaiveDate,
+    pub hours: Work,
+}
+
+/// Reconciliation of the hours of a `Skill` in a `Period`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkillHourLedger
+{
+    /// The available hours of the technicians with the skill, see
+    /// `ScheduleGraph::capacity_calendar`.
+    pub opening_capacity: Work,
+    /// Hours of the activity assignments requiring the skill on the days of
+    /// the period.
+    pub committed_hours: Work,
+    pub reserved_hours: Work,
+    pub actual_hours: Work,
+}
+
+impl SkillHourLedger
+{
+    pub fn remaining_hours(&self) -> Work
+    {
+        self.opening_capacity - self.committed_hours - self.reserved_hours
+    }
+}
+
+/// Public API for the skill-hour ledger.
+impl ScheduleGraph
+{
+    pub fn reserve_skill_hours(&mut self, period: Period, skill: Skill, hours: Work, recorded_on: NaiveDate) -> Result<(), ScheduleGraphErrors>
+    {
+        self.record_skill_hours(period, skill, SkillHourEntryKind::Reservation, hours, recorded_on)
+    }
+
+    pub fn record_actual_skill_hours(&mut self, period: Period, skill: Skill, hours: Work, recorded_on: NaiveDate)
+    -> Result<(), ScheduleGraphErrors>
+    {
+        self.record_skill_hours(period, skill, SkillHourEntryKind::Actual, hours, recorded_on)
+    }
+
+    /// The ledger of the skill in the period with the entries recorded on or
+    /// before `as_of`, or all entries if `as_of` is `None`.
+    ///
+    /// Assignments are not dated, so the commitments are always the current
+    /// ones.
+    pub fn skill_hour_ledger(&self, period: Period, skill: Skill, as_of: Option<NaiveDate>) -> Result<SkillHourLedger, ScheduleGraphErrors>
+    {
+        let opening_capacity = self
+            .capacity_calendar(&[period])?
+            .0
+            .get(&period)
+            .and_then(|period_capacity| period_capacity.skills.get(&skill))
+            .map_or(Work::ZERO, |skill_capacity| skill_capacity.total_hours);
+
+        let mut skill_hour_ledger = SkillHourLedger {
+            opening_capacity,
+            committed_hours: self.committed_skill_hours(period, skill)?,
+            ..SkillHourLedger::default()
+        };
+
+        for skill_hour_entry in self.skill_hour_entries() {
+            if skill_hour_entry.period != period || skill_hour_entry.skill != skill || as_of.is_some_and(|as_of| skill_hour_entry.recorded_on > as_of)
+            {
+                continue;
+            }
+            match skill_hour_entry.kind {
+                SkillHourEntryKind::Reservation => skill_hour_ledger.reserved_hours += skill_hour_entry.hours,
+                SkillHourEntryKind::Actual => skill_hour_ledger.actual_hours += skill_hour_entry.hours,
+            }
+        }
+        Ok(skill_hour_ledger)
+    }
+
+    fn record_skill_hours(
+        &mut self,
+        period: Period,
+        skill: Skill,
+        kind: SkillHourEntryKind,
+        hours: Work,
+        recorded_on: NaiveDate,
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        self.period_days(&period)?;
+        if !self.nodes().contains(&Node::Skill(skill)) {
+            return Err(self.skill_missing(skill));
+        }
+
+        self.skill_hour_entries_mut().push(SkillHourEntry {
+            period,
+            skill,
+            kind,
+            recorded_on,
+            hours,
+        });
+        Ok(())
+    }
+
+    fn committed_skill_hours(&self, period: Period, skill: Skill) -> Result<Work, ScheduleGraphErrors>
+    {
+        let mut committed_hours = Work::ZERO;
+        for edge_index in self.find_all_assignments_for_period(period)? {
+            let Some(assignment) = self.assignment_view(edge_index) else {
+                continue;
+            };
+            let Some(activity_number) = assignment.activity else {
+                continue;
+            };
+
+            let activity_node_index = self.activity_node_index(assignment.work_order, activity_number)?;
+            let requires_skill = self.incidence_list()[activity_node_index].iter().any(|&edge_index| {
+                let hyperedge = &self.hyperedges()[edge_index];
+                matches!(hyperedge.edge_type(), EdgeType::Requires) && self.nodes()[hyperedge.nodes()[1]] == Node::Skill(skill)
+            });
+            if !requires_skill {
+                continue;
+            }
+
+            let hours = assignment
+                .days
+                .iter()
+                .zip(assignment.intervals())
+                .filter(|(day, _)| period.contains(day))
+                .map(|(_, (start, finish))| Work::from_duration(finish - start))
+                .sum::<Work>();
+            committed_hours += hours * assignment.technicians.len() as u64;
+        }
+        Ok(committed_hours)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::SkillHourLedger;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_skill_hour_ledger()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability_start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = (basic_start_date + Duration::days(1)).and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+
+        schedule_graph
+            .reserve_skill_hours(period, Skill::MTN_MECH, Work::from_hours(6.0), basic_start_date - Duration::days(7))
+            .unwrap();
+        schedule_graph
+            .record_actual_skill_hours(period, Skill::MTN_MECH, Work::from_hours(3.5), basic_start_date)
+            .unwrap();
+        assert_eq!(
+            schedule_graph.reserve_skill_hours(period, Skill::MTN_ELEC, Work::from_hours(1.0), basic_start_date),
+            Err(ScheduleGraphErrors::SkillMissing {
+                skill: Skill::MTN_ELEC,
+                skills: vec![Skill::MTN_MECH],
+            })
+        );
+
+        let skill_hour_ledger = schedule_graph.skill_hour_ledger(period, Skill::MTN_MECH, None).unwrap();
+        assert_eq!(
+            skill_hour_ledger,
+            SkillHourLedger {
+                opening_capacity: Work::from_hours(32.0),
+                committed_hours: Work::from_hours(4.0),
+                reserved_hours: Work::from_hours(6.0),
+                actual_hours: Work::from_hours(3.5),
+            }
+        );
+        assert_eq!(skill_hour_ledger.remaining_hours(), Work::from_hours(22.0));
+
+        let earlier_ledger = schedule_graph
+            .skill_hour_ledger(period, Skill::MTN_MECH, Some(basic_start_date - Duration::days(1)))
+            .unwrap();
+        assert_eq!(earlier_ledger.actual_hours, Work::ZERO);
+        assert_eq!(earlier_ledger.reserved_hours, Work::from_hours(6.0));
+    }
+}
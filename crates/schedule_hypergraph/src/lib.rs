@@ -1,4 +1,57 @@
+pub mod alternatives;
+pub mod baseline;
+pub mod capacity;
+pub mod change_summary;
+pub mod claims;
+pub mod close_out;
+pub mod config;
+pub mod confirmations;
+pub mod conflicts;
+pub mod contractor;
+pub mod crew;
+pub mod critical_path;
+pub mod dependencies;
 pub mod derive_instances;
+pub mod diff;
+pub mod dot;
+pub mod dual;
+pub mod effective_dating;
+pub mod ejection_chain;
+pub mod entity_detail;
+pub mod events;
+pub mod fingerprint;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod frozen;
+pub mod horizon;
+pub mod id_allocator;
+pub mod import;
+pub mod infeasibility;
+pub mod intake;
+pub mod journal;
+pub mod labor_rules;
+pub mod ledger;
+pub mod location;
+pub mod material;
+pub mod precedence;
+pub mod provenance;
+pub mod report;
+pub mod risk;
+pub mod routing;
+pub mod safety;
+pub mod scenario;
 /// The goal of the crate is to replace `petgraph` in the ordinator
 /// schedule system with a complete domain graph.
 pub mod schedule_graph;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod sharding;
+pub mod shutdown;
+pub mod snapshot;
+pub mod timestamps;
+pub mod transaction;
+pub mod unscheduled;
+pub mod validation;
+pub mod watchdog;
+pub mod work_order_metadata;
+pub mod work_package;
@@ -0,0 +1,393 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::EquipmentId;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The position of a functional location in decimal degrees.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinates
+{
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordinates
+{
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+
+    /// The great-circle distance in kilometers.
+    pub fn distance_km(&self, other: &Coordinates) -> f64
+    {
+        let (latitude, other_latitude) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let half_latitude_delta = (other_latitude - latitude) / 2.0;
+        let half_longitude_delta = (other.longitude - self.longitude).to_radians() / 2.0;
+
+        let haversine = half_latitude_delta.sin().powi(2) + latitude.cos() * other_latitude.cos() * half_longitude_delta.sin().powi(2);
+        2.0 * Self::EARTH_RADIUS_KM * haversine.sqrt().asin()
+    }
+}
+
+// `EdgeType` is totally ordered, so the coordinates are compared with
+// `f64::total_cmp`.
+impl PartialEq for Coordinates
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Coordinates {}
+
+impl PartialOrd for Coordinates
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Coordinates
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        self.latitude.total_cmp(&other.latitude).then(self.longitude.total_cmp(&other.longitude))
+    }
+}
+
+/// Public API for the technical objects that work orders are executed on.
+/// A work order is `LocatedAt` a functional location and can be `On` pieces
+/// of equipment, which are themselves `LocatedAt` a functional location.
+impl ScheduleGraph
+{
+    pub fn add_functional_location(&mut self, functional_location_id: &str) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            if graph.functional_location_node_index(functional_location_id).is_ok() {
+                return Err(ScheduleGraphErrors::FunctionalLocationDuplicate {
+                    functional_location_id: functional_location_id.to_string(),
+                    work_order_number: None,
+                });
+            }
+            Ok(graph.add_node(Node::FunctionalLocation(functional_location_id.to_string())))
+        })
+    }
+
+    /// Adds the equipment, installed at the functional location if there is
+    /// one.
+    pub fn add_equipment(&mut self, equipment_id: EquipmentId, functional_location_id: Option<&str>) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            if graph.equipment_node_index(equipment_id).is_ok() {
+                return Err(ScheduleGraphErrors::EquipmentDuplicate {
+                    equipment_id,
+                    work_order_number: None,
+                });
+            }
+            let functional_location_node_index = functional_location_id
+                .map(|functional_location_id| graph.functional_location_node_index(functional_location_id))
+                .transpose()?;
+
+            let equipment_node_index = graph.add_node(Node::Equipment(equipment_id));
+            if let Some(functional_location_node_index) = functional_location_node_index {
+                graph.add_edge(EdgeType::LocatedAt, vec![equipment_node_index, functional_location_node_index]);
+            }
+            Ok(equipment_node_index)
+        })
+    }
+
+    /// Places the functional location at the coordinates, replacing the
+    /// ones it had.
+    pub fn set_functional_location_coordinates(
+        &mut self,
+        functional_location_id: &str,
+        coordinates: Coordinates,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let functional_location_node_index = self.functional_location_node_index(functional_location_id)?;
+        let previous_edge_index = self.coordinates_edge_index(functional_location_node_index);
+
+        Ok(self.journaled(|graph| {
+            if let Some(previous_edge_index) = previous_edge_index {
+                graph.remove_edge(previous_edge_index).expect("the coordinates hyperedge is not removed");
+            }
+            graph.add_edge(EdgeType::Coordinates(coordinates), vec![functional_location_node_index])
+        }))
+    }
+
+    pub fn functional_location_coordinates(&self, functional_location_id: &str) -> Result<Option<Coordinates>, ScheduleGraphErrors>
+    {
+        let functional_location_node_index = self.functional_location_node_index(functional_location_id)?;
+
+        Ok(self
+            .coordinates_edge_index(functional_location_node_index)
+            .and_then(|edge_index| match self.hyperedges()[edge_index].edge_type() {
+                EdgeType::Coordinates(coordinates) => Some(*coordinates),
+                _ => None,
+            }))
+    }
+
+    /// The coordinates of the work order: those of its functional location,
+    /// or else those of the location of the first of its equipment that has
+    /// coordinates.
+    pub fn work_order_coordinates(&self, work_order_number: WorkOrderNumber) -> Result<Option<Coordinates>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        Ok(std::iter::once(work_order_node_index)
+            .chain(self.work_order_equipment_node_indices(work_order_node_index))
+            .filter_map(|node_index| self.located_at(node_index))
+            .find_map(|functional_location_node_index| self.coordinates_edge_index(functional_location_node_index))
+            .and_then(|edge_index| match self.hyperedges()[edge_index].edge_type() {
+                EdgeType::Coordinates(coordinates) => Some(*coordinates),
+                _ => None,
+            }))
+    }
+
+    /// A work order is located at one functional location.
+    pub fn add_work_order_location(
+        &mut self,
+        work_order_number: WorkOrderNumber,
+        functional_location_id: &str,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        let functional_location_node_index = self.functional_location_node_index(functional_location_id)?;
+        if let Some(located_at) = self.located_at(work_order_node_index)
+            && let Node::FunctionalLocation(located_at_id) = &self.nodes()[located_at]
+        {
+            return Err(ScheduleGraphErrors::FunctionalLocationDuplicate {
+                functional_location_id: located_at_id.clone(),
+                work_order_number: Some(work_order_number),
+            });
+        }
+
+        Ok(self.journaled(|graph| graph.add_edge(EdgeType::LocatedAt, vec![work_order_node_index, functional_location_node_index])))
+    }
+
+    pub fn add_work_order_equipment(
+        &mut self,
+        work_order_number: WorkOrderNumber,
+        equipment_id: EquipmentId,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        let equipment_node_index = self.equipment_node_index(equipment_id)?;
+        if self
+            .work_order_equipment_node_indices(work_order_node_index)
+            .contains(&equipment_node_index)
+        {
+            return Err(ScheduleGraphErrors::EquipmentDuplicate {
+                equipment_id,
+                work_order_number: Some(work_order_number),
+            });
+        }
+
+        Ok(self.journaled(|graph| graph.add_edge(EdgeType::On, vec![work_order_node_index, equipment_node_index])))
+    }
+
+    /// The functional location of the work order, if it has one.
+    pub fn work_order_location(&self, work_order_number: WorkOrderNumber) -> Result<Option<&str>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        Ok(self
+            .located_at(work_order_node_index)
+            .and_then(|node_index| match &self.nodes()[node_index] {
+                Node::FunctionalLocation(functional_location_id) => Some(functional_location_id.as_str()),
+                _ => None,
+            }))
+    }
+
+    /// The work orders located at the functional location, either directly
+    /// or through equipment installed there, ordered by `WorkOrderNumber`.
+    pub fn work_orders_at_location(&self, functional_location_id: &str) -> Result<Vec<WorkOrderNumber>, ScheduleGraphErrors>
+    {
+        let functional_location_node_index = self.functional_location_node_index(functional_location_id)?;
+
+        let mut work_orders = BTreeSet::new();
+        for located_node_index in self.incoming(functional_location_node_index, EdgeType::LocatedAt) {
+            match &self.nodes()[located_node_index] {
+                Node::WorkOrder(work_order_number) => {
+                    work_orders.insert(*work_order_number);
+                }
+                Node::Equipment(equipment_id) => {
+                    work_orders.extend(self.work_orders_on_equipment(*equipment_id)?);
+                }
+                _ => (),
+            }
+        }
+        Ok(work_orders.into_iter().collect())
+    }
+
+    /// The work orders on the equipment, ordered by `WorkOrderNumber`.
+    pub fn work_orders_on_equipment(&self, equipment_id: EquipmentId) -> Result<Vec<WorkOrderNumber>, ScheduleGraphErrors>
+    {
+        let equipment_node_index = self.equipment_node_index(equipment_id)?;
+
+        let mut work_orders = self
+            .incoming(equipment_node_index, EdgeType::On)
+            .filter_map(|node_index| match &self.nodes()[node_index] {
+                Node::WorkOrder(work_order_number) => Some(*work_order_number),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        work_orders.sort();
+        Ok(work_orders)
+    }
+
+    /// The first nodes of the hyperedges of the `EdgeType` that end in the
+    /// node.
+    fn incoming(&self, node_index: NodeIndex, edge_type: EdgeType) -> impl Iterator<Item = NodeIndex> + '_
+    {
+        self.incidence_list()[node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(move |hyperedge| *hyperedge.edge_type() == edge_type && hyperedge.nodes()[1] == node_index)
+            .map(|hyperedge| hyperedge.nodes()[0])
+    }
+
+    fn located_at(&self, node_index: NodeIndex) -> Option<NodeIndex>
+    {
+        self.incidence_list()[node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .find(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::LocatedAt) && hyperedge.nodes()[0] == node_index)
+            .map(|hyperedge| hyperedge.nodes()[1])
+    }
+
+    fn coordinates_edge_index(&self, functional_location_node_index: NodeIndex) -> Option<EdgeIndex>
+    {
+        self.incidence_list()[functional_location_node_index]
+            .iter()
+            .copied()
+            .find(|&edge_index| matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Coordinates(_)))
+    }
+
+    fn work_order_equipment_node_indices(&self, work_order_node_index: NodeIndex) -> Vec<NodeIndex>
+    {
+        self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::On) && hyperedge.nodes()[0] == work_order_node_index)
+            .map(|hyperedge| hyperedge.nodes()[1])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_work_orders_at_location()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        schedule_graph.add_functional_location("1000-PMP-01").unwrap();
+        schedule_graph.add_functional_location("1000-CMP-02").unwrap();
+        assert_eq!(
+            schedule_graph.add_functional_location("1000-PMP-01"),
+            Err(ScheduleGraphErrors::FunctionalLocationDuplicate {
+                functional_location_id: "1000-PMP-01".to_string(),
+                work_order_number: None,
+            })
+        );
+        schedule_graph.add_equipment(10004711, Some("1000-PMP-01")).unwrap();
+        schedule_graph.add_equipment(10004712, None).unwrap();
+        assert_eq!(
+            schedule_graph.add_equipment(10004713, Some("1000-XXX-00")),
+            Err(ScheduleGraphErrors::FunctionalLocationMissing {
+                functional_location_id: "1000-XXX-00".to_string(),
+            })
+        );
+
+        schedule_graph
+            .add_work_order_location(WorkOrderNumber::new(1122334455).unwrap(), "1000-PMP-01")
+            .unwrap();
+        schedule_graph
+            .add_work_order_location(WorkOrderNumber::new(1122334466).unwrap(), "1000-CMP-02")
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_work_order_location(WorkOrderNumber::new(1122334466).unwrap(), "1000-PMP-01"),
+            Err(ScheduleGraphErrors::FunctionalLocationDuplicate {
+                functional_location_id: "1000-CMP-02".to_string(),
+                work_order_number: Some(WorkOrderNumber::new(1122334466).unwrap()),
+            })
+        );
+        schedule_graph
+            .add_work_order_equipment(WorkOrderNumber::new(1122334466).unwrap(), 10004711)
+            .unwrap();
+        schedule_graph
+            .add_work_order_equipment(WorkOrderNumber::new(1122334477).unwrap(), 10004712)
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_work_order_equipment(WorkOrderNumber::new(1122334477).unwrap(), 10004712),
+            Err(ScheduleGraphErrors::EquipmentDuplicate {
+                equipment_id: 10004712,
+                work_order_number: Some(WorkOrderNumber::new(1122334477).unwrap()),
+            })
+        );
+
+        assert_eq!(
+            schedule_graph.work_order_location(WorkOrderNumber::new(1122334466).unwrap()),
+            Ok(Some("1000-CMP-02"))
+        );
+        assert_eq!(schedule_graph.work_order_location(WorkOrderNumber::new(1122334477).unwrap()), Ok(None));
+        // 1122334466 is located elsewhere but on equipment installed at the
+        // pump.
+        assert_eq!(
+            schedule_graph.work_orders_at_location("1000-PMP-01"),
+            Ok(vec![WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()])
+        );
+        assert_eq!(
+            schedule_graph.work_orders_at_location("1000-CMP-02"),
+            Ok(vec![WorkOrderNumber::new(1122334466).unwrap()])
+        );
+        assert_eq!(
+            schedule_graph.work_orders_on_equipment(10004712),
+            Ok(vec![WorkOrderNumber::new(1122334477).unwrap()])
+        );
+        assert_eq!(
+            schedule_graph.work_orders_on_equipment(10004713),
+            Err(ScheduleGraphErrors::EquipmentMissing { equipment_id: 10004713 })
+        );
+        assert!(schedule_graph.validate().is_valid());
+    }
+}
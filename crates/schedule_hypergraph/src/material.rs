@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::MaterialId;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// A work order that is scheduled to start before one of its materials is
+/// expected to be available.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MaterialShortage
+{
+    pub work_order_number: WorkOrderNumber,
+    pub material_id: MaterialId,
+    pub expected_availability: NaiveDate,
+    pub scheduled_start: NaiveDate,
+}
+
+/// Public API for the materials that work orders need. A work order
+/// `RequiresMaterial` on the `Day` the material is expected to be available.
+impl ScheduleGraph
+{
+    pub fn add_material(&mut self, material_id: MaterialId) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            if graph.material_node_index(material_id).is_ok() {
+                return Err(ScheduleGraphErrors::MaterialDuplicate {
+                    material_id,
+                    work_order_number: None,
+                });
+            }
+            Ok(graph.add_node(Node::Material(material_id)))
+        })
+    }
+
+    /// The expected availability must be a `Day` in the graph. A work order
+    /// requires each material once.
+    pub fn add_material_requirement(
+        &mut self,
+        work_order_number: WorkOrderNumber,
+        material_id: MaterialId,
+        expected_availability: NaiveDate,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        let material_node_index = self.material_node_index(material_id)?;
+        let day_node_index = self.day_node_index(expected_availability)?;
+        if self
+            .material_requirement_edges(work_order_node_index)
+            .any(|nodes| nodes[1] == material_node_index)
+        {
+            return Err(ScheduleGraphErrors::MaterialDuplicate {
+                material_id,
+                work_order_number: Some(work_order_number),
+            });
+        }
+
+        Ok(self.journaled(|graph| {
+            graph.add_edge(
+                EdgeType::RequiresMaterial,
+                vec![work_order_node_index, material_node_index, day_node_index],
+            )
+        }))
+    }
+
+    /// The materials of the work order with their expected availability,
+    /// ordered by `MaterialId`.
+    pub fn material_requirements(&self, work_order_number: WorkOrderNumber) -> Result<Vec<(MaterialId, NaiveDate)>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        let mut material_requirements = self
+            .material_requirement_edges(work_order_node_index)
+            .filter_map(|nodes| match (&self.nodes()[nodes[1]], &self.nodes()[nodes[2]]) {
+                (Node::Material(material_id), Node::Day(day)) => Some((*material_id, *day)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        material_requirements.sort();
+        Ok(material_requirements)
+    }
+
+    /// The work orders whose first scheduled day is before the expected
+    /// availability of one of their materials. A work order assignment
+    /// counts from the start of its period, an activity assignment from its
+    /// first day. Work orders without assignments are never short.
+    pub fn material_shortages(&self) -> Vec<MaterialShortage>
+    {
+        let mut scheduled_starts = BTreeMap::<WorkOrderNumber, NaiveDate>::new();
+        for assignment in self.edges().filter_map(|(edge_index, _)| self.assignment_view(edge_index)) {
+            let start = assignment
+                .days
+                .iter()
+                .min()
+                .copied()
+                .or(assignment.period.map(|period| period.start_date()));
+            if let Some(start) = start {
+                scheduled_starts
+                    .entry(assignment.work_order)
+                    .and_modify(|scheduled_start| *scheduled_start = (*scheduled_start).min(start))
+                    .or_insert(start);
+            }
+        }
+
+        let mut material_shortages = vec![];
+        for (work_order_number, scheduled_start) in scheduled_starts {
+            let Ok(material_requirements) = self.material_requirements(work_order_number) else {
+                continue;
+            };
+            material_shortages.extend(
+                material_requirements
+                    .into_iter()
+                    .filter(|(_, expected_availability)| scheduled_start < *expected_availability)
+                    .map(|(material_id, expected_availability)| MaterialShortage {
+                        work_order_number,
+                        material_id,
+                        expected_availability,
+                        scheduled_start,
+                    }),
+            );
+        }
+        material_shortages
+    }
+
+    fn material_requirement_edges(&self, work_order_node_index: NodeIndex) -> impl Iterator<Item = &[NodeIndex]> + '_
+    {
+        self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(move |hyperedge| matches!(hyperedge.edge_type(), EdgeType::RequiresMaterial) && hyperedge.nodes()[0] == work_order_node_index)
+            .map(|hyperedge| hyperedge.nodes())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::MaterialShortage;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_material_shortages()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = start_date.and_hms_opt(7, 0, 0).unwrap();
+        let end = (start_date + Duration::days(13)).and_hms_opt(19, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        schedule_graph.add_material(2000100).unwrap();
+        schedule_graph.add_material(2000200).unwrap();
+        assert_eq!(
+            schedule_graph.add_material(2000100),
+            Err(ScheduleGraphErrors::MaterialDuplicate {
+                material_id: 2000100,
+                work_order_number: None,
+            })
+        );
+
+        let day_3 = start_date + Duration::days(3);
+        let day_5 = start_date + Duration::days(5);
+        schedule_graph
+            .add_material_requirement(WorkOrderNumber::new(1122334455).unwrap(), 2000100, day_3)
+            .unwrap();
+        schedule_graph
+            .add_material_requirement(WorkOrderNumber::new(1122334455).unwrap(), 2000200, day_5)
+            .unwrap();
+        schedule_graph
+            .add_material_requirement(WorkOrderNumber::new(1122334466).unwrap(), 2000200, day_5)
+            .unwrap();
+        schedule_graph
+            .add_material_requirement(WorkOrderNumber::new(1122334477).unwrap(), 2000100, day_3)
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_material_requirement(WorkOrderNumber::new(1122334455).unwrap(), 2000100, day_5),
+            Err(ScheduleGraphErrors::MaterialDuplicate {
+                material_id: 2000100,
+                work_order_number: Some(WorkOrderNumber::new(1122334455).unwrap()),
+            })
+        );
+        assert_eq!(
+            schedule_graph.add_material_requirement(WorkOrderNumber::new(1122334455).unwrap(), 2000300, day_5),
+            Err(ScheduleGraphErrors::MaterialMissing { material_id: 2000300 })
+        );
+        assert_eq!(
+            schedule_graph.add_material_requirement(WorkOrderNumber::new(1122334455).unwrap(), 2000200, start_date + Duration::days(30)),
+            Err(ScheduleGraphErrors::DayMissing {
+                day: start_date + Duration::days(30),
+                horizon: Some((start_date, period.end_date() - Duration::days(1))),
+            })
+        );
+        assert_eq!(
+            schedule_graph.material_requirements(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(vec![(2000100, day_3), (2000200, day_5)])
+        );
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        // 1122334455 starts between the two deliveries, 1122334466 is
+        // planned for the period and 1122334477 is not scheduled.
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(4)],
+                shift,
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), period)
+            .unwrap();
+
+        assert_eq!(
+            schedule_graph.material_shortages(),
+            vec![
+                MaterialShortage {
+                    work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                    material_id: 2000200,
+                    expected_availability: day_5,
+                    scheduled_start: start_date + Duration::days(4),
+                },
+                MaterialShortage {
+                    work_order_number: WorkOrderNumber::new(1122334466).unwrap(),
+                    material_id: 2000200,
+                    expected_availability: day_5,
+                    scheduled_start: start_date,
+                },
+            ]
+        );
+        assert!(schedule_graph.validate().is_valid());
+    }
+}
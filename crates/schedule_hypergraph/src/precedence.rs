@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// Public API to order the activities of work orders by their precedence
+/// relations.
+impl ScheduleGraph
+{
+    /// The activity numbers of the work order ordered so that every activity
+    /// comes after the activities preceding it.
+    pub fn activity_topological_order(&self, work_order_number: WorkOrderNumber) -> Result<Vec<ActivityNumber>, ScheduleGraphErrors>
+    {
+        Ok(self
+            .precedence_order(work_order_number)?
+            .into_iter()
+            .filter_map(|activity_node_index| match &self.nodes()[activity_node_index] {
+                Node::Activity(activity) => Some(activity.activity_number()),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Work orders whose precedence relations contain a cycle, with the
+    /// activities that cannot be ordered. These are the activities on a
+    /// cycle and the activities succeeding them.
+    pub fn detect_precedence_cycles(&self) -> BTreeMap<WorkOrderNumber, Vec<ActivityNumber>>
+    {
+        let mut precedence_cycles = BTreeMap::new();
+        for node in self.nodes() {
+            let Node::WorkOrder(work_order_number) = node else {
+                continue;
+            };
+            let Ok((_, unordered)) = self.topological_sort(*work_order_number) else {
+                continue;
+            };
+            if unordered.is_empty() {
+                continue;
+            }
+            let activity_numbers = unordered
+                .into_iter()
+                .filter_map(|activity_node_index| match &self.nodes()[activity_node_index] {
+                    Node::Activity(activity) => Some(activity.activity_number()),
+                    _ => None,
+                })
+                .collect();
+            precedence_cycles.insert(*work_order_number, activity_numbers);
+        }
+        precedence_cycles
+    }
+
+    /// The activity nodes of the work order in topological order, see
+    /// `activity_topological_order`.
+    pub(crate) fn precedence_order(&self, work_order_number: WorkOrderNumber) -> Result<Vec<NodeIndex>, ScheduleGraphErrors>
+    {
+        let (order, unordered) = self.topological_sort(work_order_number)?;
+        if !unordered.is_empty() {
+            return Err(ScheduleGraphErrors::PrecedenceCycle);
+        }
+        Ok(order)
+    }
+
+    /// The `StartStart`, `FinishStart` and `Postpone` hyperedges between the
+    /// activities of the work order as `(previous, next, edge_type)`.
+    pub(crate) fn precedence_relations(
+        &self,
+        work_order_number: WorkOrderNumber,
+    ) -> Result<Vec<(NodeIndex, NodeIndex, EdgeType)>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        let mut relations = vec![];
+        for edge_index in &self.incidence_list()[work_order_node_index] {
+            let contains_edge = &self.hyperedges()[*edge_index];
+            let (EdgeType::Contains, [_, activity_node_index]) = (contains_edge.edge_type(), contains_edge.nodes()) else {
+                continue;
+            };
+            for edge_index in &self.incidence_list()[*activity_node_index] {
+                let hyperedge = &self.hyperedges()[*edge_index];
+                let [previous, next] = hyperedge.nodes() else {
+                    continue;
+                };
+                if previous == activity_node_index
+                    && matches!(
+                        hyperedge.edge_type(),
+                        EdgeType::StartStart | EdgeType::FinishStart | EdgeType::Postpone(_)
+                    )
+                {
+                    relations.push((*previous, *next, hyperedge.edge_type().clone()));
+                }
+            }
+        }
+        Ok(relations)
+    }
+
+    /// Kahn's algorithm over the precedence relations of the work order.
+    /// Returns the ordered activity nodes and the activity nodes left
+    /// unordered by a cycle.
+    fn topological_sort(&self, work_order_number: WorkOrderNumber) -> Result<(Vec<NodeIndex>, Vec<NodeIndex>), ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        let relations = self.precedence_relations(work_order_number)?;
+
+        let mut predecessor_counts = self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|edge_index| self.hyperedges()[*edge_index].nodes())
+            .filter(|nodes| nodes[0] == work_order_node_index)
+            .filter_map(|nodes| nodes.get(1))
+            .filter(|node_index| matches!(self.nodes()[**node_index], Node::Activity(_)))
+            .map(|node_index| (*node_index, 0))
+            .collect::<BTreeMap<NodeIndex, usize>>();
+        for (_, next, _) in &relations {
+            if let Some(predecessor_count) = predecessor_counts.get_mut(next) {
+                *predecessor_count += 1;
+            }
+        }
+
+        let mut ready = predecessor_counts
+            .iter()
+            .filter(|(_, predecessor_count)| **predecessor_count == 0)
+            .map(|(node_index, _)| *node_index)
+            .collect::<Vec<_>>();
+        let mut order = vec![];
+        while let Some(activity_node_index) = ready.pop() {
+            order.push(activity_node_index);
+            for (_, next, _) in relations.iter().filter(|(previous, _, _)| *previous == activity_node_index) {
+                let Some(predecessor_count) = predecessor_counts.get_mut(next) else {
+                    continue;
+                };
+                *predecessor_count -= 1;
+                if *predecessor_count == 0 {
+                    ready.push(*next);
+                }
+            }
+        }
+
+        let unordered = predecessor_counts
+            .into_iter()
+            .filter(|(_, predecessor_count)| *predecessor_count > 0)
+            .map(|(node_index, _)| node_index)
+            .collect();
+        Ok((order, unordered))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::ActivityRelation;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_activity_topological_order()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(30), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap()
+        .with_activity_relations(vec![ActivityRelation::FinishStart, ActivityRelation::FinishStart])
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        assert_eq!(
+            schedule_graph.activity_topological_order(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(vec![ActivityNumber::new(10), ActivityNumber::new(20), ActivityNumber::new(30)])
+        );
+        assert_eq!(schedule_graph.detect_precedence_cycles(), BTreeMap::new());
+        assert_eq!(
+            schedule_graph.activity_topological_order(WorkOrderNumber::new(1122334466).unwrap()),
+            Err(ScheduleGraphErrors::WorkOrderMissing {
+                work_order_number: WorkOrderNumber::new(1122334466).unwrap(),
+            })
+        );
+
+        // A malformed import where 30 finishes before 20 starts.
+        let activity_20 = schedule_graph
+            .activity_node_index(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(20))
+            .unwrap();
+        let activity_30 = schedule_graph
+            .activity_node_index(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(30))
+            .unwrap();
+        schedule_graph.add_edge(EdgeType::FinishStart, vec![activity_30, activity_20]);
+
+        assert_eq!(
+            schedule_graph.activity_topological_order(WorkOrderNumber::new(1122334455).unwrap()),
+            Err(ScheduleGraphErrors::PrecedenceCycle)
+        );
+        assert_eq!(
+            schedule_graph.detect_precedence_cycles(),
+            BTreeMap::from([(
+                WorkOrderNumber::new(1122334455).unwrap(),
+                vec![ActivityNumber::new(20), ActivityNumber::new(30)]
+            )])
+        );
+    }
+}
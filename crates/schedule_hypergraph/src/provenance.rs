@@ -0,0 +1,335 @@
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The actor class that made a planning decision, i.e. created an
+/// `EdgeType::Assign` or `EdgeType::Exclude` hyperedge.
+///
+/// TODO [ ] - Annotate lock edges as well when period locks are part of the
+/// graph.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DecisionSource
+{
+    Import,
+    Optimizer
+    {
+        run_id: String,
+    },
+    User
+    {
+        user_id: String,
+    },
+}
+
+/// Number of assignments made by each actor class.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Authorship
+{
+    pub import: usize,
+    pub optimizer: usize,
+    pub user: usize,
+    /// Assignments that were added without a `DecisionSource`.
+    pub unknown: usize,
+}
+
+impl Authorship
+{
+    pub fn total(&self) -> usize
+    {
+        self.import + self.optimizer + self.user + self.unknown
+    }
+
+    /// Share of the assignments made by an optimizer, between 0 and 1.
+    pub fn machine_made_share(&self) -> f64
+    {
+        if self.total() == 0 {
+            return 0.0;
+        }
+        self.optimizer as f64 / self.total() as f64
+    }
+}
+
+/// Public API to record and audit who made the decisions in the plan.
+impl ScheduleGraph
+{
+    /// Every `EdgeType::Assign` and `EdgeType::Exclude` hyperedge added
+    /// after this call is annotated with the `DecisionSource`. Pass `None` to
+    /// stop annotating.
+    pub fn set_decision_source(&mut self, decision_source: Option<DecisionSource>)
+    {
+        *self.current_decision_source_mut() = decision_source;
+    }
+
+    pub fn decision_source(&self, edge_index: EdgeIndex) -> Result<Option<&DecisionSource>, ScheduleGraphErrors>
+    {
+        if edge_index >= self.hyperedges().len() || self.tombstones().contains(&edge_index) {
+            return Err(ScheduleGraphErrors::EdgeMissing { edge_index });
+        }
+        Ok(self.decision_sources().get(&edge_index))
+    }
+
+    pub fn assignment_decision_source(&self, assignment_id: AssignmentId) -> Result<Option<&DecisionSource>, ScheduleGraphErrors>
+    {
+        let edge_index = self.assignment_edge_index(assignment_id)?;
+        Ok(self.decision_sources().get(&edge_index))
+    }
+
+    /// The `EdgeType::Assign` and `EdgeType::Exclude` hyperedges whose
+    /// `DecisionSource` satisfies the filter.
+    pub fn edges_by_decision_source(&self, filter: impl Fn(Option<&DecisionSource>) -> bool) -> Vec<EdgeIndex>
+    {
+        self.edges()
+            .filter(|(_, hyperedge)| matches!(hyperedge.edge_type(), EdgeType::Assign(_) | EdgeType::Exclude))
+            .filter(|(edge_index, _)| filter(self.decision_sources().get(edge_index)))
+            .map(|(edge_index, _)| edge_index)
+            .collect()
+    }
+
+    pub fn authorship(&self) -> Authorship
+    {
+        self.authorship_of(self.edges().map(|(edge_index, _)| edge_index))
+    }
+
+    /// The `Authorship` of the `EdgeType::Assign` hyperedges among the
+    /// hyperedges.
+    pub(crate) fn authorship_of(&self, edge_indices: impl IntoIterator<Item = EdgeIndex>) -> Authorship
+    {
+        let mut authorship = Authorship::default();
+        for edge_index in edge_indices {
+            if !matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Assign(_)) {
+                continue;
+            }
+            match self.decision_sources().get(&edge_index) {
+                Some(DecisionSource::Import) => authorship.import += 1,
+                Some(DecisionSource::Optimizer { .. }) => authorship.optimizer += 1,
+                Some(DecisionSource::User { .. }) => authorship.user += 1,
+                None => authorship.unknown += 1,
+            }
+        }
+        authorship
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::Authorship;
+    use super::DecisionSource;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_decision_source()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.enable_journal();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        schedule_graph.set_decision_source(Some(DecisionSource::Import));
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                basic_start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let end = basic_start_date.and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let imported_assignment_id = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334455).unwrap(), period)
+            .unwrap();
+
+        let optimizer = DecisionSource::Optimizer { run_id: "run-1".to_string() };
+        schedule_graph.set_decision_source(Some(optimizer.clone()));
+        let optimized_assignment_id = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), period)
+            .unwrap();
+
+        let planner = DecisionSource::User {
+            user_id: "planner".to_string(),
+        };
+        schedule_graph.set_decision_source(Some(planner.clone()));
+        let exclusion_edge_index = schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334477).unwrap(), &period).unwrap();
+
+        schedule_graph.set_decision_source(None);
+        let unknown_assignment_id = schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334477).unwrap(), period)
+            .unwrap();
+
+        assert_eq!(
+            schedule_graph.assignment_decision_source(imported_assignment_id),
+            Ok(Some(&DecisionSource::Import))
+        );
+        assert_eq!(schedule_graph.assignment_decision_source(optimized_assignment_id), Ok(Some(&optimizer)));
+        assert_eq!(schedule_graph.decision_source(exclusion_edge_index), Ok(Some(&planner)));
+        assert_eq!(schedule_graph.assignment_decision_source(unknown_assignment_id), Ok(None));
+        assert_eq!(
+            schedule_graph.edges_by_decision_source(|decision_source| matches!(decision_source, Some(DecisionSource::User { .. }))),
+            vec![exclusion_edge_index]
+        );
+
+        let authorship = schedule_graph.authorship();
+        assert_eq!(
+            authorship,
+            Authorship {
+                import: 1,
+                optimizer: 1,
+                user: 0,
+                unknown: 1,
+            }
+        );
+        assert_eq!(authorship.total(), 3);
+
+        // Undo and redo keep the original source, not the current one.
+        schedule_graph.undo();
+        schedule_graph.undo();
+        schedule_graph.undo();
+        schedule_graph.set_decision_source(Some(DecisionSource::Import));
+        schedule_graph.redo();
+        assert_eq!(schedule_graph.assignment_decision_source(optimized_assignment_id), Ok(Some(&optimizer)));
+
+        schedule_graph.remove_assignment(imported_assignment_id).unwrap();
+        schedule_graph.compact();
+        assert_eq!(schedule_graph.assignment_decision_source(optimized_assignment_id), Ok(Some(&optimizer)));
+        assert_eq!(schedule_graph.authorship().total(), 1);
+    }
+
+    #[test]
+    fn test_unassign_activity_keeps_decision_source()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.enable_journal();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 2, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 2, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = basic_start_date.and_hms_opt(0, 0, 0).unwrap();
+        let end = basic_start_date.succ_opt().unwrap().and_hms_opt(0, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(start, end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        }
+
+        // A 14 hour emergency shift by the optimizer overrides the daily
+        // limit of the `LaborRules`, and activity 20 has no source.
+        let optimizer = DecisionSource::Optimizer { run_id: "run-1".to_string() };
+        schedule_graph.set_decision_source(Some(optimizer.clone()));
+        let shift = |start: u32, finish: u32| {
+            Shift::new(
+                NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(finish, 0, 0).unwrap(),
+                false,
+            )
+            .unwrap()
+        };
+        let assignment_id = schedule_graph
+            .add_assignment_activity_with_labor_rules(
+                vec![TechnicianId::new(1001), TechnicianId::new(1002)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift(6, 20),
+                Some("pump failure".to_string()),
+            )
+            .unwrap();
+        let labor_rule_override = schedule_graph.assignment_labor_rule_override(assignment_id).unwrap().cloned();
+        assert!(labor_rule_override.is_some());
+        schedule_graph.set_decision_source(None);
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001), TechnicianId::new(1002)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+                vec![basic_start_date],
+                shift(20, 22),
+            )
+            .unwrap();
+
+        let planner = DecisionSource::User {
+            user_id: "planner".to_string(),
+        };
+        schedule_graph.set_decision_source(Some(planner));
+        for activity_number in [ActivityNumber::new(10), ActivityNumber::new(20)] {
+            schedule_graph
+                .unassign_activity(TechnicianId::new(1002), WorkOrderNumber::new(1122334455).unwrap(), activity_number)
+                .unwrap();
+        }
+
+        let check_remaining_assignments = |schedule_graph: &ScheduleGraph, remaining_assignment_ids: &[_]| {
+            assert_eq!(
+                schedule_graph.assignment_decision_source(remaining_assignment_ids[0]),
+                Ok(Some(&optimizer))
+            );
+            assert_eq!(
+                schedule_graph.assignment_labor_rule_override(remaining_assignment_ids[0]),
+                Ok(labor_rule_override.as_ref())
+            );
+            assert_eq!(schedule_graph.assignment_decision_source(remaining_assignment_ids[1]), Ok(None));
+            assert_eq!(schedule_graph.assignment_labor_rule_override(remaining_assignment_ids[1]), Ok(None));
+        };
+        let remaining_assignment_ids = schedule_graph
+            .assignments_for_technician(TechnicianId::new(1001))
+            .unwrap()
+            .into_iter()
+            .map(|assignment| assignment.assignment_id)
+            .collect::<Vec<_>>();
+        assert_eq!(remaining_assignment_ids.len(), 2);
+        check_remaining_assignments(&schedule_graph, &remaining_assignment_ids);
+
+        // Undo and redo restore the copied source and override.
+        schedule_graph.undo();
+        schedule_graph.undo();
+        schedule_graph.redo();
+        schedule_graph.redo();
+        check_remaining_assignments(&schedule_graph, &remaining_assignment_ids);
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Priority;
+use scheduling_environment::work_order::Work;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::TechnicianId;
+
+/// Human readable composition of a `ScheduleGraph` instance. This is meant
+/// for stakeholders who will never read the graph itself.
+#[derive(Debug, Default, PartialEq)]
+pub struct InstanceReport
+{
+    pub periods: Vec<Period>,
+    pub skills: Vec<Skill>,
+    pub technicians: Vec<TechnicianSummary>,
+    /// Work orders counted by the `Period` of their basic start date. Work
+    /// orders starting outside of every period are counted under `None`.
+    pub work_orders_by_period: BTreeMap<Option<Period>, usize>,
+    /// Work orders counted by their `Priority`, from the most to the least
+    /// urgent.
+    pub work_orders_by_priority: BTreeMap<Priority, usize>,
+    /// The `Commitment::Forecast` work orders, which are also counted in
+    /// `work_orders_by_period`.
+    pub forecast_work_order_count: usize,
+    pub activity_count: usize,
+    pub assignment_count: usize,
+    pub exclusion_count: usize,
+    pub hse_critical_count: usize,
+    pub simops_restricted_count: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TechnicianSummary
+{
+    pub id: TechnicianId,
+    pub skills: Vec<Skill>,
+    pub available_hours: Work,
+}
+
+/// Public API to generate reports of the instance.
+impl ScheduleGraph
+{
+    pub fn instance_report(&self) -> InstanceReport
+    {
+        let mut instance_report = InstanceReport::default();
+
+        for node in self.nodes() {
+            match node {
+                Node::Period(period) => instance_report.periods.push(*period),
+                Node::Skill(skill) => instance_report.skills.push(*skill),
+                Node::Activity(_) => instance_report.activity_count += 1,
+                Node::WorkOrder(work_order_number) => {
+                    let priority = self.work_order_priority(*work_order_number).unwrap_or_default();
+                    *instance_report.work_orders_by_priority.entry(priority).or_default() += 1;
+                }
+                Node::Technician(_)
+                | Node::Day(_)
+                | Node::WorkPackage(_)
+                | Node::Crew(_)
+                | Node::FunctionalLocation(_)
+                | Node::Equipment(_)
+                | Node::Material(_)
+                | Node::ContractorPool(_)
+                | Node::ShutdownWindow(_) => (),
+            }
+        }
+        instance_report.periods.sort();
+        instance_report.skills.sort();
+
+        let mut technicians = BTreeMap::<TechnicianId, TechnicianSummary>::new();
+        for (_, hyperedge) in self.edges() {
+            match hyperedge.edge_type() {
+                EdgeType::Available(availability) => {
+                    let mut skills = vec![];
+                    let mut technician_id = None;
+                    for node_index in hyperedge.nodes() {
+                        match &self.nodes()[*node_index] {
+                            Node::Technician(id) => technician_id = Some(*id),
+                            Node::Skill(skill) => skills.push(*skill),
+                            _ => (),
+                        }
+                    }
+                    let Some(technician_id) = technician_id else {
+                        continue;
+                    };
+
+                    let technician_summary = technicians.entry(technician_id).or_insert_with(|| TechnicianSummary {
+                        id: technician_id,
+                        skills: vec![],
+                        available_hours: Work::ZERO,
+                    });
+                    technician_summary.skills.extend(skills);
+                    technician_summary.skills.sort();
+                    technician_summary.skills.dedup();
+                    technician_summary.available_hours += Work::from_duration(availability.end() - availability.start());
+                }
+                EdgeType::BasicStart => {
+                    let Node::Day(basic_start_date) = self.nodes()[hyperedge.nodes()[1]] else {
+                        continue;
+                    };
+                    let period = instance_report
+                        .periods
+                        .iter()
+                        .find(|period| self.period_days(period).is_ok_and(|days| days.contains(&basic_start_date)))
+                        .copied();
+
+                    *instance_report.work_orders_by_period.entry(period).or_default() += 1;
+                }
+                EdgeType::Forecast => instance_report.forecast_work_order_count += 1,
+                EdgeType::Assign(_) => instance_report.assignment_count += 1,
+                EdgeType::Exclude => instance_report.exclusion_count += 1,
+                EdgeType::Risk(risk_classification) => {
+                    instance_report.hse_critical_count += risk_classification.hse_critical as usize;
+                    instance_report.simops_restricted_count += risk_classification.simops_restricted as usize;
+                }
+                _ => (),
+            }
+        }
+        instance_report.technicians = technicians.into_values().collect();
+
+        instance_report
+    }
+}
+
+impl InstanceReport
+{
+    pub fn work_order_count(&self) -> usize
+    {
+        self.work_orders_by_period.values().sum()
+    }
+
+    pub fn to_markdown(&self) -> String
+    {
+        let mut markdown = String::new();
+
+        writeln!(markdown, "# Schedule Instance Report\n").unwrap();
+
+        writeln!(markdown, "## Periods\n").unwrap();
+        for period in &self.periods {
+            writeln!(markdown, "- {}", period.start_date()).unwrap();
+        }
+
+        writeln!(markdown, "\n## Skills\n").unwrap();
+        for skill in &self.skills {
+            writeln!(markdown, "- {skill}").unwrap();
+        }
+
+        writeln!(markdown, "\n## Technicians\n").unwrap();
+        writeln!(markdown, "| Technician | Skills | Available hours |").unwrap();
+        writeln!(markdown, "|---|---|---|").unwrap();
+        for technician in &self.technicians {
+            writeln!(
+                markdown,
+                "| {} | {} | {:.1} |",
+                technician.id,
+                skills_to_string(&technician.skills),
+                technician.available_hours
+            )
+            .unwrap();
+        }
+
+        writeln!(markdown, "\n## Work Orders\n").unwrap();
+        writeln!(markdown, "| Basic start period | Work orders |").unwrap();
+        writeln!(markdown, "|---|---|").unwrap();
+        for (period, count) in &self.work_orders_by_period {
+            writeln!(markdown, "| {} | {} |", period_to_string(period), count).unwrap();
+        }
+
+        writeln!(markdown, "\n| Priority | Work orders |").unwrap();
+        writeln!(markdown, "|---|---|").unwrap();
+        for (priority, count) in &self.work_orders_by_priority {
+            writeln!(markdown, "| {priority:?} | {count} |").unwrap();
+        }
+
+        writeln!(markdown, "\n## Totals\n").unwrap();
+        writeln!(markdown, "- Work orders: {}", self.work_order_count()).unwrap();
+        writeln!(markdown, "- Forecast work orders: {}", self.forecast_work_order_count).unwrap();
+        writeln!(markdown, "- Activities: {}", self.activity_count).unwrap();
+        writeln!(markdown, "- Assignments: {}", self.assignment_count).unwrap();
+        writeln!(markdown, "- Exclusions: {}", self.exclusion_count).unwrap();
+        writeln!(markdown, "- HSE-critical work orders: {}", self.hse_critical_count).unwrap();
+        writeln!(markdown, "- SIMOPS-restricted work orders: {}", self.simops_restricted_count).unwrap();
+
+        markdown
+    }
+
+    /// The report as an HTML fragment. Every name in the report is
+    /// escaped, so e.g. a skill name cannot inject markup into the page.
+    pub fn to_html(&self) -> String
+    {
+        let mut html = String::new();
+
+        writeln!(html, "<h1>Schedule Instance Report</h1>").unwrap();
+
+        writeln!(html, "<h2>Periods</h2>\n<ul>").unwrap();
+        for period in &self.periods {
+            writeln!(html, "<li>{}</li>", escape_html(&period.start_date().to_string())).unwrap();
+        }
+        writeln!(html, "</ul>").unwrap();
+
+        writeln!(html, "<h2>Skills</h2>\n<ul>").unwrap();
+        for skill in &self.skills {
+            writeln!(html, "<li>{}</li>", escape_html(skill.name())).unwrap();
+        }
+        writeln!(html, "</ul>").unwrap();
+
+        writeln!(html, "<h2>Technicians</h2>\n<table>").unwrap();
+        writeln!(html, "<tr><th>Technician</th><th>Skills</th><th>Available hours</th></tr>").unwrap();
+        for technician in &self.technicians {
+            writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(&technician.id.to_string()),
+                escape_html(&skills_to_string(&technician.skills)),
+                escape_html(&format!("{:.1}", technician.available_hours))
+            )
+            .unwrap();
+        }
+        writeln!(html, "</table>").unwrap();
+
+        writeln!(html, "<h2>Work Orders</h2>\n<table>").unwrap();
+        writeln!(html, "<tr><th>Basic start period</th><th>Work orders</th></tr>").unwrap();
+        for (period, count) in &self.work_orders_by_period {
+            writeln!(html, "<tr><td>{}</td><td>{}</td></tr>", escape_html(&period_to_string(period)), count).unwrap();
+        }
+        writeln!(html, "</table>").unwrap();
+
+        writeln!(html, "<table>").unwrap();
+        writeln!(html, "<tr><th>Priority</th><th>Work orders</th></tr>").unwrap();
+        for (priority, count) in &self.work_orders_by_priority {
+            writeln!(html, "<tr><td>{}</td><td>{}</td></tr>", escape_html(&format!("{priority:?}")), count).unwrap();
+        }
+        writeln!(html, "</table>").unwrap();
+
+        writeln!(html, "<h2>Totals</h2>\n<ul>").unwrap();
+        writeln!(html, "<li>Work orders: {}</li>", self.work_order_count()).unwrap();
+        writeln!(html, "<li>Forecast work orders: {}</li>", self.forecast_work_order_count).unwrap();
+        writeln!(html, "<li>Activities: {}</li>", self.activity_count).unwrap();
+        writeln!(html, "<li>Assignments: {}</li>", self.assignment_count).unwrap();
+        writeln!(html, "<li>Exclusions: {}</li>", self.exclusion_count).unwrap();
+        writeln!(html, "<li>HSE-critical work orders: {}</li>", self.hse_critical_count).unwrap();
+        writeln!(html, "<li>SIMOPS-restricted work orders: {}</li>", self.simops_restricted_count).unwrap();
+        writeln!(html, "</ul>").unwrap();
+
+        html
+    }
+}
+
+fn skills_to_string(skills: &[Skill]) -> String
+{
+    skills.iter().map(|skill| format!("{skill}")).collect::<Vec<_>>().join(", ")
+}
+
+/// Escapes the characters that have a meaning in HTML text and attribute
+/// values.
+fn escape_html(text: &str) -> String
+{
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn period_to_string(period: &Option<Period>) -> String
+{
+    match period {
+        Some(period) => period.start_date().to_string(),
+        None => "Outside of periods".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Commitment;
+    use scheduling_environment::work_order::Priority;
+    use scheduling_environment::work_order::RiskClassification;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_instance_report()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let period_start = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(period_start);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            period_start,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap()
+        .with_priority(Priority::High)
+        .with_risk_classification(RiskClassification {
+            hse_critical: true,
+            simops_restricted: false,
+        });
+        schedule_graph.add_work_order(&work_order).unwrap();
+        schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &period).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334466).unwrap(),
+            period_start,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap()
+        .with_commitment(Commitment::Forecast);
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = period_start.and_hms_opt(8, 0, 0).unwrap();
+        let end = period_start.and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let instance_report = schedule_graph.instance_report();
+
+        assert_eq!(instance_report.periods, vec![period]);
+        assert_eq!(instance_report.skills, vec![Skill::MTN_MECH]);
+        assert_eq!(instance_report.technicians[0].available_hours, Work::from_hours(8.0));
+        assert_eq!(instance_report.work_orders_by_period[&Some(period)], 2);
+        assert_eq!(instance_report.forecast_work_order_count, 1);
+        assert_eq!(instance_report.activity_count, 3);
+        assert_eq!(instance_report.exclusion_count, 1);
+        assert_eq!(instance_report.hse_critical_count, 1);
+        assert_eq!(instance_report.simops_restricted_count, 0);
+        assert_eq!(
+            instance_report.work_orders_by_priority,
+            BTreeMap::from([(Priority::High, 1), (Priority::Medium, 1)])
+        );
+
+        let markdown = instance_report.to_markdown();
+        assert!(markdown.contains("| 1001 | MtnMech | 8.0 |"));
+        assert!(markdown.contains("| 2025-01-13 | 2 |"));
+        assert!(markdown.contains("- Forecast work orders: 1"));
+        assert!(markdown.contains("- HSE-critical work orders: 1"));
+
+        assert!(markdown.contains("| High | 1 |"));
+
+        let html = instance_report.to_html();
+        assert!(html.contains("<tr><td>1001</td><td>MtnMech</td><td>8.0</td></tr>"));
+        assert!(html.contains("<tr><td>High</td><td>1</td></tr>"));
+
+        // A skill name is text, not markup.
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.add_skill(Skill::new("<b>Mtn&Mech</b>")).unwrap();
+        let html = schedule_graph.instance_report().to_html();
+        assert!(html.contains("<li>&lt;b&gt;Mtn&amp;Mech&lt;/b&gt;</li>"));
+        assert!(!html.contains("<b>"));
+    }
+}
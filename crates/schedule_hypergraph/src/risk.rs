@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use scheduling_environment::work_order::RiskClassification;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::AssignmentView;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// Public API to query the `RiskClassification` of the work in the graph.
+///
+/// TODO [ ] - Filter by zone when work orders have a location.
+impl ScheduleGraph
+{
+    pub fn risk_classification(&self, work_order_number: WorkOrderNumber) -> Result<RiskClassification, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        Ok(self.incidence_list()[work_order_node_index]
+            .iter()
+            .find_map(|&edge_index| match self.hyperedges()[edge_index].edge_type() {
+                EdgeType::Risk(risk_classification) => Some(*risk_classification),
+                _ => None,
+            })
+            .unwrap_or_default())
+    }
+
+    /// Activity assignments of HSE-critical work orders on the day. This is
+    /// the agenda of the daily permit meeting.
+    pub fn hse_critical_assignments_on(&self, day: NaiveDate) -> Vec<AssignmentView>
+    {
+        let risk_classifications = self.risk_classifications();
+
+        self.activity_assignments()
+            .filter(|assignment| assignment.days.contains(&day))
+            .filter(|assignment| risk_classifications.get(&assignment.work_order).is_some_and(|risk| risk.hse_critical))
+            .collect()
+    }
+
+    /// Pairs of work orders that are executed at the same time where at least
+    /// one of them is SIMOPS-restricted. Each pair is ordered and only
+    /// reported once.
+    pub fn simops_conflicts(&self) -> Vec<(WorkOrderNumber, WorkOrderNumber)>
+    {
+        let risk_classifications = self.risk_classifications();
+
+        let mut intervals = HashMap::<WorkOrderNumber, Vec<(NaiveDateTime, NaiveDateTime)>>::new();
+        for assignment in self.activity_assignments() {
+            intervals.entry(assignment.work_order).or_default().extend(assignment.intervals());
+        }
+
+        let mut conflicts = vec![];
+        for (work_order_number, work_order_intervals) in &intervals {
+            if !risk_classifications.get(work_order_number).is_some_and(|risk| risk.simops_restricted) {
+                continue;
+            }
+            for (other_work_order_number, other_intervals) in &intervals {
+                if work_order_number == other_work_order_number {
+                    continue;
+                }
+                let overlaps = work_order_intervals.iter().any(|(start, finish)| {
+                    other_intervals
+                        .iter()
+                        .any(|(other_start, other_finish)| start < other_finish && other_start < finish)
+                });
+                if overlaps {
+                    conflicts.push((
+                        *work_order_number.min(other_work_order_number),
+                        *work_order_number.max(other_work_order_number),
+                    ));
+                }
+            }
+        }
+
+        conflicts.sort();
+        conflicts.dedup();
+        conflicts
+    }
+
+    fn risk_classifications(&self) -> HashMap<WorkOrderNumber, RiskClassification>
+    {
+        self.edges()
+            .filter_map(|(_, hyperedge)| {
+                let EdgeType::Risk(risk_classification) = hyperedge.edge_type() else {
+                    return None;
+                };
+                match self.nodes()[hyperedge.nodes()[0]] {
+                    Node::WorkOrder(work_order_number) => Some((work_order_number, *risk_classification)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    fn activity_assignments(&self) -> impl Iterator<Item = AssignmentView>
+    {
+        self.edges()
+            .filter_map(|(edge_index, _)| self.assignment_view(edge_index))
+            .filter(|assignment| assignment.activity.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::RiskClassification;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_risk_classification()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let tuesday = monday + Duration::days(1);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+
+        let risk_classifications = [
+            (WorkOrderNumber::new(1122334455).unwrap(), RiskClassification::default()),
+            (
+                WorkOrderNumber::new(1122334466).unwrap(),
+                RiskClassification {
+                    hse_critical: true,
+                    simops_restricted: false,
+                },
+            ),
+            (
+                WorkOrderNumber::new(1122334477).unwrap(),
+                RiskClassification {
+                    hse_critical: false,
+                    simops_restricted: true,
+                },
+            ),
+        ];
+        for (work_order_number, risk_classification) in risk_classifications {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                monday,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap()
+            .with_risk_classification(risk_classification);
+            schedule_graph.add_work_order(&work_order).unwrap();
+            assert_eq!(schedule_graph.risk_classification(work_order_number), Ok(risk_classification));
+        }
+
+        let availability_start = monday.and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = tuesday.and_hms_opt(23, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002), TechnicianId::new(1003)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability_start, availability_end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph
+                .add_technician(technician, Availability::new(availability_start, availability_end))
+                .unwrap();
+        }
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                Shift::new(time(8), time(12), false).unwrap(),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1002)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                Shift::new(time(13), time(16), false).unwrap(),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1003)],
+                WorkOrderNumber::new(1122334477).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                Shift::new(time(11), time(14), false).unwrap(),
+            )
+            .unwrap();
+
+        let hse_critical_assignments = schedule_graph.hse_critical_assignments_on(monday);
+        assert_eq!(hse_critical_assignments.len(), 1);
+        assert_eq!(hse_critical_assignments[0].work_order, WorkOrderNumber::new(1122334466).unwrap());
+        assert!(schedule_graph.hse_critical_assignments_on(tuesday).is_empty());
+
+        assert_eq!(
+            schedule_graph.simops_conflicts(),
+            vec![
+                (WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334477).unwrap()),
+                (WorkOrderNumber::new(1122334466).unwrap(), WorkOrderNumber::new(1122334477).unwrap())
+            ]
+        );
+    }
+}
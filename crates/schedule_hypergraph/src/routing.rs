@@ -0,0 +1,284 @@
+use chrono::NaiveDate;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::location::Coordinates;
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+/// An assignment visited on a `DayRoute`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteStop
+{
+    pub assignment_id: AssignmentId,
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: Option<ActivityNumber>,
+    /// `None` if the work order has no location with coordinates.
+    pub coordinates: Option<Coordinates>,
+}
+
+/// The order in which a technician visits the jobs of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayRoute
+{
+    /// The stops with coordinates in the order of the route, followed by
+    /// the stops without coordinates ordered by `AssignmentId`.
+    pub stops: Vec<RouteStop>,
+    /// The travel between the stops with coordinates, starting from the
+    /// start of the route if there is one.
+    pub distance_km: f64,
+}
+
+/// Public API to plan the travel of technicians between their jobs.
+impl ScheduleGraph
+{
+    /// Orders the activity assignments of the technician on the day to
+    /// minimize the travel between their work orders. The route is built
+    /// with the nearest-neighbour heuristic and improved with 2-opt until
+    /// no reversal of a part of the route shortens it. Without a start the
+    /// route starts at the first stop by `AssignmentId`.
+    ///
+    /// The assignments are not changed, the route is a proposal for the
+    /// dispatcher.
+    pub fn route_day_plan(&self, technician_id: TechnicianId, day: NaiveDate, start: Option<Coordinates>) -> Result<DayRoute, ScheduleGraphErrors>
+    {
+        let mut located_stops = vec![];
+        let mut unlocated_stops = vec![];
+        for assignment in self.assignments_on_day(technician_id, day)? {
+            let route_stop = RouteStop {
+                assignment_id: assignment.assignment_id,
+                work_order_number: assignment.work_order,
+                activity_number: assignment.activity,
+                coordinates: self.work_order_coordinates(assignment.work_order)?,
+            };
+            match route_stop.coordinates {
+                Some(coordinates) => located_stops.push((route_stop, coordinates)),
+                None => unlocated_stops.push(route_stop),
+            }
+        }
+
+        let mut route = nearest_neighbour_route(start, located_stops);
+        improve_with_two_opt(start, &mut route);
+
+        let distance_km = route_distance_km(start, &route);
+        let stops = route.into_iter().map(|(route_stop, _)| route_stop).chain(unlocated_stops).collect();
+        Ok(DayRoute { stops, distance_km })
+    }
+}
+
+fn nearest_neighbour_route(start: Option<Coordinates>, mut stops: Vec<(RouteStop, Coordinates)>) -> Vec<(RouteStop, Coordinates)>
+{
+    let mut route = Vec::with_capacity(stops.len());
+    let mut position = match start {
+        Some(start) => start,
+        None if stops.is_empty() => return route,
+        None => {
+            let first_stop = stops.remove(0);
+            let position = first_stop.1;
+            route.push(first_stop);
+            position
+        }
+    };
+
+    while !stops.is_empty() {
+        let nearest_index = (0..stops.len())
+            .min_by(|&left, &right| position.distance_km(&stops[left].1).total_cmp(&position.distance_km(&stops[right].1)))
+            .expect("stops is not empty");
+        let nearest_stop = stops.remove(nearest_index);
+        position = nearest_stop.1;
+        route.push(nearest_stop);
+    }
+    route
+}
+
+/// Reverses parts of the route as long as that shortens it. Without a
+/// start the first stop stays first.
+fn improve_with_two_opt(start: Option<Coordinates>, route: &mut [(RouteStop, Coordinates)])
+{
+    let first_movable_stop = if start.is_some() { 0 } else { 1 };
+    let mut distance_km = route_distance_km(start, route);
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for first in first_movable_stop..route.len() {
+            for last in first + 1..route.len() {
+                route[first..=last].reverse();
+                let reversed_distance_km = route_distance_km(start, route);
+                if reversed_distance_km + 1e-9 < distance_km {
+                    distance_km = reversed_distance_km;
+                    improved = true;
+                } else {
+                    route[first..=last].reverse();
+                }
+            }
+        }
+    }
+}
+
+fn route_distance_km(start: Option<Coordinates>, route: &[(RouteStop, Coordinates)]) -> f64
+{
+    start
+        .into_iter()
+        .chain(route.iter().map(|(_, coordinates)| *coordinates))
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|leg| leg[0].distance_km(&leg[1]))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::location::Coordinates;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_route_day_plan()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        // Three pumps along the coast, assigned out of order, and one work
+        // order without a location.
+        let locations = [("1000-PMP-03", 55.70), ("1000-PMP-01", 55.50), ("1000-PMP-02", 55.60)];
+        for (work_order_number, (functional_location_id, latitude)) in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ]
+        .into_iter()
+        .zip(locations)
+        {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+            schedule_graph.add_functional_location(functional_location_id).unwrap();
+            schedule_graph
+                .set_functional_location_coordinates(functional_location_id, Coordinates { latitude, longitude: 12.0 })
+                .unwrap();
+            schedule_graph.add_work_order_location(work_order_number, functional_location_id).unwrap();
+        }
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334488).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        for work_order_number in [
+            WorkOrderNumber::new(1122334488).unwrap(),
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            schedule_graph
+                .add_assignment_activity(
+                    vec![TechnicianId::new(1001)],
+                    work_order_number,
+                    ActivityNumber::new(10),
+                    vec![start_date],
+                    shift,
+                )
+                .unwrap();
+        }
+
+        let workshop = Coordinates {
+            latitude: 55.45,
+            longitude: 12.0,
+        };
+        let day_route = schedule_graph
+            .route_day_plan(TechnicianId::new(1001), start_date, Some(workshop))
+            .unwrap();
+        assert_eq!(
+            day_route.stops.iter().map(|route_stop| route_stop.work_order_number).collect::<Vec<_>>(),
+            vec![
+                WorkOrderNumber::new(1122334466).unwrap(),
+                WorkOrderNumber::new(1122334477).unwrap(),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                WorkOrderNumber::new(1122334488).unwrap()
+            ]
+        );
+        let expected_distance_km = workshop.distance_km(&Coordinates {
+            latitude: 55.70,
+            longitude: 12.0,
+        });
+        assert!((day_route.distance_km - expected_distance_km).abs() < 1e-6);
+        assert!((expected_distance_km - 27.8).abs() < 0.1);
+
+        // Without a start the route starts at the first assignment and
+        // goes to the nearest pump from there.
+        let day_route = schedule_graph.route_day_plan(TechnicianId::new(1001), start_date, None).unwrap();
+        assert_eq!(
+            day_route.stops.iter().map(|route_stop| route_stop.work_order_number).collect::<Vec<_>>(),
+            vec![
+                WorkOrderNumber::new(1122334455).unwrap(),
+                WorkOrderNumber::new(1122334477).unwrap(),
+                WorkOrderNumber::new(1122334466).unwrap(),
+                WorkOrderNumber::new(1122334488).unwrap()
+            ]
+        );
+
+        schedule_graph
+            .set_functional_location_coordinates(
+                "1000-PMP-03",
+                Coordinates {
+                    latitude: 55.40,
+                    longitude: 12.0,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph
+                .functional_location_coordinates("1000-PMP-03")
+                .unwrap()
+                .map(|coordinates| coordinates.latitude),
+            Some(55.40)
+        );
+        assert!(
+            schedule_graph
+                .route_day_plan(TechnicianId::new(1001), start_date + chrono::Duration::days(1), None)
+                .unwrap()
+                .stops
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The urgency rules of HSE-critical work orders, see
+/// `RiskClassification::hse_critical`. Safety-critical notifications cannot
+/// wait for the next open period, so the rules that protect the near-term
+/// plan can be lifted for them. Every rule is off by default.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct SafetyPolicy
+{
+    /// HSE-critical work orders are not counted against the `IntakeLimit`s.
+    pub bypass_intake_limits: bool,
+    /// HSE-critical work orders may be assigned in the frozen window, see
+    /// `ScheduleGraph::freeze_until`. The days of archived periods stay
+    /// frozen.
+    pub bypass_frozen_window: bool,
+}
+
+/// A rule that an HSE-critical work order was exempted from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SafetyBypass
+{
+    IntakeLimit,
+    FrozenWindow,
+}
+
+/// Recorded when an assignment of an HSE-critical work order is only
+/// accepted because of the `SafetyPolicy`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SafetyOverride
+{
+    pub work_order_number: WorkOrderNumber,
+    pub bypasses: Vec<SafetyBypass>,
+    /// The `SystemClock` time of the assignment.
+    pub logged_at: NaiveDateTime,
+}
+
+/// Public API for the `SafetyPolicy` of the graph.
+///
+/// The overrides are an audit log, they are neither removed with the
+/// assignment nor by undo.
+impl ScheduleGraph
+{
+    pub fn set_safety_policy(&mut self, safety_policy: SafetyPolicy)
+    {
+        self.config_mut().safety_policy = safety_policy;
+    }
+
+    pub fn safety_policy(&self) -> &SafetyPolicy
+    {
+        &self.config().safety_policy
+    }
+
+    /// Whether the `SafetyPolicy` exempts the work order from the rule.
+    pub(crate) fn safety_exempt(&self, work_order_number: WorkOrderNumber, safety_bypass: SafetyBypass) -> bool
+    {
+        let safety_policy = self.safety_policy();
+        let enabled = match safety_bypass {
+            SafetyBypass::IntakeLimit => safety_policy.bypass_intake_limits,
+            SafetyBypass::FrozenWindow => safety_policy.bypass_frozen_window,
+        };
+        enabled
+            && self
+                .risk_classification(work_order_number)
+                .is_ok_and(|risk_classification| risk_classification.hse_critical)
+    }
+
+    /// The rules that adding work of the work order on the days only passes
+    /// because of the `SafetyPolicy`. Call it before the mutation and log
+    /// the result with `ScheduleGraph::log_safety_override` after it.
+    pub(crate) fn safety_bypasses(
+        &self,
+        work_order_number: WorkOrderNumber,
+        days: &[NaiveDate],
+        added_hours: &BTreeMap<Period, Work>,
+        released_hours: &BTreeMap<Period, Work>,
+    ) -> Result<Vec<SafetyBypass>, ScheduleGraphErrors>
+    {
+        let mut safety_bypasses = vec![];
+        if self.safety_exempt(work_order_number, SafetyBypass::IntakeLimit)
+            && self.intake_limit_exceeded(work_order_number, added_hours, released_hours)?.is_some()
+        {
+            safety_bypasses.push(SafetyBypass::IntakeLimit);
+        }
+        if self.safety_exempt(work_order_number, SafetyBypass::FrozenWindow) && self.check_frozen_days(days).is_err() {
+            safety_bypasses.push(SafetyBypass::FrozenWindow);
+        }
+        Ok(safety_bypasses)
+    }
+
+    pub(crate) fn log_safety_override(&mut self, work_order_number: WorkOrderNumber, safety_bypasses: Vec<SafetyBypass>)
+    {
+        if safety_bypasses.is_empty() {
+            return;
+        }
+        tracing::warn!(
+            %work_order_number,
+            ?safety_bypasses,
+            "HSE-critical work order assigned in spite of the near-term planning rules"
+        );
+        let logged_at = self.system_clock().now();
+        self.safety_overrides_mut().push(SafetyOverride {
+            work_order_number,
+            bypasses: safety_bypasses,
+            logged_at,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::RiskClassification;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::SafetyBypass;
+    use super::SafetyPolicy;
+    use crate::intake::IntakeLimit;
+    use crate::intake::IntakeQuantity;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_safety_policy()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        let routine_work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&routine_work_order).unwrap();
+        let safety_work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334466).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap()
+        .with_risk_classification(RiskClassification {
+            hse_critical: true,
+            simops_restricted: false,
+        });
+        schedule_graph.add_work_order(&safety_work_order).unwrap();
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = (start_date + Duration::days(13)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let frozen_day = start_date + Duration::days(1);
+        let open_day = start_date + Duration::days(5);
+        schedule_graph.freeze_until(frozen_day);
+        schedule_graph
+            .set_intake_limit(
+                period,
+                IntakeLimit {
+                    max_new_work_orders: Some(0),
+                    max_new_hours: None,
+                },
+            )
+            .unwrap();
+
+        // Without a policy the safety work waits like any other work.
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![frozen_day],
+                shift
+            ),
+            Err(ScheduleGraphErrors::FrozenPeriod { day: frozen_day })
+        );
+
+        schedule_graph.set_safety_policy(SafetyPolicy {
+            bypass_intake_limits: true,
+            bypass_frozen_window: true,
+        });
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![frozen_day],
+                shift
+            ),
+            Err(ScheduleGraphErrors::FrozenPeriod { day: frozen_day })
+        );
+        assert_eq!(
+            schedule_graph.add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![open_day],
+                shift
+            ),
+            Err(ScheduleGraphErrors::IntakeLimitExceeded {
+                period,
+                limit: IntakeQuantity::WorkOrders(0),
+                requested: IntakeQuantity::WorkOrders(1),
+            })
+        );
+
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![frozen_day],
+                shift,
+            )
+            .unwrap();
+        let safety_overrides = schedule_graph.safety_overrides();
+        assert_eq!(safety_overrides.len(), 1);
+        assert_eq!(safety_overrides[0].work_order_number, WorkOrderNumber::new(1122334466).unwrap());
+        assert_eq!(safety_overrides[0].bypasses, vec![SafetyBypass::IntakeLimit, SafetyBypass::FrozenWindow]);
+
+        // The moved work order is still new intake of the period.
+        schedule_graph.unfreeze();
+        schedule_graph
+            .move_assignment(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![open_day],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.safety_overrides()[1].bypasses, vec![SafetyBypass::IntakeLimit]);
+    }
+}
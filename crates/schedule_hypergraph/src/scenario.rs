@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+
+use crate::diff::ScheduleDiff;
+use crate::events::Listeners;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// Public API to branch the graph for what-if analysis.
+impl ScheduleGraph
+{
+    /// An independent copy of the graph. The nodes, hyperedges and incidence
+    /// list are shared with `self` until either graph is mutated, so forking
+    /// a graph that is only read is cheap.
+    ///
+    /// The `GraphListener`s are not carried over, a fork is a scenario and
+    /// not the plan that the listeners observe.
+    pub fn fork(&self) -> ScheduleGraph
+    {
+        let mut fork = self.clone();
+        *fork.listeners_mut() = Listeners::default();
+        fork
+    }
+}
+
+/// Named branches of a `ScheduleGraph`. Every scenario is forked from
+/// another scenario, the first one is called `ScenarioManager::BASE`.
+#[derive(Clone, Debug)]
+pub struct ScenarioManager
+{
+    scenarios: BTreeMap<String, ScheduleGraph>,
+}
+
+impl ScenarioManager
+{
+    pub const BASE: &str = "base";
+
+    pub fn new(base: ScheduleGraph) -> Self
+    {
+        Self {
+            scenarios: BTreeMap::from([(Self::BASE.to_string(), base)]),
+        }
+    }
+
+    /// Forks the scenario `from` into a new scenario called `name`.
+    pub fn branch(&mut self, from: &str, name: impl Into<String>) -> Result<&mut ScheduleGraph, ScheduleGraphErrors>
+    {
+        let fork = self.scenario(from)?.fork();
+
+        match self.scenarios.entry(name.into()) {
+            Entry::Occupied(_) => Err(ScheduleGraphErrors::ScenarioDuplicate),
+            Entry::Vacant(vacant_entry) => Ok(vacant_entry.insert(fork)),
+        }
+    }
+
+    pub fn scenario(&self, name: &str) -> Result<&ScheduleGraph, ScheduleGraphErrors>
+    {
+        self.scenarios.get(name).ok_or_else(|| self.scenario_missing(name))
+    }
+
+    pub fn scenario_mut(&mut self, name: &str) -> Result<&mut ScheduleGraph, ScheduleGraphErrors>
+    {
+        if !self.scenarios.contains_key(name) {
+            return Err(self.scenario_missing(name));
+        }
+        Ok(self.scenarios.get_mut(name).expect("the scenario exists"))
+    }
+
+    /// Removes the scenario. The `ScenarioManager::BASE` scenario cannot be
+    /// removed.
+    pub fn remove(&mut self, name: &str) -> Result<ScheduleGraph, ScheduleGraphErrors>
+    {
+        if name == Self::BASE {
+            return Err(self.scenario_missing(name));
+        }
+        self.scenarios.remove(name).ok_or_else(|| self.scenario_missing(name))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str>
+    {
+        self.scenarios.keys().map(String::as_str)
+    }
+
+    /// The changes needed to go from scenario `from` to scenario `to`.
+    pub fn compare(&self, from: &str, to: &str) -> Result<ScheduleDiff, ScheduleGraphErrors>
+    {
+        Ok(self.scenario(from)?.diff(self.scenario(to)?))
+    }
+
+    /// The `ScheduleGraphErrors::ScenarioMissing` of the name, with the
+    /// scenarios that can be removed, or all of them if the name is not
+    /// `ScenarioManager::BASE`.
+    fn scenario_missing(&self, name: &str) -> ScheduleGraphErrors
+    {
+        ScheduleGraphErrors::ScenarioMissing {
+            name: name.to_string(),
+            scenarios: self
+                .names()
+                .filter(|scenario| name != Self::BASE || *scenario != Self::BASE)
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::ScenarioManager;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_scenario_manager()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability_start = basic_start_date.and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = basic_start_date.and_hms_opt(23, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let mut scenario_manager = ScenarioManager::new(schedule_graph);
+
+        scenario_manager.branch(ScenarioManager::BASE, "assigned").unwrap();
+
+        // The fork shares the nodes with the base until it is mutated.
+        assert_eq!(
+            scenario_manager.scenario("assigned").unwrap().nodes().as_ptr(),
+            scenario_manager.scenario(ScenarioManager::BASE).unwrap().nodes().as_ptr()
+        );
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        scenario_manager
+            .scenario_mut("assigned")
+            .unwrap()
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+
+        assert_eq!(
+            scenario_manager
+                .scenario(ScenarioManager::BASE)
+                .unwrap()
+                .assignments_for_technician(TechnicianId::new(1001)),
+            Ok(vec![])
+        );
+        let schedule_diff = scenario_manager.compare(ScenarioManager::BASE, "assigned").unwrap();
+        assert_eq!(schedule_diff.added_assignments.len(), 1);
+        assert!(schedule_diff.removed_assignments.is_empty());
+
+        assert_eq!(
+            scenario_manager.branch("assigned", "assigned").map(|_| ()),
+            Err(ScheduleGraphErrors::ScenarioDuplicate)
+        );
+        assert_eq!(
+            scenario_manager.remove(ScenarioManager::BASE).map(|_| ()),
+            Err(ScheduleGraphErrors::ScenarioMissing {
+                name: ScenarioManager::BASE.to_string(),
+                scenarios: vec!["assigned".to_string()],
+            })
+        );
+        assert_eq!(
+            scenario_manager.scenario("unassigned").map(|_| ()),
+            Err(ScheduleGraphErrors::ScenarioMissing {
+                name: "unassigned".to_string(),
+                scenarios: vec!["assigned".to_string(), ScenarioManager::BASE.to_string()],
+            })
+        );
+        scenario_manager.remove("assigned").unwrap();
+        assert_eq!(scenario_manager.names().collect::<Vec<_>>(), vec![ScenarioManager::BASE]);
+    }
+}
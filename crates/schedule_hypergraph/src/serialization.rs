@@ -0,0 +1,531 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use scheduling_environment::Period;
+use scheduling_environment::work_order::WorkOrderNumber;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde::de::Error;
+
+use crate::close_out::PeriodCloseOut;
+use crate::config::GraphConfig;
+use crate::events::GraphEntity;
+use crate::id_allocator::PrefixedIdAllocator;
+use crate::import::ImportError;
+use crate::intake::IntakeState;
+use crate::labor_rules::LaborRuleOverride;
+use crate::ledger::SkillHourEntry;
+use crate::provenance::DecisionSource;
+use crate::safety::SafetyOverride;
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::AssignmentView;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::HyperEdge;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::timestamps::Timestamps;
+use crate::unscheduled::UnscheduledReason;
+
+/// Only the `nodes`, `hyperedges`, `tombstones`, `labor_rule_overrides`,
+/// `decision_sources`, `intake_limits`, `skill_hour_entries`,
+/// `period_close_outs`, `period_baselines`, `safety_overrides`, the
+/// `PrefixedIdAllocator`, `unscheduled_reasons`, the `GraphConfig`, the
+/// `Timestamps` and the `AssignmentId`s of the assignments that are not removed
+/// are serialized. The index maps and the incidence list are rebuilt on
+/// deserialization so that they can never disagree with the nodes and
+/// hyperedges.
+///
+/// Maps keyed by a `Period` are serialized as lists of pairs, as JSON only
+/// allows string keys.
+#[derive(Serialize)]
+struct SerializeScheduleGraph<'a>
+{
+    nodes: &'a [Node],
+    hyperedges: &'a [HyperEdge],
+    tombstones: &'a BTreeSet<EdgeIndex>,
+    labor_rule_overrides: &'a BTreeMap<EdgeIndex, LaborRuleOverride>,
+    decision_sources: &'a BTreeMap<EdgeIndex, DecisionSource>,
+    intake_limits: Vec<(&'a Period, &'a IntakeState)>,
+    skill_hour_entries: &'a [SkillHourEntry],
+    period_close_outs: Vec<(&'a Period, &'a PeriodCloseOut)>,
+    period_baselines: Vec<(&'a Period, &'a Vec<AssignmentView>)>,
+    safety_overrides: &'a [SafetyOverride],
+    id_allocator: &'a PrefixedIdAllocator,
+    unscheduled_reasons: &'a BTreeMap<WorkOrderNumber, Vec<UnscheduledReason>>,
+    assignment_ids: BTreeMap<EdgeIndex, AssignmentId>,
+    next_assignment_id: u64,
+    node_timestamps: &'a [Timestamps],
+    edge_timestamps: &'a [Timestamps],
+    config: &'a GraphConfig,
+}
+
+#[derive(Deserialize)]
+struct DeserializeScheduleGraph
+{
+    nodes: Vec<Node>,
+    hyperedges: Vec<HyperEdge>,
+    tombstones: BTreeSet<EdgeIndex>,
+    #[serde(default)]
+    labor_rule_overrides: BTreeMap<EdgeIndex, LaborRuleOverride>,
+    #[serde(default)]
+    decision_sources: BTreeMap<EdgeIndex, DecisionSource>,
+    #[serde(default)]
+    intake_limits: Vec<(Period, IntakeState)>,
+    #[serde(default)]
+    skill_hour_entries: Vec<SkillHourEntry>,
+    #[serde(default)]
+    period_close_outs: Vec<(Period, PeriodCloseOut)>,
+    #[serde(default)]
+    period_baselines: Vec<(Period, Vec<AssignmentView>)>,
+    #[serde(default)]
+    safety_overrides: Vec<SafetyOverride>,
+    #[serde(default)]
+    id_allocator: PrefixedIdAllocator,
+    #[serde(default)]
+    unscheduled_reasons: BTreeMap<WorkOrderNumber, Vec<UnscheduledReason>>,
+    assignment_ids: Option<BTreeMap<EdgeIndex, AssignmentId>>,
+    #[serde(default)]
+    next_assignment_id: u64,
+    #[serde(default)]
+    node_timestamps: Vec<Timestamps>,
+    #[serde(default)]
+    edge_timestamps: Vec<Timestamps>,
+    #[serde(default)]
+    config: GraphConfig,
+}
+
+impl Serialize for ScheduleGraph
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializeScheduleGraph {
+            nodes: self.nodes(),
+            hyperedges: self.hyperedges(),
+            tombstones: self.tombstones(),
+            labor_rule_overrides: self.labor_rule_overrides(),
+            decision_sources: self.decision_sources(),
+            intake_limits: self.intake_limits().iter().collect(),
+            skill_hour_entries: self.skill_hour_entries(),
+            period_close_outs: self.period_close_outs().iter().collect(),
+            period_baselines: self.period_baselines().iter().collect(),
+            safety_overrides: self.safety_overrides(),
+            id_allocator: self.id_allocator(),
+            unscheduled_reasons: self.unscheduled_reasons(),
+            assignment_ids: self.live_assignment_ids(),
+            next_assignment_id: self.next_assignment_id(),
+            node_timestamps: self.node_timestamps(),
+            edge_timestamps: self.edge_timestamps(),
+            config: self.config(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleGraph
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let DeserializeScheduleGraph {
+            nodes,
+            hyperedges,
+            tombstones,
+            labor_rule_overrides,
+            decision_sources,
+            intake_limits,
+            skill_hour_entries,
+            period_close_outs,
+            period_baselines,
+            safety_overrides,
+            id_allocator,
+            unscheduled_reasons,
+            assignment_ids,
+            next_assignment_id,
+            node_timestamps,
+            edge_timestamps,
+            config,
+        } = DeserializeScheduleGraph::deserialize(deserializer)?;
+
+        let mut schedule_graph = ScheduleGraph::from_parts(
+            nodes,
+            hyperedges,
+            tombstones,
+            labor_rule_overrides,
+            decision_sources,
+            intake_limits.into_iter().collect(),
+            skill_hour_entries,
+        )
+        .map_err(|error| D::Error::custom(format!("invalid ScheduleGraph: {error:?}")))?;
+        schedule_graph.set_config(config);
+
+        // Every close out and baseline of a missing period is reported, not
+        // only the first one.
+        let mut import_errors = vec![];
+        let period_missing = |row: usize, column: &'static str, period: Period, schedule_graph: &ScheduleGraph| ImportError {
+            row,
+            column: Some(column),
+            entity: GraphEntity::Period(period),
+            error: schedule_graph.period_missing(period),
+        };
+        for (row, (period, period_close_out)) in period_close_outs.into_iter().enumerate() {
+            if !schedule_graph.nodes().contains(&Node::Period(period)) {
+                import_errors.push(period_missing(row, "period_close_outs", period, &schedule_graph));
+                continue;
+            }
+            schedule_graph.period_close_outs_mut().insert(period, period_close_out);
+        }
+        for (row, (period, baseline)) in period_baselines.into_iter().enumerate() {
+            if !schedule_graph.nodes().contains(&Node::Period(period)) {
+                import_errors.push(period_missing(row, "period_baselines", period, &schedule_graph));
+                continue;
+            }
+            schedule_graph.period_baselines_mut().insert(period, baseline);
+        }
+        if !import_errors.is_empty() {
+            let import_errors = import_errors.iter().map(ImportError::to_string).collect::<Vec<_>>();
+            return Err(D::Error::custom(format!("invalid ScheduleGraph: {}", import_errors.join("; "))));
+        }
+        *schedule_graph.safety_overrides_mut() = safety_overrides;
+        schedule_graph.set_id_allocator(id_allocator);
+        schedule_graph
+            .record_unscheduled_reasons(unscheduled_reasons)
+            .map_err(|error| D::Error::custom(format!("invalid ScheduleGraph: {error:?}")))?;
+
+        // Graphs serialized before `AssignmentId`s existed keep the ids
+        // minted by `from_parts`.
+        if let Some(assignment_ids) = assignment_ids {
+            schedule_graph
+                .set_assignment_ids(assignment_ids, next_assignment_id)
+                .map_err(|error| D::Error::custom(format!("invalid ScheduleGraph: {error:?}")))?;
+        }
+        // Graphs serialized before `Timestamps` existed are timestamped at
+        // deserialization.
+        if !node_timestamps.is_empty() || !edge_timestamps.is_empty() {
+            schedule_graph
+                .set_timestamps(node_timestamps, edge_timestamps)
+                .map_err(|error| D::Error::custom(format!("invalid ScheduleGraph: {error:?}")))?;
+        }
+        Ok(schedule_graph)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::ActivityRelation;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::close_out::CloseOutDecision;
+    use crate::close_out::PeriodCloseOut;
+    use crate::intake::IntakeLimit;
+    use crate::provenance::DecisionSource;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+    use crate::unscheduled::UnscheduledReason;
+
+    #[test]
+    fn test_schedule_graph_round_trip()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 2, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability_start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = basic_start_date.and_hms_opt(17, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(availability_start, availability_end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        // The removed assignment keeps its id, so the ids differ from the
+        // ones that `from_parts` mints.
+        let removed_assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        schedule_graph.remove_assignment(removed_assignment_id).unwrap();
+        let assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        schedule_graph.set_decision_source(Some(DecisionSource::Import));
+        let exclusion_edge_index = schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &period).unwrap();
+        schedule_graph.remove_edge(exclusion_edge_index).unwrap();
+        schedule_graph.set_intake_limit(period, IntakeLimit::default()).unwrap();
+        schedule_graph
+            .reserve_skill_hours(period, Skill::MTN_MECH, Work::from_hours(4.0), basic_start_date)
+            .unwrap();
+        schedule_graph.commit_baseline(period).unwrap();
+        schedule_graph
+            .close_period(
+                period,
+                &BTreeMap::from([(WorkOrderNumber::new(1122334455).unwrap(), CloseOutDecision::Completed)]),
+            )
+            .unwrap();
+        schedule_graph.set_strict_availability(true);
+        schedule_graph.allocate_work_order_number().unwrap();
+        schedule_graph
+            .record_unscheduled_reasons(BTreeMap::from([(
+                WorkOrderNumber::new(1122334455).unwrap(),
+                vec![UnscheduledReason::Excluded],
+            )]))
+            .unwrap();
+
+        let json = serde_json::to_string(&schedule_graph).unwrap();
+        let mut deserialized_graph: ScheduleGraph = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized_graph.nodes(), schedule_graph.nodes());
+        assert_eq!(deserialized_graph.hyperedges(), schedule_graph.hyperedges());
+        assert_eq!(deserialized_graph.incidence_list(), schedule_graph.incidence_list());
+        assert_eq!(deserialized_graph.tombstones(), schedule_graph.tombstones());
+        assert_eq!(deserialized_graph.node_timestamps(), schedule_graph.node_timestamps());
+        assert_eq!(deserialized_graph.edge_timestamps(), schedule_graph.edge_timestamps());
+        assert_eq!(deserialized_graph.decision_sources(), schedule_graph.decision_sources());
+        assert_eq!(deserialized_graph.intake_limits(), schedule_graph.intake_limits());
+        assert_eq!(deserialized_graph.skill_hour_entries(), schedule_graph.skill_hour_entries());
+        assert_eq!(deserialized_graph.period_close_outs(), schedule_graph.period_close_outs());
+        assert_eq!(deserialized_graph.period_baselines(), schedule_graph.period_baselines());
+        assert_eq!(deserialized_graph.config(), schedule_graph.config());
+        assert_eq!(deserialized_graph.id_allocator(), schedule_graph.id_allocator());
+        assert_eq!(deserialized_graph.unscheduled_reasons(), schedule_graph.unscheduled_reasons());
+        assert_eq!(
+            deserialized_graph.assignments_for_technician(TechnicianId::new(1001)),
+            schedule_graph.assignments_for_technician(TechnicianId::new(1001))
+        );
+        assert_eq!(deserialized_graph.assignment(assignment_id), schedule_graph.assignment(assignment_id));
+        assert_eq!(
+            deserialized_graph.assignment(removed_assignment_id),
+            Err(ScheduleGraphErrors::AssignmentMissing {
+                assignment_id: removed_assignment_id,
+            })
+        );
+
+        // The index maps are rebuilt, so lookups and duplicate checks still work.
+        assert_eq!(
+            deserialized_graph.add_period(period),
+            Err(ScheduleGraphErrors::PeriodDuplicate { period })
+        );
+        assert_eq!(
+            deserialized_graph.add_work_order(&work_order),
+            Err(ScheduleGraphErrors::WorkOrderDuplicate {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            })
+        );
+        deserialized_graph
+            .unassign_activity(
+                TechnicianId::new(1001),
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+            )
+            .unwrap();
+        assert_eq!(deserialized_graph.assignments_for_technician(TechnicianId::new(1001)), Ok(vec![]));
+        let next_assignment_id = deserialized_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        assert!(next_assignment_id > assignment_id);
+    }
+
+    #[test]
+    fn test_schedule_graph_rejects_dangling_node_indices()
+    {
+        let json = r#"{"nodes":[],"hyperedges":[{"edge_type":"Exclude","nodes":[0]}],"tombstones":[]}"#;
+
+        let error = serde_json::from_str::<ScheduleGraph>(json).unwrap_err();
+
+        assert!(error.to_string().contains("NodeMissing"));
+    }
+
+    #[test]
+    fn test_schedule_graph_rejects_duplicate_nodes_and_dangling_tombstones()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph
+            .add_period(Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap()))
+            .unwrap();
+        let json = serde_json::to_value(&schedule_graph).unwrap();
+
+        let mut duplicate_nodes = json.clone();
+        let period_node = duplicate_nodes["nodes"][0].clone();
+        duplicate_nodes["nodes"].as_array_mut().unwrap().push(period_node);
+        let error = serde_json::from_value::<ScheduleGraph>(duplicate_nodes).unwrap_err();
+        assert!(error.to_string().contains("NodeDuplicate"));
+
+        let mut dangling_tombstones = json;
+        let edge_index = dangling_tombstones["hyperedges"].as_array().unwrap().len();
+        dangling_tombstones["tombstones"] = serde_json::json!([edge_index]);
+        let error = serde_json::from_value::<ScheduleGraph>(dangling_tombstones).unwrap_err();
+        assert!(error.to_string().contains(&format!("EdgeMissing {{ edge_index: {edge_index} }}")));
+    }
+
+    #[test]
+    fn test_schedule_graph_rejects_precedence_cycles()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let activity_10 = schedule_graph
+            .activity_node_index(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10))
+            .unwrap();
+        let activity_20 = schedule_graph
+            .activity_node_index(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(20))
+            .unwrap();
+        schedule_graph.add_edge(EdgeType::FinishStart, vec![activity_20, activity_10]);
+
+        let json = serde_json::to_string(&schedule_graph).unwrap();
+        let error = serde_json::from_str::<ScheduleGraph>(&json).unwrap_err();
+
+        assert!(error.to_string().contains("PrecedenceCycle"));
+    }
+
+    #[test]
+    fn test_schedule_graph_reports_every_missing_period()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_period(period).unwrap();
+
+        // The close outs and baselines of two periods that are not in the
+        // graph.
+        let mut json = serde_json::to_value(&schedule_graph).unwrap();
+        let missing_periods = [
+            Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap()),
+            Period::from_start_date(NaiveDate::from_ymd_opt(2025, 2, 10).unwrap()),
+        ];
+        let period_close_out = PeriodCloseOut {
+            completed: vec![],
+            carried_over: BTreeMap::new(),
+            returned_to_backlog: vec![],
+            actual_hours: BTreeMap::new(),
+            schedule_adherence: None,
+        };
+        json["period_close_outs"] = serde_json::json!([[missing_periods[1], period_close_out]]);
+        json["period_baselines"] = serde_json::json!([[period, []], [missing_periods[0], []], [missing_periods[1], []]]);
+
+        let error = serde_json::from_value::<ScheduleGraph>(json).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "invalid ScheduleGraph: row 0, column period_close_outs: period starting 2025-02-10 is missing, the graph has periods starting [2025-01-13]; \
+             row 1, column period_baselines: period starting 2025-01-27 is missing, the graph has periods starting [2025-01-13]; \
+             row 2, column period_baselines: period starting 2025-02-10 is missing, the graph has periods starting [2025-01-13]"
+        );
+    }
+
+    #[test]
+    fn test_schedule_graph_rejects_unscheduled_reasons_of_missing_work_orders()
+    {
+        let mut json = serde_json::to_value(ScheduleGraph::new()).unwrap();
+        json["unscheduled_reasons"] = serde_json::json!({ "1122334455": ["Excluded"] });
+
+        let error = serde_json::from_value::<ScheduleGraph>(json).unwrap_err();
+
+        assert!(error.to_string().contains("WorkOrderMissing"));
+    }
+
+    #[test]
+    fn test_work_order_round_trip_is_validated()
+    {
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap()
+        .with_activity_relations(vec![ActivityRelation::StartStart])
+        .unwrap();
+
+        let json = serde_json::to_value(&work_order).unwrap();
+        let deserialized_work_order: WorkOrder = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(deserialized_work_order.activities_relations(), &vec![ActivityRelation::StartStart]);
+
+        // Too few relations would make `add_work_order` index out of bounds.
+        let mut missing_relation = json.clone();
+        missing_relation["activity_relations"] = serde_json::json!([]);
+        let Err(error) = serde_json::from_value::<WorkOrder>(missing_relation) else {
+            panic!("a work order with too few activity relations was deserialized");
+        };
+        assert_eq!(
+            error.to_string(),
+            "there has to be one relation between each pair of consecutive activities"
+        );
+
+        let mut unsorted_activities = json;
+        unsorted_activities["activities"].as_array_mut().unwrap().reverse();
+        assert!(serde_json::from_value::<WorkOrder>(unsorted_activities).is_err());
+    }
+}
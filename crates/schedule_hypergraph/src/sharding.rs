@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::conflicts::ScheduleConflict;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::TechnicianId;
+
+/// The work orders and technicians that belong to a single work center.
+/// The `Skill` of an activity is the work center that executes it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorkCenterShard
+{
+    pub work_orders: BTreeSet<WorkOrderNumber>,
+    pub technicians: BTreeSet<TechnicianId>,
+}
+
+/// A split of the graph into independent `WorkCenterShard`s and the
+/// boundary that is shared between them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorkCenterPartition
+{
+    pub shards: BTreeMap<Skill, WorkCenterShard>,
+    /// Technicians with more than one skill, i.e. technicians that are lent
+    /// across work centers.
+    pub boundary_technicians: BTreeSet<TechnicianId>,
+    /// Work orders with activities in more than one work center, or with no
+    /// activities at all.
+    pub boundary_work_orders: BTreeSet<WorkOrderNumber>,
+}
+
+/// Public API to decompose the graph by work center, so that each shard can
+/// be optimized on its own. The strategic algorithm optimizes the shards
+/// in parallel and merges them with its `ShardedSolver`.
+impl ScheduleGraph
+{
+    pub fn partition_by_work_center(&self) -> WorkCenterPartition
+    {
+        let mut work_center_partition = WorkCenterPartition::default();
+
+        for (node_index, node) in self.nodes().iter().enumerate() {
+            match node {
+                Node::WorkOrder(work_order_number) => {
+                    let work_centers = self.incidence_list()[node_index]
+                        .iter()
+                        .map(|edge_index| &self.hyperedges()[*edge_index])
+                        .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Contains) && hyperedge.nodes()[0] == node_index)
+                        .flat_map(|hyperedge| hyperedge.nodes().get(1))
+                        .flat_map(|activity_node_index| self.node_skills(*activity_node_index, |edge_type| matches!(edge_type, EdgeType::Requires)))
+                        .collect::<BTreeSet<_>>();
+                    match work_centers.first() {
+                        Some(work_center) if work_centers.len() == 1 => {
+                            work_center_partition
+                                .shards
+                                .entry(*work_center)
+                                .or_default()
+                                .work_orders
+                                .insert(*work_order_number);
+                        }
+                        _ => {
+                            work_center_partition.boundary_work_orders.insert(*work_order_number);
+                        }
+                    }
+                }
+                Node::Technician(technician_id) => {
+                    let work_centers = self.node_skills(node_index, |edge_type| {
+                        matches!(edge_type, EdgeType::Available(_) | EdgeType::HasSkill(_))
+                    });
+                    match work_centers.first() {
+                        Some(work_center) if work_centers.len() == 1 => {
+                            work_center_partition
+                                .shards
+                                .entry(*work_center)
+                                .or_default()
+                                .technicians
+                                .insert(*technician_id);
+                        }
+                        _ => {
+                            work_center_partition.boundary_technicians.insert(*technician_id);
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        work_center_partition
+    }
+
+    /// The double bookings of the boundary technicians. When the shards are
+    /// optimized on their own these are the conflicts that the merge has to
+    /// resolve.
+    pub fn boundary_conflicts(&self, work_center_partition: &WorkCenterPartition) -> Vec<ScheduleConflict>
+    {
+        self.detect_conflicts()
+            .into_iter()
+            .filter(|schedule_conflict| match schedule_conflict {
+                ScheduleConflict::DoubleBooking { technician_id, .. } => work_center_partition.boundary_technicians.contains(technician_id),
+                ScheduleConflict::ExcludedPeriod { .. } => false,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeSet;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_partition_by_work_center()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(Period::from_start_date(monday)).unwrap();
+
+        let work_orders = [
+            (
+                WorkOrderNumber::new(1122334455).unwrap(),
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            ),
+            (
+                WorkOrderNumber::new(1122334466).unwrap(),
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_ELEC)],
+            ),
+            (
+                WorkOrderNumber::new(1122334477).unwrap(),
+                vec![
+                    Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                    Activity::new(ActivityNumber::new(20), 1, Skill::MTN_ELEC),
+                ],
+            ),
+        ];
+        for (work_order_number, activities) in work_orders {
+            let work_order = WorkOrder::new(work_order_number, monday, activities).unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = monday.and_hms_opt(7, 0, 0).unwrap();
+        let end = monday.and_hms_opt(15, 0, 0).unwrap();
+        for (technician_id, skills) in [
+            (TechnicianId::new(1001), vec![Skill::MTN_MECH]),
+            (TechnicianId::new(1002), vec![Skill::MTN_ELEC]),
+            (TechnicianId::new(1003), vec![Skill::MTN_MECH, Skill::MTN_ELEC]),
+        ] {
+            let mut technician_builder = Technician::builder(technician_id).add_availability(start, end).unwrap();
+            for skill in skills {
+                technician_builder = technician_builder.add_skill(skill);
+            }
+            schedule_graph
+                .add_technician(technician_builder.build(), Availability::new(start, end))
+                .unwrap();
+        }
+
+        let work_center_partition = schedule_graph.partition_by_work_center();
+
+        let mechanical_shard = &work_center_partition.shards[&Skill::MTN_MECH];
+        assert_eq!(mechanical_shard.work_orders, BTreeSet::from([WorkOrderNumber::new(1122334455).unwrap()]));
+        assert_eq!(mechanical_shard.technicians, BTreeSet::from([TechnicianId::new(1001)]));
+        let electrical_shard = &work_center_partition.shards[&Skill::MTN_ELEC];
+        assert_eq!(electrical_shard.work_orders, BTreeSet::from([WorkOrderNumber::new(1122334466).unwrap()]));
+        assert_eq!(electrical_shard.technicians, BTreeSet::from([TechnicianId::new(1002)]));
+        assert_eq!(work_center_partition.boundary_technicians, BTreeSet::from([TechnicianId::new(1003)]));
+        assert_eq!(
+            work_center_partition.boundary_work_orders,
+            BTreeSet::from([WorkOrderNumber::new(1122334477).unwrap()])
+        );
+
+        // Both shards book the lent technician at the same time.
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1003)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift,
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1003)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift,
+            )
+            .unwrap();
+
+        assert_eq!(schedule_graph.boundary_conflicts(&work_center_partition).len(), 1);
+    }
+}
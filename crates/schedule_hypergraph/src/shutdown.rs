@@ -0,0 +1,332 @@
+use std::collections::BTreeSet;
+
+use scheduling_environment::Period;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::EquipmentId;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::ShutdownWindowId;
+
+/// Public API for shutdown windows. A shutdown window is an outage of
+/// equipment that can only be isolated in a single period, e.g. a unit
+/// shutdown of the plant, and every work order that needs the isolation
+/// depends on the window. Moving the window moves the work of its
+/// dependents along with it.
+impl ScheduleGraph
+{
+    /// Adds the window with an `EdgeType::Outage` of the equipment in the
+    /// period.
+    pub fn add_shutdown_window(
+        &mut self,
+        shutdown_window_id: ShutdownWindowId,
+        period: Period,
+        equipment_ids: &[EquipmentId],
+    ) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        if self.shutdown_window_node_index(shutdown_window_id).is_ok() {
+            return Err(ScheduleGraphErrors::ShutdownWindowDuplicate { shutdown_window_id });
+        }
+        let mut nodes = vec![self.period_node_index(period)?];
+        for equipment_id in equipment_ids {
+            nodes.push(self.equipment_node_index(*equipment_id)?);
+        }
+
+        self.journaled(|graph| {
+            let shutdown_window_node_index = graph.add_node(Node::ShutdownWindow(shutdown_window_id));
+            nodes.insert(0, shutdown_window_node_index);
+            graph.add_edge(EdgeType::Outage, nodes);
+            Ok(shutdown_window_node_index)
+        })
+    }
+
+    /// Adds an `EdgeType::During` from the work order to the window.
+    pub fn add_shutdown_window_dependency(
+        &mut self,
+        work_order_number: WorkOrderNumber,
+        shutdown_window_id: ShutdownWindowId,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+        let shutdown_window_node_index = self.shutdown_window_node_index(shutdown_window_id)?;
+
+        let nodes = vec![work_order_node_index, shutdown_window_node_index];
+        if self.edge_exists(&EdgeType::During, &nodes) {
+            return Err(ScheduleGraphErrors::DependencyDuplicate);
+        }
+
+        Ok(self.journaled(|graph| graph.add_edge(EdgeType::During, nodes)))
+    }
+
+    /// The period in which the equipment of the window is isolated.
+    pub fn shutdown_window_period(&self, shutdown_window_id: ShutdownWindowId) -> Result<Period, ScheduleGraphErrors>
+    {
+        let shutdown_window_node_index = self.shutdown_window_node_index(shutdown_window_id)?;
+        let outage_edge_index = self.outage_edge_index(shutdown_window_node_index);
+
+        match &self.nodes()[self.hyperedges()[outage_edge_index].nodes()[1]] {
+            Node::Period(period) => Ok(*period),
+            _ => unreachable!("the second node of an `EdgeType::Outage` is a period"),
+        }
+    }
+
+    /// The work orders that depend on the window, ordered by
+    /// `WorkOrderNumber`.
+    pub fn shutdown_window_dependents(&self, shutdown_window_id: ShutdownWindowId) -> Result<Vec<WorkOrderNumber>, ScheduleGraphErrors>
+    {
+        let shutdown_window_node_index = self.shutdown_window_node_index(shutdown_window_id)?;
+        let mut dependents = self.incidence_list()[shutdown_window_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::During))
+            .filter_map(|hyperedge| match &self.nodes()[hyperedge.nodes()[0]] {
+                Node::WorkOrder(work_order_number) => Some(*work_order_number),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        dependents.sort();
+        Ok(dependents)
+    }
+
+    /// Moves the window to the new period and cascades the move to every
+    /// dependent work order. The work order assignments of a dependent in
+    /// the old period are placed in the new period, its activity
+    /// assignments are shifted by the offset between the start dates of the
+    /// two periods, and the hours contracted to pools in the old period are
+    /// contracted in the new period instead.
+    ///
+    /// All assignments are removed before any of them are added again, so
+    /// the dependents do not compete with their own old assignments for
+    /// capacity. If any of the moved assignments is rejected, e.g. by a
+    /// frozen day or an intake limit, the graph is rolled back and the
+    /// window stays where it is.
+    ///
+    /// Returns the new `AssignmentId`s of the moved assignments.
+    pub fn move_window(&mut self, shutdown_window_id: ShutdownWindowId, new_period: Period) -> Result<Vec<AssignmentId>, ScheduleGraphErrors>
+    {
+        let shutdown_window_node_index = self.shutdown_window_node_index(shutdown_window_id)?;
+        let new_period_node_index = self.period_node_index(new_period)?;
+        let old_period = self.shutdown_window_period(shutdown_window_id)?;
+        if old_period == new_period {
+            return Ok(vec![]);
+        }
+
+        let dependents = self.shutdown_window_dependents(shutdown_window_id)?.into_iter().collect::<BTreeSet<_>>();
+        let moved_assignments = self
+            .assignments_for_period(old_period)?
+            .into_iter()
+            .filter(|assignment_view| dependents.contains(&assignment_view.work_order))
+            .collect::<Vec<_>>();
+        let moved_contracts = self
+            .edges()
+            .filter(|(_, hyperedge)| {
+                matches!(hyperedge.edge_type(), EdgeType::Contracted(_)) && self.nodes()[hyperedge.nodes()[2]] == Node::Period(old_period)
+            })
+            .filter_map(|(edge_index, hyperedge)| {
+                let (EdgeType::Contracted(contracted_hours), Node::Activity(activity_node), Node::ContractorPool(contractor_pool_id)) = (
+                    hyperedge.edge_type(),
+                    &self.nodes()[hyperedge.nodes()[0]],
+                    &self.nodes()[hyperedge.nodes()[1]],
+                ) else {
+                    return None;
+                };
+                dependents.contains(&activity_node.work_order_number()).then_some((
+                    edge_index,
+                    *contractor_pool_id,
+                    activity_node.work_order_number(),
+                    activity_node.activity_number(),
+                    contracted_hours.hours,
+                ))
+            })
+            .collect::<Vec<_>>();
+        let offset = new_period.start_date() - old_period.start_date();
+
+        self.transaction(|schedule_graph| {
+            schedule_graph.journaled(|graph| {
+                let outage_edge_index = graph.outage_edge_index(shutdown_window_node_index);
+                let mut outage_nodes = graph.hyperedges()[outage_edge_index].nodes().to_vec();
+                outage_nodes[1] = new_period_node_index;
+                graph.remove_edge(outage_edge_index)?;
+                graph.add_edge(EdgeType::Outage, outage_nodes);
+
+                for assignment_view in &moved_assignments {
+                    graph.remove_assignment(assignment_view.assignment_id)?;
+                }
+                if !moved_contracts.is_empty() {
+                    graph.check_frozen_days(&graph.period_days(&old_period)?)?;
+                }
+                for (edge_index, ..) in &moved_contracts {
+                    graph.remove_edge(*edge_index)?;
+                }
+
+                let mut assignment_ids = vec![];
+                for assignment_view in moved_assignments {
+                    let assignment_id = match (assignment_view.activity, assignment_view.shift) {
+                        (Some(activity_number), Some(shift)) => graph.add_assignment_activity(
+                            assignment_view.technicians,
+                            assignment_view.work_order,
+                            activity_number,
+                            assignment_view.days.iter().map(|day| *day + offset).collect(),
+                            shift,
+                        )?,
+                        _ => graph.add_assignment_work_order(assignment_view.technicians[0], assignment_view.work_order, new_period)?,
+                    };
+                    assignment_ids.push(assignment_id);
+                }
+                for (_, contractor_pool_id, work_order_number, activity_number, hours) in moved_contracts {
+                    graph.add_assignment_contractor_pool(contractor_pool_id, work_order_number, activity_number, new_period, hours)?;
+                }
+                Ok(assignment_ids)
+            })
+        })
+    }
+
+    fn outage_edge_index(&self, shutdown_window_node_index: NodeIndex) -> EdgeIndex
+    {
+        self.incidence_list()[shutdown_window_node_index]
+            .iter()
+            .copied()
+            .find(|&edge_index| matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Outage))
+            .expect("a shutdown window is added with an `EdgeType::Outage`")
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_move_window()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+        for work_order_number in [
+            WorkOrderNumber::new(1122334455).unwrap(),
+            WorkOrderNumber::new(1122334466).unwrap(),
+            WorkOrderNumber::new(1122334477).unwrap(),
+        ] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let start = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let end = (start_date + Duration::days(27)).and_hms_opt(20, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        schedule_graph.add_equipment(5, None).unwrap();
+        schedule_graph.add_shutdown_window(1, next_period, &[5]).unwrap();
+        assert_eq!(
+            schedule_graph.add_shutdown_window(1, next_period, &[5]),
+            Err(ScheduleGraphErrors::ShutdownWindowDuplicate { shutdown_window_id: 1 })
+        );
+        schedule_graph
+            .add_shutdown_window_dependency(WorkOrderNumber::new(1122334466).unwrap(), 1)
+            .unwrap();
+        schedule_graph
+            .add_shutdown_window_dependency(WorkOrderNumber::new(1122334455).unwrap(), 1)
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_shutdown_window_dependency(WorkOrderNumber::new(1122334455).unwrap(), 1),
+            Err(ScheduleGraphErrors::DependencyDuplicate)
+        );
+        assert_eq!(
+            schedule_graph.shutdown_window_dependents(1),
+            Ok(vec![WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()])
+        );
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![next_period.start_date() + Duration::days(2)],
+                shift,
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), next_period)
+            .unwrap();
+        // Not a dependent of the window, so it stays.
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334477).unwrap(), next_period)
+            .unwrap();
+
+        let moved_assignment_ids = schedule_graph.move_window(1, period).unwrap();
+        assert_eq!(moved_assignment_ids.len(), 2);
+        assert_eq!(schedule_graph.shutdown_window_period(1), Ok(period));
+
+        let moved_assignments = schedule_graph.assignments_for_period(period).unwrap();
+        assert_eq!(moved_assignments.len(), 2);
+        assert!(moved_assignments.iter().any(|assignment_view| {
+            assignment_view.work_order == WorkOrderNumber::new(1122334455).unwrap()
+                && assignment_view.days == vec![start_date + Duration::days(2)]
+                && assignment_view.shift == Some(shift)
+        }));
+        assert!(moved_assignments.iter().any(|assignment_view| {
+            assignment_view.work_order == WorkOrderNumber::new(1122334466).unwrap() && assignment_view.period == Some(period)
+        }));
+        let remaining_assignments = schedule_graph.assignments_for_period(next_period).unwrap();
+        assert_eq!(remaining_assignments.len(), 1);
+        assert_eq!(remaining_assignments[0].work_order, WorkOrderNumber::new(1122334477).unwrap());
+
+        // The window cannot leave the frozen days, so nothing moves.
+        schedule_graph.freeze_until(start_date + Duration::days(2));
+        assert_eq!(
+            schedule_graph.move_window(1, next_period),
+            Err(ScheduleGraphErrors::FrozenPeriod {
+                day: start_date + Duration::days(2)
+            })
+        );
+        assert_eq!(schedule_graph.shutdown_window_period(1), Ok(period));
+        assert_eq!(schedule_graph.assignments_for_period(period).unwrap(), moved_assignments);
+        assert_eq!(schedule_graph.assignments_for_period(next_period).unwrap(), remaining_assignments);
+
+        assert_eq!(
+            schedule_graph.move_window(2, next_period),
+            Err(ScheduleGraphErrors::ShutdownWindowMissing { shutdown_window_id: 2 })
+        );
+    }
+}
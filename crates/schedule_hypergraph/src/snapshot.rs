@@ -0,0 +1,148 @@
+use std::ops::Deref;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use arc_swap::ArcSwap;
+
+use crate::schedule_graph::ScheduleGraph;
+
+/// An immutable version of a `ScheduleGraph` published by a
+/// `SnapshotPublisher`. It derefs to the graph, so every read-only method
+/// of the graph can be called on it.
+#[derive(Debug)]
+pub struct GraphSnapshot
+{
+    version: u64,
+    schedule_graph: ScheduleGraph,
+}
+
+impl GraphSnapshot
+{
+    /// Increases by one with every publish, starting from zero.
+    pub fn version(&self) -> u64
+    {
+        self.version
+    }
+}
+
+impl Deref for GraphSnapshot
+{
+    type Target = ScheduleGraph;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.schedule_graph
+    }
+}
+
+/// Hands consistent snapshots of a graph to many reader threads, e.g.
+/// dashboards, without locking. The writer keeps mutating its own graph and
+/// publishes it after each batch of mutations, which atomically swaps in a
+/// new `GraphSnapshot`. A reader keeps the snapshot that it loaded for as
+/// long as it needs it, later publishes do not change it.
+///
+/// A snapshot is a `ScheduleGraph::fork`, so the nodes and hyperedges are
+/// shared with the writer until it mutates them.
+#[derive(Debug)]
+pub struct SnapshotPublisher
+{
+    snapshot: ArcSwap<GraphSnapshot>,
+    next_version: AtomicU64,
+}
+
+impl SnapshotPublisher
+{
+    /// Publishes the graph as version zero.
+    pub fn new(schedule_graph: &ScheduleGraph) -> Self
+    {
+        Self {
+            snapshot: ArcSwap::from_pointee(GraphSnapshot {
+                version: 0,
+                schedule_graph: schedule_graph.fork(),
+            }),
+            next_version: AtomicU64::new(1),
+        }
+    }
+
+    /// Replaces the published snapshot with the current state of the graph
+    /// and returns its version.
+    pub fn publish(&self, schedule_graph: &ScheduleGraph) -> u64
+    {
+        let version = self.next_version.fetch_add(1, Ordering::Relaxed);
+        self.snapshot.store(Arc::new(GraphSnapshot {
+            version,
+            schedule_graph: schedule_graph.fork(),
+        }));
+        version
+    }
+
+    /// The latest published snapshot.
+    pub fn snapshot(&self) -> Arc<GraphSnapshot>
+    {
+        self.snapshot.load_full()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::SnapshotPublisher;
+    use crate::schedule_graph::ScheduleGraph;
+
+    #[test]
+    fn test_snapshot_publisher()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+
+        let snapshot_publisher = Arc::new(SnapshotPublisher::new(&schedule_graph));
+        let first_snapshot = snapshot_publisher.snapshot();
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let snapshot_publisher = Arc::clone(&snapshot_publisher);
+                scope.spawn(move || {
+                    let mut previous_version = 0;
+                    for _ in 0..100 {
+                        let snapshot = snapshot_publisher.snapshot();
+                        assert!(snapshot.version() >= previous_version);
+                        // Every batch adds one work order, so a consistent
+                        // snapshot has as many work orders as its version.
+                        assert_eq!(snapshot.instance_report().work_order_count() as u64, snapshot.version());
+                        previous_version = snapshot.version();
+                    }
+                });
+            }
+
+            for work_order_number in (1122334401..=1122334410).map(|work_order_number| WorkOrderNumber::new(work_order_number).unwrap()) {
+                let work_order = WorkOrder::new(
+                    work_order_number,
+                    start_date,
+                    vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+                )
+                .unwrap();
+                schedule_graph.add_work_order(&work_order).unwrap();
+                snapshot_publisher.publish(&schedule_graph);
+            }
+        });
+
+        assert_eq!(snapshot_publisher.snapshot().version(), 10);
+        assert_eq!(snapshot_publisher.snapshot().instance_report().work_order_count(), 10);
+        assert_eq!(first_snapshot.version(), 0);
+        assert_eq!(first_snapshot.instance_report().work_order_count(), 0);
+    }
+}
@@ -0,0 +1,267 @@
+use chrono::NaiveDateTime;
+
+use crate::events::GraphEntity;
+use crate::schedule_graph::AssignmentId;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// When a node or hyperedge was created and last updated, taken from the
+/// `SystemClock` of the graph.
+///
+/// A node is updated whenever a hyperedge is added to or removed from it,
+/// and a hyperedge when it is removed or annotated. Undoing a mutation is
+/// an update as well, so `updated_at` is never moved back in time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timestamps
+{
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl Timestamps
+{
+    pub(crate) fn new(now: NaiveDateTime) -> Self
+    {
+        Self {
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Public API for the creation and update times of the graph.
+impl ScheduleGraph
+{
+    pub fn timestamps(&self, graph_entity: &GraphEntity) -> Result<Timestamps, ScheduleGraphErrors>
+    {
+        let node_index = self.entity_node_index(graph_entity)?;
+        Ok(self.node_timestamps()[node_index])
+    }
+
+    pub fn assignment_timestamps(&self, assignment_id: AssignmentId) -> Result<Timestamps, ScheduleGraphErrors>
+    {
+        let edge_index = self.assignment_edge_index(assignment_id)?;
+        Ok(self.edge_timestamps()[edge_index])
+    }
+
+    /// The assignments that have not been updated since the cutoff, ordered
+    /// by `AssignmentId`. These are the assignments that planners should
+    /// confirm again.
+    pub fn assignments_updated_before(&self, cutoff: NaiveDateTime) -> Vec<AssignmentId>
+    {
+        let mut assignment_ids = self
+            .edges()
+            .filter(|(edge_index, _)| self.edge_timestamps()[*edge_index].updated_at < cutoff)
+            .filter_map(|(edge_index, _)| self.assignment_view(edge_index))
+            .map(|assignment| assignment.assignment_id)
+            .collect::<Vec<_>>();
+        assignment_ids.sort();
+        assignment_ids
+    }
+
+    pub(crate) fn entity_node_index(&self, graph_entity: &GraphEntity) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        match graph_entity {
+            GraphEntity::Technician(technician_id) => self.technician_node_index(*technician_id),
+            GraphEntity::WorkOrder(work_order_number) => self.work_order_node_index(*work_order_number),
+            GraphEntity::Activity(work_order_number, activity_number) => self.activity_node_index(*work_order_number, *activity_number),
+            GraphEntity::Period(period) => self.period_node_index(*period),
+            GraphEntity::Skill(skill) => self.skill_node_index(*skill),
+            GraphEntity::Day(day) => self.day_node_index(*day),
+            GraphEntity::WorkPackage(work_package_id) => self.work_package_node_index(*work_package_id),
+            GraphEntity::Crew(crew_id) => self.crew_node_index(*crew_id),
+            GraphEntity::FunctionalLocation(functional_location_id) => self.functional_location_node_index(functional_location_id),
+            GraphEntity::Equipment(equipment_id) => self.equipment_node_index(*equipment_id),
+            GraphEntity::Material(material_id) => self.material_node_index(*material_id),
+            GraphEntity::ContractorPool(contractor_pool_id) => self.contractor_pool_node_index(*contractor_pool_id),
+            GraphEntity::ShutdownWindow(shutdown_window_id) => self.shutdown_window_node_index(*shutdown_window_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveDateTime;
+    use chrono::NaiveTime;
+    use scheduling_environment::FixedClock;
+    use scheduling_environment::Period;
+    use scheduling_environment::SystemClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::claims::ClaimRegistry;
+    use crate::events::GraphEntity;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_assignments_updated_before()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let created_at = start_date.and_hms_opt(6, 0, 0).unwrap();
+        schedule_graph.set_system_clock(FixedClock(created_at));
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = start_date.and_hms_opt(7, 0, 0).unwrap();
+        let end = (start_date + Duration::days(13)).and_hms_opt(19, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        let old_assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date],
+                shift,
+            )
+            .unwrap();
+
+        let updated_at = created_at + Duration::days(30);
+        schedule_graph.set_system_clock(FixedClock(updated_at));
+        let new_assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date + Duration::days(1)],
+                shift,
+            )
+            .unwrap();
+
+        assert_eq!(
+            schedule_graph
+                .timestamps(&GraphEntity::WorkOrder(WorkOrderNumber::new(1122334455).unwrap()))
+                .unwrap()
+                .created_at,
+            created_at
+        );
+        let technician_timestamps = schedule_graph.timestamps(&GraphEntity::Technician(TechnicianId::new(1001))).unwrap();
+        assert_eq!(
+            (technician_timestamps.created_at, technician_timestamps.updated_at),
+            (created_at, updated_at)
+        );
+        assert_eq!(
+            schedule_graph.timestamps(&GraphEntity::Technician(TechnicianId::new(1002))),
+            Err(ScheduleGraphErrors::WorkerMissing {
+                technician_id: TechnicianId::new(1002),
+            })
+        );
+        assert_eq!(schedule_graph.assignment_timestamps(old_assignment_id).unwrap().updated_at, created_at);
+        assert_eq!(
+            schedule_graph.assignments_updated_before(updated_at - Duration::days(1)),
+            vec![old_assignment_id]
+        );
+
+        schedule_graph.remove_assignment(old_assignment_id).unwrap();
+        assert_eq!(
+            schedule_graph.assignment_timestamps(old_assignment_id),
+            Err(ScheduleGraphErrors::AssignmentMissing {
+                assignment_id: old_assignment_id,
+            })
+        );
+        assert!(schedule_graph.assignments_updated_before(updated_at).is_empty());
+        assert_eq!(
+            schedule_graph.assignments_updated_before(updated_at + Duration::seconds(1)),
+            vec![new_assignment_id]
+        );
+    }
+
+    /// A clock that only moves when the simulation advances it.
+    #[derive(Debug)]
+    struct SimulationClock(Mutex<NaiveDateTime>);
+
+    impl SimulationClock
+    {
+        fn advance(&self, time_delta: Duration)
+        {
+            *self.0.lock().unwrap() += time_delta;
+        }
+    }
+
+    impl SystemClock for SimulationClock
+    {
+        fn now(&self) -> NaiveDateTime
+        {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[test]
+    fn test_simulation_clock()
+    {
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let started_at = start_date.and_hms_opt(6, 0, 0).unwrap();
+        let simulation_clock = Arc::new(SimulationClock(Mutex::new(started_at)));
+
+        let mut schedule_graph = ScheduleGraph::new();
+        schedule_graph.set_system_clock(Arc::clone(&simulation_clock));
+        let mut claim_registry = ClaimRegistry::new();
+        claim_registry.set_system_clock(Arc::clone(&simulation_clock));
+
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(start_date)).unwrap();
+        let start = start_date.and_hms_opt(7, 0, 0).unwrap();
+        let end = start_date.and_hms_opt(19, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        let claim_id = claim_registry
+            .claim("maintenance", TechnicianId::new(1001), vec![(start, end)], Duration::hours(1))
+            .unwrap();
+
+        // Both the graph and the registry follow the simulated time.
+        simulation_clock.advance(Duration::hours(2));
+        assert_eq!(schedule_graph.system_clock().now(), started_at + Duration::hours(2));
+        assert_eq!(
+            schedule_graph
+                .timestamps(&GraphEntity::Technician(TechnicianId::new(1001)))
+                .unwrap()
+                .created_at,
+            started_at
+        );
+        assert_eq!(claim_registry.claims().count(), 0);
+        assert_eq!(
+            claim_registry.renew(claim_id, Duration::hours(1)),
+            Err(ScheduleGraphErrors::ClaimMissing { claim_id })
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::schedule_graph::ScheduleGraph;
+
+/// Public API to apply several mutations as a single unit.
+impl ScheduleGraph
+{
+    /// Runs the mutations in `transaction` on the graph. If any of them
+    /// returns an error the graph is rolled back to the state it had before
+    /// the transaction started.
+    ///
+    /// The rollback restores a checkpoint of the whole graph. The checkpoint
+    /// shares the nodes and hyperedges with the graph, but the first mutation
+    /// inside the transaction copies them. Prefer a single transaction around
+    /// a batch of mutations over one transaction per mutation.
+    pub fn transaction<T, E>(&mut self, transaction: impl FnOnce(&mut ScheduleGraph) -> Result<T, E>) -> Result<T, E>
+    {
+        let checkpoint = self.clone();
+
+        let result = transaction(self);
+        if result.is_err() {
+            *self = checkpoint;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_transaction_rollback()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+        let other_work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334466).unwrap(),
+            basic_start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap();
+
+        let number_of_nodes = schedule_graph.nodes().len();
+        let number_of_hyperedges = schedule_graph.hyperedges().len();
+
+        let result = schedule_graph.transaction(|transaction| {
+            transaction.add_work_order(&work_order)?;
+            transaction.add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &period)?;
+            transaction.add_work_order(&work_order)
+        });
+
+        assert_eq!(
+            result,
+            Err(ScheduleGraphErrors::WorkOrderDuplicate {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            })
+        );
+        assert_eq!(schedule_graph.nodes().len(), number_of_nodes);
+        assert_eq!(schedule_graph.hyperedges().len(), number_of_hyperedges);
+        assert_eq!(
+            schedule_graph.periods_excluding(WorkOrderNumber::new(1122334455).unwrap()),
+            Err(ScheduleGraphErrors::WorkOrderMissing {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            })
+        );
+
+        schedule_graph
+            .transaction(|transaction| {
+                transaction.add_work_order(&work_order)?;
+                transaction.add_work_order(&other_work_order)
+            })
+            .unwrap();
+
+        assert!(
+            schedule_graph
+                .technicians_for_work_order(WorkOrderNumber::new(1122334455).unwrap())
+                .is_ok()
+        );
+        assert!(
+            schedule_graph
+                .technicians_for_work_order(WorkOrderNumber::new(1122334466).unwrap())
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// Why the batch scheduler left a work order in the backlog.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnscheduledReason
+{
+    /// The work order is excluded from, or locked out of, every period of
+    /// the horizon.
+    Excluded,
+    /// The remaining capacity of the skill is too low for the work in the
+    /// periods. Every period that the work order may go in is listed under
+    /// at least one skill.
+    NoSkillCapacity
+    {
+        skill: Skill, periods: Vec<Period>
+    },
+    /// A predecessor, see `ScheduleGraph::add_work_order_dependency`, is in
+    /// the backlog as well.
+    WaitingOnPredecessor(WorkOrderNumber),
+}
+
+/// Public API to keep the outcome of a scheduler run with the graph, so the
+/// backlog can be reviewed without running the scheduler again.
+impl ScheduleGraph
+{
+    /// Replaces the reasons of the previous run. Work orders that are not
+    /// in the map were scheduled by the run.
+    pub fn record_unscheduled_reasons(
+        &mut self,
+        unscheduled_reasons: BTreeMap<WorkOrderNumber, Vec<UnscheduledReason>>,
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        for work_order_number in unscheduled_reasons.keys() {
+            self.work_order_node_index(*work_order_number)?;
+        }
+        *self.unscheduled_reasons_mut() = unscheduled_reasons;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::UnscheduledReason;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_record_unscheduled_reasons()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(basic_start_date);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            schedule_graph
+                .add_work_order(
+                    &WorkOrder::new(
+                        work_order_number,
+                        basic_start_date,
+                        vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+        assert!(schedule_graph.unscheduled_reasons().is_empty());
+
+        let unscheduled_reasons = BTreeMap::from([
+            (
+                WorkOrderNumber::new(1122334455).unwrap(),
+                vec![UnscheduledReason::NoSkillCapacity {
+                    skill: Skill::MTN_MECH,
+                    periods: vec![period],
+                }],
+            ),
+            (
+                WorkOrderNumber::new(1122334466).unwrap(),
+                vec![UnscheduledReason::WaitingOnPredecessor(WorkOrderNumber::new(1122334455).unwrap())],
+            ),
+        ]);
+        schedule_graph.record_unscheduled_reasons(unscheduled_reasons.clone()).unwrap();
+        assert_eq!(schedule_graph.unscheduled_reasons(), &unscheduled_reasons);
+
+        assert_eq!(
+            schedule_graph.record_unscheduled_reasons(BTreeMap::from([(
+                WorkOrderNumber::new(1122334477).unwrap(),
+                vec![UnscheduledReason::Excluded]
+            )])),
+            Err(ScheduleGraphErrors::WorkOrderMissing {
+                work_order_number: WorkOrderNumber::new(1122334477).unwrap(),
+            })
+        );
+        assert_eq!(schedule_graph.unscheduled_reasons(), &unscheduled_reasons);
+
+        // A later run replaces the reasons.
+        schedule_graph
+            .record_unscheduled_reasons(BTreeMap::from([(
+                WorkOrderNumber::new(1122334466).unwrap(),
+                vec![UnscheduledReason::Excluded],
+            )]))
+            .unwrap();
+        assert_eq!(
+            schedule_graph.unscheduled_reasons(),
+            &BTreeMap::from([(WorkOrderNumber::new(1122334466).unwrap(), vec![UnscheduledReason::Excluded])])
+        );
+    }
+}
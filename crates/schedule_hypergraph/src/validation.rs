@@ -0,0 +1,420 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::btree_map::Entry;
+
+use chrono::NaiveDateTime;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::conflicts::ScheduleConflict;
+use crate::dependencies::DependencyViolation;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::HyperEdge;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::PrecedenceViolation;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+
+/// Every invariant breach of a `ScheduleGraph`, see `ScheduleGraph::validate`.
+///
+/// TODO [ ] - Report assignments in locked periods when period locks are
+/// part of the graph.
+#[derive(Debug, Default, PartialEq)]
+pub struct ValidationReport
+{
+    pub dangling_indices: Vec<DanglingIndex>,
+    /// Pairs of hyperedges with the same `EdgeType` and nodes. The first
+    /// `EdgeIndex` is the one that was added first.
+    pub duplicate_edges: Vec<(EdgeIndex, EdgeIndex)>,
+    pub skill_mismatches: Vec<SkillMismatch>,
+    pub availability_violations: Vec<AvailabilityViolation>,
+    pub work_overruns: Vec<WorkOverrun>,
+    pub precedence_violations: BTreeMap<WorkOrderNumber, Vec<PrecedenceViolation>>,
+    pub dependency_violations: Vec<DependencyViolation>,
+    /// Double bookings and assignments in excluded periods.
+    pub schedule_conflicts: Vec<ScheduleConflict>,
+}
+
+/// An index that does not point to a live node or hyperedge.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DanglingIndex
+{
+    /// The hyperedge contains a `NodeIndex` that is not in the graph.
+    EdgeNode
+    {
+        edge_index: EdgeIndex, node_index: NodeIndex
+    },
+    /// The incidence list of the node contains an `EdgeIndex` that is not in
+    /// the graph, is removed, or does not contain the node.
+    IncidenceEdge
+    {
+        node_index: NodeIndex, edge_index: EdgeIndex
+    },
+}
+
+/// A technician assigned to an activity requiring a skill that the
+/// technician does not have.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkillMismatch
+{
+    pub technician_id: TechnicianId,
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub skill: Skill,
+}
+
+/// A shift of an activity assignment that is not covered by an
+/// `Availability` of the technician.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AvailabilityViolation
+{
+    pub technician_id: TechnicianId,
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub start: NaiveDateTime,
+    pub finish: NaiveDateTime,
+}
+
+/// An activity whose assigned shifts and contracted hours add up to more
+/// hours than its planned work.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkOverrun
+{
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub planned_work: Work,
+    pub assigned_work: Work,
+}
+
+impl ValidationReport
+{
+    pub fn is_valid(&self) -> bool
+    {
+        self.dangling_indices.is_empty()
+            && self.duplicate_edges.is_empty()
+            && self.skill_mismatches.is_empty()
+            && self.availability_violations.is_empty()
+            && self.work_overruns.is_empty()
+            && self.precedence_violations.is_empty()
+            && self.dependency_violations.is_empty()
+            && self.schedule_conflicts.is_empty()
+    }
+}
+
+/// Public API to validate the whole graph in one call, e.g. to gate
+/// generated schedules in CI.
+impl ScheduleGraph
+{
+    pub fn validate(&self) -> ValidationReport
+    {
+        let mut validation_report = ValidationReport {
+            dangling_indices: self.dangling_indices(),
+            ..ValidationReport::default()
+        };
+
+        // The remaining checks index the nodes through the hyperedges.
+        if !validation_report.dangling_indices.is_empty() {
+            return validation_report;
+        }
+
+        let mut seen_hyperedges = BTreeMap::<&HyperEdge, EdgeIndex>::new();
+        let mut assigned_work = BTreeMap::<NodeIndex, Work>::new();
+        for (edge_index, hyperedge) in self.edges() {
+            match seen_hyperedges.entry(hyperedge) {
+                Entry::Occupied(first_edge_index) => validation_report.duplicate_edges.push((*first_edge_index.get(), edge_index)),
+                Entry::Vacant(vacant_entry) => {
+                    vacant_entry.insert(edge_index);
+                }
+            }
+
+            let EdgeType::Assign(Some(shift)) = hyperedge.edge_type() else {
+                continue;
+            };
+            let Node::Activity(activity) = &self.nodes()[hyperedge.nodes()[0]] else {
+                continue;
+            };
+            let required_skills = self.node_skills(hyperedge.nodes()[0], |edge_type| matches!(edge_type, EdgeType::Requires));
+            let days = hyperedge
+                .nodes()
+                .iter()
+                .filter_map(|node_index| match &self.nodes()[*node_index] {
+                    Node::Day(day) => Some(*day),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let technician_count = hyperedge
+                .nodes()
+                .iter()
+                .filter(|node_index| matches!(self.nodes()[**node_index], Node::Technician(_)))
+                .count();
+            *assigned_work.entry(hyperedge.nodes()[0]).or_default() += Work::from_duration(shift.duration()) * (days.len() * technician_count) as u64;
+
+            for technician_node_index in hyperedge.nodes() {
+                let Node::Technician(technician_id) = self.nodes()[*technician_node_index] else {
+                    continue;
+                };
+
+                let technician_skills = self.node_skills(*technician_node_index, |edge_type| {
+                    matches!(edge_type, EdgeType::Available(_) | EdgeType::HasSkill(_))
+                });
+                let mismatched_skills = days
+                    .iter()
+                    .flat_map(|day| {
+                        let technician_skills = self.skills_on(*technician_node_index, technician_skills.clone(), *day);
+                        required_skills.difference(&technician_skills).copied().collect::<Vec<_>>()
+                    })
+                    .collect::<BTreeSet<_>>();
+                for skill in mismatched_skills {
+                    validation_report.skill_mismatches.push(SkillMismatch {
+                        technician_id,
+                        work_order_number: activity.work_order_number(),
+                        activity_number: activity.activity_number(),
+                        skill,
+                    });
+                }
+
+                for day in &days {
+                    if let Err(ScheduleGraphErrors::TechnicianUnavailable { start, finish, .. }) =
+                        self.check_shift_availability(technician_id, *technician_node_index, &[*day], shift)
+                    {
+                        validation_report.availability_violations.push(AvailabilityViolation {
+                            technician_id,
+                            work_order_number: activity.work_order_number(),
+                            activity_number: activity.activity_number(),
+                            start,
+                            finish,
+                        });
+                    }
+                }
+            }
+        }
+        validation_report.skill_mismatches.sort();
+        validation_report.availability_violations.sort();
+
+        for node_index in 0..self.nodes().len() {
+            let contracted_hours = self.node_contracted_hours(node_index);
+            if contracted_hours > Work::ZERO {
+                *assigned_work.entry(node_index).or_default() += contracted_hours;
+            }
+        }
+        for (activity_node_index, assigned_work) in assigned_work {
+            if let Node::Activity(activity) = &self.nodes()[activity_node_index]
+                && let Some(planned_work) = activity.work()
+                && assigned_work > planned_work
+            {
+                validation_report.work_overruns.push(WorkOverrun {
+                    work_order_number: activity.work_order_number(),
+                    activity_number: activity.activity_number(),
+                    planned_work,
+                    assigned_work,
+                });
+            }
+        }
+
+        for node in self.nodes() {
+            if let Node::WorkOrder(work_order_number) = node
+                && let Err(precedence_violations) = self.validate_precedence(*work_order_number)
+            {
+                validation_report.precedence_violations.insert(*work_order_number, precedence_violations);
+            }
+        }
+
+        validation_report.dependency_violations = self.validate_dependencies();
+        validation_report.schedule_conflicts = self.detect_conflicts();
+        validation_report
+    }
+
+    fn dangling_indices(&self) -> Vec<DanglingIndex>
+    {
+        let mut dangling_indices = vec![];
+
+        for (edge_index, hyperedge) in self.edges() {
+            for node_index in hyperedge.nodes() {
+                if *node_index >= self.nodes().len() {
+                    dangling_indices.push(DanglingIndex::EdgeNode {
+                        edge_index,
+                        node_index: *node_index,
+                    });
+                }
+            }
+        }
+
+        for (node_index, incident_edges) in self.incidence_list().iter().enumerate() {
+            for edge_index in incident_edges {
+                let live_and_incident = *edge_index < self.hyperedges().len()
+                    && !self.tombstones().contains(edge_index)
+                    && self.hyperedges()[*edge_index].nodes().contains(&node_index);
+                if !live_and_incident {
+                    dangling_indices.push(DanglingIndex::IncidenceEdge {
+                        node_index,
+                        edge_index: *edge_index,
+                    });
+                }
+            }
+        }
+
+        dangling_indices
+    }
+
+    /// The skills in the hyperedges of the node whose `EdgeType` satisfies
+    /// the predicate.
+    pub(crate) fn node_skills(&self, node_index: NodeIndex, predicate: impl Fn(&EdgeType) -> bool) -> BTreeSet<Skill>
+    {
+        self.incidence_list()[node_index]
+            .iter()
+            .map(|edge_index| &self.hyperedges()[*edge_index])
+            .filter(|hyperedge| predicate(hyperedge.edge_type()))
+            .flat_map(|hyperedge| hyperedge.nodes())
+            .filter_map(|node_index| match &self.nodes()[*node_index] {
+                Node::Skill(skill) => Some(*skill),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Work;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::AvailabilityViolation;
+    use super::SkillMismatch;
+    use super::WorkOverrun;
+    use crate::conflicts::ScheduleConflict;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_validate()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(monday);
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            monday,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH).with_work(Work::from_hours(4.0)),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_ELEC).with_work(Work::from_hours(4.0)),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let start = monday.and_hms_opt(8, 0, 0).unwrap();
+        let end = monday.and_hms_opt(16, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let shift = |start: u32, finish: u32| {
+            Shift::new(
+                NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(finish, 0, 0).unwrap(),
+                false,
+            )
+            .unwrap()
+        };
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![monday],
+                shift(8, 12),
+            )
+            .unwrap();
+        assert!(schedule_graph.validate().is_valid());
+
+        // Electrical work after the availability ends, before the mechanical
+        // work is finished, in an excluded period.
+        let assignment_id = schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(20),
+                vec![monday],
+                shift(11, 17),
+            )
+            .unwrap();
+        let assignment_edge_index = schedule_graph.assignment_edge_index(assignment_id).unwrap();
+        schedule_graph.add_exclusion(&WorkOrderNumber::new(1122334455).unwrap(), &period).unwrap();
+        let nodes = schedule_graph.hyperedges()[assignment_edge_index].nodes().to_vec();
+        let duplicate_edge_index = schedule_graph.add_edge(EdgeType::Assign(Some(shift(11, 17))), nodes);
+
+        let validation_report = schedule_graph.validate();
+
+        assert!(!validation_report.is_valid());
+        assert_eq!(validation_report.dangling_indices, vec![]);
+        assert_eq!(validation_report.duplicate_edges, vec![(assignment_edge_index, duplicate_edge_index)]);
+        assert_eq!(
+            validation_report.skill_mismatches,
+            vec![
+                SkillMismatch {
+                    technician_id: TechnicianId::new(1001),
+                    work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                    activity_number: ActivityNumber::new(20),
+                    skill: Skill::MTN_ELEC,
+                };
+                2
+            ]
+        );
+        assert_eq!(
+            validation_report.availability_violations[0],
+            AvailabilityViolation {
+                technician_id: TechnicianId::new(1001),
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                activity_number: ActivityNumber::new(20),
+                start: monday.and_hms_opt(11, 0, 0).unwrap(),
+                finish: monday.and_hms_opt(17, 0, 0).unwrap(),
+            }
+        );
+        assert_eq!(
+            validation_report.work_overruns,
+            vec![WorkOverrun {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                activity_number: ActivityNumber::new(20),
+                planned_work: Work::from_hours(4.0),
+                assigned_work: Work::from_hours(12.0),
+            }]
+        );
+        assert_eq!(
+            validation_report.precedence_violations[&WorkOrderNumber::new(1122334455).unwrap()].len(),
+            1
+        );
+        assert!(validation_report.schedule_conflicts.contains(&ScheduleConflict::ExcludedPeriod {
+            work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            activity_number: Some(ActivityNumber::new(20)),
+            period,
+        }));
+    }
+}
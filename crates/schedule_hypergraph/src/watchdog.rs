@@ -0,0 +1,174 @@
+use std::mem::size_of;
+use std::mem::size_of_val;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+
+/// Sizes of the graph above which `tracing` warnings are emitted. A
+/// warning is emitted once, when the size crosses the threshold.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GrowthThresholds
+{
+    pub max_nodes: Option<usize>,
+    /// Hyperedges including the tombstoned ones.
+    pub max_hyperedges: Option<usize>,
+    /// Hyperedges incident to a single node.
+    pub max_incidence_list_len: Option<usize>,
+}
+
+/// Estimated heap usage of the graph in bytes. The estimates are based on
+/// the capacity of the collections and ignore the allocator overhead.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryReport
+{
+    pub nodes: usize,
+    pub hyperedges: usize,
+    pub incidence_list: usize,
+    /// The index maps from the domain ids to the `NodeIndex`s.
+    pub indices: usize,
+    pub tombstones: usize,
+    /// Zero unless the `MutationJournal` is enabled.
+    pub journal: usize,
+}
+
+impl MemoryReport
+{
+    pub fn total(&self) -> usize
+    {
+        self.nodes + self.hyperedges + self.incidence_list + self.indices + self.tombstones + self.journal
+    }
+}
+
+/// Public API to monitor the size of the graph.
+impl ScheduleGraph
+{
+    /// Opt-in warnings when the graph grows beyond the `GrowthThresholds`.
+    pub fn enable_growth_watchdog(&mut self, growth_thresholds: GrowthThresholds)
+    {
+        *self.growth_thresholds_mut() = Some(growth_thresholds);
+    }
+
+    pub fn disable_growth_watchdog(&mut self)
+    {
+        *self.growth_thresholds_mut() = None;
+    }
+
+    pub fn memory_report(&self) -> MemoryReport
+    {
+        let hyperedge_nodes = self.hyperedges().iter().map(|hyperedge| hyperedge.nodes().len()).sum::<usize>();
+        let incident_edges = self.incidence_list().iter().map(Vec::capacity).sum::<usize>();
+
+        MemoryReport {
+            nodes: size_of_val(self.nodes()),
+            hyperedges: size_of_val(self.hyperedges()) + hyperedge_nodes * size_of::<NodeIndex>(),
+            incidence_list: size_of_val(self.incidence_list()) + incident_edges * size_of::<EdgeIndex>(),
+            indices: self.index_bytes(),
+            tombstones: self.tombstones().len() * size_of::<EdgeIndex>(),
+            journal: self
+                .journal()
+                .map_or(0, |journal| journal.mutation_count() * size_of::<crate::journal::Mutation>()),
+        }
+    }
+
+    pub(crate) fn check_node_growth(&self)
+    {
+        let Some(growth_thresholds) = self.growth_thresholds() else {
+            return;
+        };
+        if growth_thresholds.max_nodes.is_some_and(|max_nodes| self.nodes().len() == max_nodes + 1) {
+            tracing::warn!(
+                nodes = self.nodes().len(),
+                estimated_bytes = self.memory_report().total(),
+                "ScheduleGraph crossed the node threshold"
+            );
+        }
+    }
+
+    pub(crate) fn check_edge_growth(&self, edge_index: EdgeIndex)
+    {
+        let Some(growth_thresholds) = self.growth_thresholds() else {
+            return;
+        };
+        if growth_thresholds
+            .max_hyperedges
+            .is_some_and(|max_hyperedges| self.hyperedges().len() == max_hyperedges + 1)
+        {
+            tracing::warn!(
+                hyperedges = self.hyperedges().len(),
+                estimated_bytes = self.memory_report().total(),
+                "ScheduleGraph crossed the hyperedge threshold"
+            );
+        }
+
+        let Some(max_incidence_list_len) = growth_thresholds.max_incidence_list_len else {
+            return;
+        };
+        for &node_index in self.hyperedges()[edge_index].nodes() {
+            if self.incidence_list()[node_index].len() == max_incidence_list_len + 1 {
+                tracing::warn!(
+                    node = ?self.nodes()[node_index],
+                    incident_edges = max_incidence_list_len + 1,
+                    "ScheduleGraph node crossed the incidence list threshold"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::GrowthThresholds;
+    use crate::schedule_graph::ScheduleGraph;
+
+    #[test]
+    fn test_memory_report()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+        assert_eq!(schedule_graph.memory_report().total(), 0);
+
+        schedule_graph.enable_growth_watchdog(GrowthThresholds {
+            max_nodes: Some(10),
+            max_hyperedges: Some(2),
+            max_incidence_list_len: Some(1),
+        });
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+        let memory_report = schedule_graph.memory_report();
+
+        let work_order = WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            basic_start_date,
+            vec![
+                Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH),
+                Activity::new(ActivityNumber::new(20), 1, Skill::MTN_MECH),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let grown_memory_report = schedule_graph.memory_report();
+        assert!(grown_memory_report.nodes > memory_report.nodes);
+        assert!(grown_memory_report.hyperedges > memory_report.hyperedges);
+        assert!(grown_memory_report.indices > memory_report.indices);
+        assert_eq!(grown_memory_report.journal, 0);
+
+        schedule_graph.enable_journal();
+        schedule_graph
+            .add_period(Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap()))
+            .unwrap();
+        assert!(schedule_graph.memory_report().journal > 0);
+        assert!(schedule_graph.memory_report().total() > grown_memory_report.total());
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::BTreeMap;
+
+use chrono::Days;
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Priority;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::HyperEdge;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// A work order that is scheduled to finish after its due date.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Tardiness
+{
+    pub work_order_number: WorkOrderNumber,
+    pub due_date: NaiveDate,
+    /// The last scheduled day of the work order.
+    pub scheduled_finish: NaiveDate,
+}
+
+/// Public API for the priority, revision, due date and commitment of work
+/// orders.
+impl ScheduleGraph
+{
+    pub fn work_order_priority(&self, work_order_number: WorkOrderNumber) -> Result<Priority, ScheduleGraphErrors>
+    {
+        Ok(self
+            .work_order_metadata_edges(work_order_number)?
+            .find_map(|hyperedge| match hyperedge.edge_type() {
+                EdgeType::Priority(priority) => Some(*priority),
+                _ => None,
+            })
+            .unwrap_or_default())
+    }
+
+    pub fn work_order_revision(&self, work_order_number: WorkOrderNumber) -> Result<Option<&str>, ScheduleGraphErrors>
+    {
+        Ok(self
+            .work_order_metadata_edges(work_order_number)?
+            .find_map(|hyperedge| match hyperedge.edge_type() {
+                EdgeType::Revision(revision) => Some(revision.as_str()),
+                _ => None,
+            }))
+    }
+
+    pub fn work_order_due_date(&self, work_order_number: WorkOrderNumber) -> Result<Option<NaiveDate>, ScheduleGraphErrors>
+    {
+        Ok(self
+            .work_order_metadata_edges(work_order_number)?
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Due))
+            .find_map(|hyperedge| match self.nodes()[hyperedge.nodes()[1]] {
+                Node::Day(due_date) => Some(due_date),
+                _ => None,
+            }))
+    }
+
+    /// The day that the work order can start at the earliest.
+    pub fn work_order_basic_start(&self, work_order_number: WorkOrderNumber) -> Result<NaiveDate, ScheduleGraphErrors>
+    {
+        Ok(self
+            .work_order_metadata_edges(work_order_number)?
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::BasicStart))
+            .find_map(|hyperedge| match self.nodes()[hyperedge.nodes()[1]] {
+                Node::Day(basic_start_date) => Some(basic_start_date),
+                _ => None,
+            })
+            .expect("every work order has a basic start"))
+    }
+
+    pub fn work_order_commitment(&self, work_order_number: WorkOrderNumber) -> Result<Commitment, ScheduleGraphErrors>
+    {
+        let forecast = self
+            .work_order_metadata_edges(work_order_number)?
+            .any(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Forecast));
+        Ok(if forecast { Commitment::Forecast } else { Commitment::Firm })
+    }
+
+    /// Every work order of the graph, ordered by `WorkOrderNumber`.
+    pub fn work_order_numbers(&self) -> Vec<WorkOrderNumber>
+    {
+        let mut work_order_numbers = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::WorkOrder(work_order_number) => Some(*work_order_number),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        work_order_numbers.sort();
+        work_order_numbers
+    }
+
+    /// The work orders that are due before the period starts, ordered by
+    /// `WorkOrderNumber`. These are late if they are scheduled in the
+    /// period or after it.
+    pub fn work_orders_due_before(&self, period: Period) -> Vec<WorkOrderNumber>
+    {
+        let mut work_order_numbers = self
+            .edges()
+            .filter(|(_, hyperedge)| matches!(hyperedge.edge_type(), EdgeType::Due))
+            .filter_map(
+                |(_, hyperedge)| match (&self.nodes()[hyperedge.nodes()[0]], &self.nodes()[hyperedge.nodes()[1]]) {
+                    (Node::WorkOrder(work_order_number), Node::Day(due_date)) if *due_date < period.start_date() => Some(*work_order_number),
+                    _ => None,
+                },
+            )
+            .collect::<Vec<_>>();
+        work_order_numbers.sort();
+        work_order_numbers
+    }
+
+    /// The work orders whose last scheduled day is after their due date,
+    /// ordered by `WorkOrderNumber`. A work order assignment counts until
+    /// the last day of its period, an activity assignment until its last
+    /// day.
+    pub fn tardy_work_orders(&self) -> Vec<Tardiness>
+    {
+        let mut scheduled_finishes = BTreeMap::<WorkOrderNumber, NaiveDate>::new();
+        for assignment in self.edges().filter_map(|(edge_index, _)| self.assignment_view(edge_index)) {
+            let finish = assignment
+                .days
+                .iter()
+                .max()
+                .copied()
+                .or(assignment.period.map(|period| period.end_date() - Days::new(1)));
+            if let Some(finish) = finish {
+                scheduled_finishes
+                    .entry(assignment.work_order)
+                    .and_modify(|scheduled_finish| *scheduled_finish = (*scheduled_finish).max(finish))
+                    .or_insert(finish);
+            }
+        }
+
+        scheduled_finishes
+            .into_iter()
+            .filter_map(|(work_order_number, scheduled_finish)| {
+                let due_date = self.work_order_due_date(work_order_number).ok()??;
+                (due_date < scheduled_finish).then_some(Tardiness {
+                    work_order_number,
+                    due_date,
+                    scheduled_finish,
+                })
+            })
+            .collect()
+    }
+
+    /// The hyperedges starting in the work order.
+    fn work_order_metadata_edges(&self, work_order_number: WorkOrderNumber) -> Result<impl Iterator<Item = &HyperEdge>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = self.work_order_node_index(work_order_number)?;
+
+        Ok(self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(move |hyperedge| hyperedge.nodes()[0] == work_order_node_index))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::Commitment;
+    use scheduling_environment::work_order::Priority;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::Tardiness;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_tardy_work_orders()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let period = Period::from_start_date(start_date);
+        let next_period = Period::from_start_date(period.end_date());
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+
+        let day_3 = start_date + Duration::days(3);
+        let work_orders = [
+            WorkOrder::new(
+                WorkOrderNumber::new(1122334455).unwrap(),
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap()
+            .with_priority(Priority::VeryHigh)
+            .with_revision("TA-2025")
+            .with_due_date(day_3),
+            WorkOrder::new(
+                WorkOrderNumber::new(1122334466).unwrap(),
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap()
+            .with_due_date(period.end_date() + Duration::days(3)),
+            WorkOrder::new(
+                WorkOrderNumber::new(1122334477).unwrap(),
+                start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap()
+            .with_commitment(Commitment::Forecast),
+        ];
+        for work_order in &work_orders {
+            schedule_graph.add_work_order(work_order).unwrap();
+        }
+        let beyond_horizon = WorkOrder::new(
+            WorkOrderNumber::new(1122334488).unwrap(),
+            start_date,
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap()
+        .with_due_date(next_period.end_date());
+        assert_eq!(
+            schedule_graph.add_work_order(&beyond_horizon),
+            Err(ScheduleGraphErrors::DayMissing {
+                day: next_period.end_date(),
+                horizon: Some((start_date, next_period.end_date() - Duration::days(1))),
+            })
+        );
+
+        assert_eq!(
+            schedule_graph.work_order_priority(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(Priority::VeryHigh)
+        );
+        assert_eq!(
+            schedule_graph.work_order_priority(WorkOrderNumber::new(1122334466).unwrap()),
+            Ok(Priority::Medium)
+        );
+        assert_eq!(
+            schedule_graph.work_order_revision(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(Some("TA-2025"))
+        );
+        assert_eq!(schedule_graph.work_order_revision(WorkOrderNumber::new(1122334477).unwrap()), Ok(None));
+        assert_eq!(
+            schedule_graph.work_order_due_date(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(Some(day_3))
+        );
+        assert_eq!(
+            schedule_graph.work_order_commitment(WorkOrderNumber::new(1122334455).unwrap()),
+            Ok(Commitment::Firm)
+        );
+        assert_eq!(
+            schedule_graph.work_order_commitment(WorkOrderNumber::new(1122334477).unwrap()),
+            Ok(Commitment::Forecast)
+        );
+        assert_eq!(
+            schedule_graph.work_order_due_date(WorkOrderNumber::new(1122334488).unwrap()),
+            Err(ScheduleGraphErrors::WorkOrderMissing {
+                work_order_number: WorkOrderNumber::new(1122334488).unwrap(),
+            })
+        );
+        assert!(schedule_graph.work_orders_due_before(period).is_empty());
+        assert_eq!(
+            schedule_graph.work_orders_due_before(next_period),
+            vec![WorkOrderNumber::new(1122334455).unwrap()]
+        );
+
+        let start = start_date.and_hms_opt(7, 0, 0).unwrap();
+        let end = (start_date + Duration::days(27)).and_hms_opt(19, 0, 0).unwrap();
+        let technician = Technician::builder(TechnicianId::new(1001))
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![start_date, day_3 + Duration::days(1)],
+                shift,
+            )
+            .unwrap();
+        // The work order assignment lasts until the last day of the period,
+        // which is before the due date.
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334466).unwrap(), period)
+            .unwrap();
+        schedule_graph
+            .add_assignment_work_order(TechnicianId::new(1001), WorkOrderNumber::new(1122334477).unwrap(), next_period)
+            .unwrap();
+
+        assert_eq!(
+            schedule_graph.tardy_work_orders(),
+            vec![Tardiness {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                due_date: day_3,
+                scheduled_finish: day_3 + Duration::days(1),
+            }]
+        );
+        assert!(schedule_graph.validate().is_valid());
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::AssignmentView;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::TechnicianId;
+use crate::schedule_graph::WorkPackageId;
+
+/// A member of a `WorkPackage` that is not co-scheduled with the rest of
+/// the package. The crew and window of the package are the technicians and
+/// days of its first assigned activity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WorkPackageViolation
+{
+    Unassigned(WorkOrderNumber, ActivityNumber),
+    CrewMismatch(WorkOrderNumber, ActivityNumber),
+    WindowMismatch(WorkOrderNumber, ActivityNumber),
+}
+
+/// Everything the field needs to execute a work package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkPackageExport
+{
+    pub work_package_id: WorkPackageId,
+    pub activities: Vec<(WorkOrderNumber, ActivityNumber)>,
+    pub technicians: Vec<TechnicianId>,
+    pub days: Vec<NaiveDate>,
+    pub violations: Vec<WorkPackageViolation>,
+}
+
+/// Public API for work packages. A work package is a set of activities,
+/// possibly from different work orders, that one crew executes together in
+/// one window.
+impl ScheduleGraph
+{
+    pub fn add_work_package(
+        &mut self,
+        work_package_id: WorkPackageId,
+        activities: &[(WorkOrderNumber, ActivityNumber)],
+    ) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.journaled(|graph| {
+            if graph.work_package_node_index(work_package_id).is_ok() {
+                return Err(ScheduleGraphErrors::WorkPackageDuplicate { work_package_id });
+            }
+            let activity_node_indices = activities
+                .iter()
+                .map(|&(work_order_number, activity_number)| graph.activity_node_index(work_order_number, activity_number))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let work_package_node_index = graph.add_node(Node::WorkPackage(work_package_id));
+            graph.add_edge(
+                EdgeType::Contains,
+                std::iter::once(work_package_node_index).chain(activity_node_indices).collect(),
+            );
+            Ok(work_package_node_index)
+        })
+    }
+
+    /// The activities of the work package in the order they were added.
+    pub fn work_package_activities(&self, work_package_id: WorkPackageId) -> Result<Vec<(WorkOrderNumber, ActivityNumber)>, ScheduleGraphErrors>
+    {
+        let work_package_node_index = self.work_package_node_index(work_package_id)?;
+
+        Ok(self.incidence_list()[work_package_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Contains) && hyperedge.nodes()[0] == work_package_node_index)
+            .flat_map(|hyperedge| &hyperedge.nodes()[1..])
+            .filter_map(|&node_index| match &self.nodes()[node_index] {
+                Node::Activity(activity) => Some((activity.work_order_number(), activity.activity_number())),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Every assignment of the activities of the work package.
+    pub fn work_package_assignments(&self, work_package_id: WorkPackageId) -> Result<Vec<AssignmentView>, ScheduleGraphErrors>
+    {
+        let mut assignments = vec![];
+        for (work_order_number, activity_number) in self.work_package_activities(work_package_id)? {
+            assignments.extend(self.package_activity_assignments(work_order_number, activity_number)?);
+        }
+        Ok(assignments)
+    }
+
+    /// Checks that every activity of the work package is assigned to the
+    /// same crew on the same days.
+    pub fn validate_work_package(&self, work_package_id: WorkPackageId) -> Result<Vec<WorkPackageViolation>, ScheduleGraphErrors>
+    {
+        let mut violations = vec![];
+        let mut crew_and_window = None;
+        for (work_order_number, activity_number) in self.work_package_activities(work_package_id)? {
+            let assignments = self.package_activity_assignments(work_order_number, activity_number)?;
+            if assignments.is_empty() {
+                violations.push(WorkPackageViolation::Unassigned(work_order_number, activity_number));
+                continue;
+            }
+
+            let (technicians, days) = crew_and_days(&assignments);
+            let (crew, window) = crew_and_window.get_or_insert_with(|| (technicians.clone(), days.clone()));
+            if technicians != *crew {
+                violations.push(WorkPackageViolation::CrewMismatch(work_order_number, activity_number));
+            }
+            if days != *window {
+                violations.push(WorkPackageViolation::WindowMismatch(work_order_number, activity_number));
+            }
+        }
+        Ok(violations)
+    }
+
+    pub fn export_work_package(&self, work_package_id: WorkPackageId) -> Result<WorkPackageExport, ScheduleGraphErrors>
+    {
+        let (technicians, days) = crew_and_days(&self.work_package_assignments(work_package_id)?);
+
+        Ok(WorkPackageExport {
+            work_package_id,
+            activities: self.work_package_activities(work_package_id)?,
+            technicians: technicians.into_iter().collect(),
+            days: days.into_iter().collect(),
+            violations: self.validate_work_package(work_package_id)?,
+        })
+    }
+
+    fn package_activity_assignments(
+        &self,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<Vec<AssignmentView>, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        Ok(self.incidence_list()[activity_node_index]
+            .iter()
+            .filter_map(|&edge_index| self.assignment_view(edge_index))
+            .collect())
+    }
+}
+
+fn crew_and_days(assignments: &[AssignmentView]) -> (BTreeSet<TechnicianId>, BTreeSet<NaiveDate>)
+{
+    let technicians = assignments.iter().flat_map(|assignment| assignment.technicians.iter().copied()).collect();
+    let days = assignments.iter().flat_map(|assignment| assignment.days.iter().copied()).collect();
+    (technicians, days)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::WorkPackageViolation;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::schedule_graph::Shift;
+    use crate::schedule_graph::TechnicianId;
+
+    #[test]
+    fn test_work_package()
+    {
+        let mut schedule_graph = ScheduleGraph::new();
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        for work_order_number in [WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                basic_start_date,
+                vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let availability_start = basic_start_date.and_hms_opt(0, 0, 0).unwrap();
+        let availability_end = (basic_start_date + Duration::days(4)).and_hms_opt(23, 0, 0).unwrap();
+        for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability_start, availability_end)
+                .unwrap()
+                .add_skill(Skill::MTN_MECH)
+                .build();
+            schedule_graph
+                .add_technician(technician, Availability::new(availability_start, availability_end))
+                .unwrap();
+        }
+
+        schedule_graph
+            .add_work_package(
+                1,
+                &[
+                    (WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+                    (WorkOrderNumber::new(1122334466).unwrap(), ActivityNumber::new(10)),
+                ],
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.add_work_package(1, &[]),
+            Err(ScheduleGraphErrors::WorkPackageDuplicate { work_package_id: 1 })
+        );
+        assert_eq!(
+            schedule_graph.add_work_package(2, &[(WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(20))]),
+            Err(ScheduleGraphErrors::ActivityMissing {
+                work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+                activity_number: ActivityNumber::new(20),
+                activity_numbers: vec![ActivityNumber::new(10)],
+            })
+        );
+        assert_eq!(
+            schedule_graph.work_package_activities(1),
+            Ok(vec![
+                (WorkOrderNumber::new(1122334455).unwrap(), ActivityNumber::new(10)),
+                (WorkOrderNumber::new(1122334466).unwrap(), ActivityNumber::new(10))
+            ])
+        );
+
+        let shift = Shift::new(
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            false,
+        )
+        .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334455).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.validate_work_package(1),
+            Ok(vec![WorkPackageViolation::Unassigned(
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10)
+            )])
+        );
+
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1002)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date + Duration::days(1)],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(
+            schedule_graph.validate_work_package(1),
+            Ok(vec![
+                WorkPackageViolation::CrewMismatch(WorkOrderNumber::new(1122334466).unwrap(), ActivityNumber::new(10)),
+                WorkPackageViolation::WindowMismatch(WorkOrderNumber::new(1122334466).unwrap(), ActivityNumber::new(10))
+            ])
+        );
+
+        schedule_graph
+            .unassign_activity(
+                TechnicianId::new(1002),
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+            )
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![TechnicianId::new(1001)],
+                WorkOrderNumber::new(1122334466).unwrap(),
+                ActivityNumber::new(10),
+                vec![basic_start_date],
+                shift,
+            )
+            .unwrap();
+        assert_eq!(schedule_graph.validate_work_package(1), Ok(vec![]));
+
+        let work_package_export = schedule_graph.export_work_package(1).unwrap();
+        assert_eq!(work_package_export.technicians, vec![TechnicianId::new(1001)]);
+        assert_eq!(work_package_export.days, vec![basic_start_date]);
+        assert_eq!(schedule_graph.work_package_assignments(1).unwrap().len(), 2);
+    }
+}
@@ -1,19 +1,115 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::Days;
+use chrono::Months;
 use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Utc;
 
 pub mod technician;
 pub mod work_order;
 
 #[derive(Hash, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Eq)]
-pub struct Period(NaiveDate);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Period
+{
+    start_date: NaiveDate,
+    period_length: PeriodLength,
+}
+
+#[derive(Hash, Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeriodLength
+{
+    Weekly,
+    #[default]
+    BiWeekly,
+    /// A calendar month, so the number of days depend on the start date.
+    Monthly,
+}
 
 impl Period
 {
+    pub fn new(start_date: NaiveDate, period_length: PeriodLength) -> Self
+    {
+        Self { start_date, period_length }
+    }
+
+    /// A `PeriodLength::BiWeekly` period.
     pub fn from_start_date(start_date: NaiveDate) -> Self
     {
-        Self(start_date)
+        Self::new(start_date, PeriodLength::default())
     }
 
     pub fn start_date(&self) -> NaiveDate
+    {
+        self.start_date
+    }
+
+    pub fn period_length(&self) -> PeriodLength
+    {
+        self.period_length
+    }
+
+    /// The first day after the period.
+    pub fn end_date(&self) -> NaiveDate
+    {
+        match self.period_length {
+            PeriodLength::Weekly => self.start_date + Days::new(7),
+            PeriodLength::BiWeekly => self.start_date + Days::new(14),
+            PeriodLength::Monthly => self.start_date + Months::new(1),
+        }
+    }
+
+    pub fn contains(&self, date: &NaiveDate) -> bool
+    {
+        self.start_date <= *date && *date < self.end_date()
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate>
+    {
+        self.start_date.iter_days().take_while(|date| *date < self.end_date())
+    }
+}
+
+/// The source of the current time. A `FixedClock` makes everything that
+/// depends on the time reproducible, which tests rely on, and a simulation
+/// can plug in a clock of its own that advances with the simulated time.
+/// A clock shared through an `Arc` is a clock as well, so the same clock
+/// can drive several graphs.
+pub trait SystemClock: fmt::Debug + Send + Sync
+{
+    fn now(&self) -> NaiveDateTime;
+}
+
+impl<T: SystemClock + ?Sized> SystemClock for Arc<T>
+{
+    fn now(&self) -> NaiveDateTime
+    {
+        (**self).now()
+    }
+}
+
+/// The current UTC time.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WallClock;
+
+impl SystemClock for WallClock
+{
+    fn now(&self) -> NaiveDateTime
+    {
+        Utc::now().naive_utc()
+    }
+}
+
+/// A clock that is stopped at the time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedClock(pub NaiveDateTime);
+
+impl SystemClock for FixedClock
+{
+    fn now(&self) -> NaiveDateTime
     {
         self.0
     }
@@ -1,13 +1,125 @@
 use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::OnceLock;
 
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
 
+/// The personnel number of a `Technician`.
+#[repr(transparent)]
+#[derive(Hash, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct TechnicianId(usize);
+
+impl TechnicianId
+{
+    pub const fn new(technician_id: usize) -> Self
+    {
+        Self(technician_id)
+    }
+
+    pub fn get(self) -> usize
+    {
+        self.0
+    }
+}
+
+impl FromStr for TechnicianId
+{
+    type Err = ParseIntError;
+
+    fn from_str(technician_id: &str) -> Result<Self, Self::Err>
+    {
+        technician_id.trim().parse().map(Self)
+    }
+}
+
+impl fmt::Display for TechnicianId
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A trade, e.g. mechanical or electrical maintenance. Skills are compared
+/// and ordered by name.
+///
+/// The name is interned, so a `Skill` is `Copy` and each distinct name is
+/// only allocated once for the lifetime of the program.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq, Hash)]
-pub enum Skill
+pub struct Skill(&'static str);
+
+impl Skill
+{
+    pub const MTN_ELEC: Skill = Skill("MtnElec");
+    pub const MTN_MECH: Skill = Skill("MtnMech");
+
+    pub fn new(name: &str) -> Self
+    {
+        static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+        let mut names = NAMES.get_or_init(Mutex::default).lock().expect("the skill names are never poisoned");
+        match names.get(name) {
+            Some(interned_name) => Skill(interned_name),
+            None => {
+                let interned_name: &'static str = Box::leak(name.to_string().into_boxed_str());
+                names.insert(interned_name);
+                Skill(interned_name)
+            }
+        }
+    }
+
+    pub fn name(&self) -> &'static str
+    {
+        self.0
+    }
+}
+
+impl fmt::Display for Skill
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Skill
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Skill
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(Skill::new(&name))
+    }
+}
+
+/// The proficiency of a technician in a `Skill`. Levels are ordered, so a
+/// `Master` is also qualified for work requiring a `Journeyman`.
+#[derive(Hash, Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkillLevel
 {
-    MtnMech,
-    MtnElec,
+    Apprentice,
+    Journeyman,
+    Master,
 }
 
 #[derive(Debug, PartialEq)]
@@ -23,6 +135,7 @@ pub enum TechnicianBuilderError
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Availability(NaiveDateTime, NaiveDateTime);
 
 impl Availability
@@ -78,23 +191,24 @@ impl Ord for Availability
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Technician
 {
-    technician_id: usize,
+    technician_id: TechnicianId,
     availabilities: BTreeSet<Availability>,
     skills: BTreeSet<Skill>,
 }
 
 pub struct TechnicianBuilder
 {
-    technician_id: usize,
+    technician_id: TechnicianId,
     availabilities: BTreeSet<Availability>,
     skills: BTreeSet<Skill>,
 }
 
 impl TechnicianBuilder
 {
-    pub fn new(technician_id: usize) -> Self
+    pub fn new(technician_id: TechnicianId) -> Self
     {
         Self {
             technician_id,
@@ -141,12 +255,12 @@ impl TechnicianBuilder
 
 impl Technician
 {
-    pub fn builder(technician_id: usize) -> TechnicianBuilder
+    pub fn builder(technician_id: TechnicianId) -> TechnicianBuilder
     {
         TechnicianBuilder::new(technician_id)
     }
 
-    pub fn id(&self) -> usize
+    pub fn id(&self) -> TechnicianId
     {
         self.technician_id
     }
@@ -1,21 +1,344 @@
 use std::collections::HashSet;
+use std::fmt;
+use std::iter::Sum;
+use std::num::ParseIntError;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+use std::str::FromStr;
 
 use chrono::NaiveDate;
 use chrono::TimeDelta;
 
 use crate::technician::Skill;
+use crate::technician::SkillLevel;
+
+/// A ten digit SAP work order number. The number is validated on
+/// construction, so a `WorkOrderNumber` is always ten digits.
+#[repr(transparent)]
+#[derive(Hash, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "u64", into = "u64"))]
+pub struct WorkOrderNumber(u64);
 
-pub type WorkOrderNumber = u64;
 pub type NumberOfPeople = u64;
 
-pub type ActivityNumber = u64;
-pub type Work = f64;
-#[derive(Hash, Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq)]
+/// The number of an `Activity` within its `WorkOrder`, e.g. 10, 20, 30.
+#[repr(transparent)]
+#[derive(Hash, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct ActivityNumber(u64);
+
+impl WorkOrderNumber
+{
+    pub fn new(work_order_number: u64) -> Result<Self, WorkOrderError>
+    {
+        if !(1_000_000_000..=9_999_999_999).contains(&work_order_number) {
+            return Err(WorkOrderError::InvalidWorkOrderNumber(work_order_number.to_string()));
+        }
+        Ok(Self(work_order_number))
+    }
+
+    pub fn get(self) -> u64
+    {
+        self.0
+    }
+}
+
+impl TryFrom<u64> for WorkOrderNumber
+{
+    type Error = WorkOrderError;
+
+    fn try_from(work_order_number: u64) -> Result<Self, Self::Error>
+    {
+        Self::new(work_order_number)
+    }
+}
+
+impl From<WorkOrderNumber> for u64
+{
+    fn from(work_order_number: WorkOrderNumber) -> Self
+    {
+        work_order_number.0
+    }
+}
+
+impl FromStr for WorkOrderNumber
+{
+    type Err = WorkOrderError;
+
+    /// Parses the number as it is written in SAP exports, e.g.
+    /// "1122334455".
+    fn from_str(work_order_number: &str) -> Result<Self, Self::Err>
+    {
+        let parsed = work_order_number
+            .trim()
+            .parse()
+            .map_err(|_| WorkOrderError::InvalidWorkOrderNumber(work_order_number.to_string()))?;
+        Self::new(parsed)
+    }
+}
+
+impl fmt::Display for WorkOrderNumber
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ActivityNumber
+{
+    pub const fn new(activity_number: u64) -> Self
+    {
+        Self(activity_number)
+    }
+
+    pub fn get(self) -> u64
+    {
+        self.0
+    }
+}
+
+impl FromStr for ActivityNumber
+{
+    type Err = ParseIntError;
+
+    fn from_str(activity_number: &str) -> Result<Self, Self::Err>
+    {
+        activity_number.trim().parse().map(Self)
+    }
+}
+
+impl fmt::Display for ActivityNumber
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Hours of work. The hours are stored as whole minutes, so that sums of
+/// work are exact and do not drift like sums of floats. The arithmetic
+/// saturates instead of overflowing, use the `checked_*` methods to detect
+/// an overflow.
+///
+/// `Work` is serialized, and formatted, as a number of hours.
+#[derive(Hash, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "f64", into = "f64"))]
+pub struct Work(i64);
+
+impl Work
+{
+    pub const MAX: Work = Work(i64::MAX);
+    pub const ZERO: Work = Work(0);
+
+    /// Rounded to the nearest minute.
+    pub fn from_hours(hours: f64) -> Self
+    {
+        Self((hours * 60.0).round() as i64)
+    }
+
+    pub const fn from_minutes(minutes: i64) -> Self
+    {
+        Self(minutes)
+    }
+
+    /// Rounded down to a whole minute.
+    pub fn from_duration(duration: TimeDelta) -> Self
+    {
+        Self(duration.num_minutes())
+    }
+
+    pub fn hours(self) -> f64
+    {
+        self.0 as f64 / 60.0
+    }
+
+    pub fn minutes(self) -> i64
+    {
+        self.0
+    }
+
+    pub fn checked_add(self, other: Work) -> Option<Work>
+    {
+        self.0.checked_add(other.0).map(Work)
+    }
+
+    pub fn checked_sub(self, other: Work) -> Option<Work>
+    {
+        self.0.checked_sub(other.0).map(Work)
+    }
+
+    pub fn checked_mul(self, factor: u64) -> Option<Work>
+    {
+        i64::try_from(factor).ok().and_then(|factor| self.0.checked_mul(factor)).map(Work)
+    }
+
+    pub fn saturating_add(self, other: Work) -> Work
+    {
+        Work(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Work) -> Work
+    {
+        Work(self.0.saturating_sub(other.0))
+    }
+
+    pub fn saturating_mul(self, factor: u64) -> Work
+    {
+        self.checked_mul(factor).unwrap_or(if self.0 < 0 { Work(i64::MIN) } else { Work::MAX })
+    }
+}
+
+impl Add for Work
+{
+    type Output = Work;
+
+    fn add(self, other: Work) -> Work
+    {
+        self.saturating_add(other)
+    }
+}
+
+impl AddAssign for Work
+{
+    fn add_assign(&mut self, other: Work)
+    {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Work
+{
+    type Output = Work;
+
+    fn sub(self, other: Work) -> Work
+    {
+        self.saturating_sub(other)
+    }
+}
+
+impl SubAssign for Work
+{
+    fn sub_assign(&mut self, other: Work)
+    {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Work
+{
+    type Output = Work;
+
+    fn neg(self) -> Work
+    {
+        Work(self.0.saturating_neg())
+    }
+}
+
+impl Mul<u64> for Work
+{
+    type Output = Work;
+
+    fn mul(self, factor: u64) -> Work
+    {
+        self.saturating_mul(factor)
+    }
+}
+
+/// The ratio of two amounts of work, e.g. a utilization.
+impl Div for Work
+{
+    type Output = f64;
+
+    fn div(self, other: Work) -> f64
+    {
+        self.0 as f64 / other.0 as f64
+    }
+}
+
+impl Sum for Work
+{
+    fn sum<I: Iterator<Item = Work>>(iter: I) -> Work
+    {
+        iter.fold(Work::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Work> for Work
+{
+    fn sum<I: Iterator<Item = &'a Work>>(iter: I) -> Work
+    {
+        iter.copied().sum()
+    }
+}
+
+impl From<f64> for Work
+{
+    fn from(hours: f64) -> Self
+    {
+        Work::from_hours(hours)
+    }
+}
+
+impl From<Work> for f64
+{
+    fn from(work: Work) -> Self
+    {
+        work.hours()
+    }
+}
+
+/// Formats the hours like an `f64`, so e.g. `{:.1}` works as for hours.
+impl fmt::Display for Work
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        fmt::Display::fmt(&self.hours(), f)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Activity
 {
     activity_number: ActivityNumber,
     number_of_people: NumberOfPeople,
     resource: Skill,
+    /// Planned hours of work for all of the people together.
+    work: Option<Work>,
+    duration: Option<TimeDelta>,
+    /// The lowest `SkillLevel` a technician needs to be assigned.
+    #[cfg_attr(feature = "serde", serde(default))]
+    skill_level: Option<SkillLevel>,
+    /// Other ways to perform the activity than `number_of_people` with the
+    /// `resource` for the `duration`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    alternatives: Vec<ResourceAlternative>,
+}
+
+/// A way to perform an `Activity`, e.g. 2 mechanics for 4 hours or 1
+/// mechanic for 8 hours.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceAlternative
+{
+    pub number_of_people: NumberOfPeople,
+    pub skill: Skill,
+    pub duration: Option<TimeDelta>,
+}
+
+impl ResourceAlternative
+{
+    /// The person-hours of the alternative. `None` without a duration.
+    pub fn work(&self) -> Option<Work>
+    {
+        self.duration.map(|duration| Work::from_duration(duration) * self.number_of_people)
+    }
 }
 
 impl Activity
@@ -34,24 +357,129 @@ impl Activity
     {
         self.number_of_people
     }
+
+    pub fn work(&self) -> Option<Work>
+    {
+        self.work
+    }
+
+    pub fn duration(&self) -> Option<TimeDelta>
+    {
+        self.duration
+    }
+
+    pub fn skill_level(&self) -> Option<SkillLevel>
+    {
+        self.skill_level
+    }
+
+    pub fn alternatives(&self) -> &[ResourceAlternative]
+    {
+        &self.alternatives
+    }
 }
 
 impl Activity
 {
-    pub fn new(activity_number: u64, number_of_people: NumberOfPeople, resource: Skill) -> Self
+    pub fn new(activity_number: ActivityNumber, number_of_people: NumberOfPeople, resource: Skill) -> Self
     {
         Self {
             activity_number,
             resource,
             number_of_people,
+            work: None,
+            duration: None,
+            skill_level: None,
+            alternatives: vec![],
         }
     }
+
+    pub fn with_work(mut self, work: Work) -> Self
+    {
+        self.work = Some(work);
+        self
+    }
+
+    pub fn with_duration(mut self, duration: TimeDelta) -> Self
+    {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn with_skill_level(mut self, skill_level: SkillLevel) -> Self
+    {
+        self.skill_level = Some(skill_level);
+        self
+    }
+
+    pub fn with_alternative(mut self, alternative: ResourceAlternative) -> Self
+    {
+        self.alternatives.push(alternative);
+        self
+    }
 }
+/// A deserialized `WorkOrder` is validated like one made with
+/// `WorkOrder::new` and `WorkOrder::with_activity_relations`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "WorkOrderFields"))]
 pub struct WorkOrder
 {
     work_order_number: WorkOrderNumber,
     basic_start_date: NaiveDate,
     activities: Vec<Activity>,
+    /// `activity_relations[i]` relates `activities[i]` to `activities[i + 1]`
+    activity_relations: Vec<ActivityRelation>,
+    risk_classification: RiskClassification,
+    priority: Priority,
+    /// The revision, e.g. a shutdown, that the work has to be executed in.
+    revision: Option<String>,
+    /// The date that the work has to be finished by.
+    due_date: Option<NaiveDate>,
+    commitment: Commitment,
+}
+
+/// The fields of a `WorkOrder` before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct WorkOrderFields
+{
+    work_order_number: WorkOrderNumber,
+    basic_start_date: NaiveDate,
+    activities: Vec<Activity>,
+    activity_relations: Vec<ActivityRelation>,
+    risk_classification: RiskClassification,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default)]
+    revision: Option<String>,
+    #[serde(default)]
+    due_date: Option<NaiveDate>,
+    #[serde(default)]
+    commitment: Commitment,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<WorkOrderFields> for WorkOrder
+{
+    type Error = WorkOrderError;
+
+    fn try_from(work_order_fields: WorkOrderFields) -> Result<Self, Self::Error>
+    {
+        let work_order = WorkOrder::new(
+            work_order_fields.work_order_number,
+            work_order_fields.basic_start_date,
+            work_order_fields.activities,
+        )?
+        .with_activity_relations(work_order_fields.activity_relations)?;
+
+        Ok(Self {
+            risk_classification: work_order_fields.risk_classification,
+            priority: work_order_fields.priority,
+            revision: work_order_fields.revision,
+            due_date: work_order_fields.due_date,
+            commitment: work_order_fields.commitment,
+            ..work_order
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -60,31 +488,93 @@ pub enum WorkOrderError
     InvalidWorkOrderNumber(String),
     NonSortedActivities(Vec<Activity>),
     DuplicatedActivities,
+    InvalidActivityRelations,
 }
 
-impl WorkOrder
+impl fmt::Display for WorkOrderError
 {
-    pub fn new(work_order_number: WorkOrderNumber, basic_start_date: NaiveDate, activities: Vec<Activity>) -> Result<Self, WorkOrderError>
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        if work_order_number.to_string().len() != 10 {
-            return Err(WorkOrderError::InvalidWorkOrderNumber(work_order_number.to_string()));
+        match self {
+            WorkOrderError::InvalidWorkOrderNumber(work_order_number) => {
+                write!(f, "invalid work order number {work_order_number}, expected ten digits")
+            }
+            WorkOrderError::NonSortedActivities(_) => write!(f, "the activities are not sorted by activity number"),
+            WorkOrderError::DuplicatedActivities => write!(f, "the activities contain a duplicated activity number"),
+            WorkOrderError::InvalidActivityRelations => write!(f, "there has to be one relation between each pair of consecutive activities"),
         }
+    }
+}
+
+impl std::error::Error for WorkOrderError {}
 
-        if !activities.is_sorted() {
+impl WorkOrder
+{
+    pub fn new(work_order_number: WorkOrderNumber, basic_start_date: NaiveDate, activities: Vec<Activity>) -> Result<Self, WorkOrderError>
+    {
+        if !activities.is_sorted_by_key(|activity| activity.activity_number) {
             return Err(WorkOrderError::NonSortedActivities(activities));
         }
 
-        if activities.iter().collect::<HashSet<_>>().len() != activities.len() {
+        if activities.iter().map(|activity| activity.activity_number).collect::<HashSet<_>>().len() != activities.len() {
             return Err(WorkOrderError::DuplicatedActivities);
         }
 
         Ok(Self {
             work_order_number,
+            activity_relations: vec![ActivityRelation::FinishStart; activities.len().saturating_sub(1)],
             activities,
             basic_start_date,
+            risk_classification: RiskClassification::default(),
+            priority: Priority::default(),
+            revision: None,
+            due_date: None,
+            commitment: Commitment::default(),
         })
     }
 
+    /// Replaces the default `FinishStart` relations. There has to be one
+    /// relation between each pair of consecutive activities.
+    pub fn with_activity_relations(mut self, activity_relations: Vec<ActivityRelation>) -> Result<Self, WorkOrderError>
+    {
+        if activity_relations.len() != self.activities.len().saturating_sub(1) {
+            return Err(WorkOrderError::InvalidActivityRelations);
+        }
+
+        self.activity_relations = activity_relations;
+        Ok(self)
+    }
+
+    pub fn with_risk_classification(mut self, risk_classification: RiskClassification) -> Self
+    {
+        self.risk_classification = risk_classification;
+        self
+    }
+
+    pub fn with_priority(mut self, priority: Priority) -> Self
+    {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_revision(mut self, revision: impl Into<String>) -> Self
+    {
+        self.revision = Some(revision.into());
+        self
+    }
+
+    pub fn with_due_date(mut self, due_date: NaiveDate) -> Self
+    {
+        self.due_date = Some(due_date);
+        self
+    }
+
+    pub fn with_commitment(mut self, commitment: Commitment) -> Self
+    {
+        self.commitment = commitment;
+        self
+    }
+
     pub fn work_order_number(&self) -> WorkOrderNumber
     {
         self.work_order_number
@@ -95,19 +585,85 @@ impl WorkOrder
         &self.activities
     }
 
-    pub fn activities_relations(&self) -> Vec<ActivityRelation>
+    pub fn activities_relations(&self) -> &Vec<ActivityRelation>
     {
-        (0..self.activities.len()).map(|_| ActivityRelation::FinishStart).collect()
+        &self.activity_relations
     }
 
     pub fn basic_start(&self) -> NaiveDate
     {
         self.basic_start_date
     }
+
+    pub fn risk_classification(&self) -> RiskClassification
+    {
+        self.risk_classification
+    }
+
+    pub fn priority(&self) -> Priority
+    {
+        self.priority
+    }
+
+    pub fn revision(&self) -> Option<&str>
+    {
+        self.revision.as_deref()
+    }
+
+    pub fn due_date(&self) -> Option<NaiveDate>
+    {
+        self.due_date
+    }
+
+    pub fn commitment(&self) -> Commitment
+    {
+        self.commitment
+    }
+}
+
+/// The priority of a `WorkOrder`, from the most to the least urgent.
+#[derive(Hash, Clone, Copy, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Priority
+{
+    VeryHigh,
+    High,
+    #[default]
+    Medium,
+    Low,
+}
+
+/// Whether a `WorkOrder` is firm work or a forecast placeholder, e.g. a
+/// preventive maintenance order generated far in the future. Forecast work
+/// is reported apart from firm work so that it does not inflate the
+/// near-term utilization.
+#[derive(Hash, Clone, Copy, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Commitment
+{
+    #[default]
+    Firm,
+    Forecast,
+}
+
+/// Health, safety and environment classification of a `WorkOrder`.
+#[derive(Hash, Clone, Copy, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RiskClassification
+{
+    /// The work has to be presented at the daily permit meeting.
+    pub hse_critical: bool,
+    /// The work may not be executed simultaneously with other work
+    /// (SIMOPS).
+    pub simops_restricted: bool,
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActivityRelation
 {
     StartStart,
     FinishStart,
+    /// The next activity starts at the earliest the `TimeDelta` after the
+    /// previous activity finishes.
     Postpone(TimeDelta),
 }
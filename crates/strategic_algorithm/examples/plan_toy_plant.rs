@@ -0,0 +1,347 @@
+//! Plans a toy plant from the import of its work orders to a published
+//! JSON snapshot. The example doubles as living documentation of the public
+//! API and as a smoke test, as `cargo test` builds it.
+//!
+//! ```text
+//! cargo run -p strategic_algorithm --example plan_toy_plant [snapshot.json]
+//! ```
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+use chrono::Weekday;
+use schedule_hypergraph::config::GraphConfig;
+use schedule_hypergraph::fixtures;
+use schedule_hypergraph::provenance::DecisionSource;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use scheduling_environment::Period;
+use scheduling_environment::PeriodLength;
+use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::Technician;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Activity;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Priority;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrder;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::capacity_smoothing::CapacitySmoothing;
+use strategic_algorithm::strategic_options::StrategicOptions;
+
+const PERIOD_COUNT: usize = 3;
+
+/// The contracted hours of a technician in a weekly period.
+const WEEKLY_HOURS: Work = Work::from_minutes(40 * 60);
+
+/// The technicians of the plant and their skills.
+const TECHNICIANS: [(TechnicianId, &[&str]); 4] = [
+    (TechnicianId::new(1001), &["MtnMech"]),
+    (TechnicianId::new(1002), &["MtnMech"]),
+    (TechnicianId::new(1003), &["MtnMech", "MtnElec"]),
+    (TechnicianId::new(1004), &["MtnElec"]),
+];
+
+/// The export of the work orders from the maintenance system. Each line is
+/// a work order with a single activity: the number, the days from the start
+/// of the horizon to the basic start, the priority, the commitment, the
+/// skill, the number of people and the hours per person.
+const WORK_ORDER_EXPORT: &str = "\
+2100000001,0,High,Firm,MtnMech,2,8
+2100000002,0,Medium,Firm,MtnMech,1,8
+2100000003,1,Medium,Firm,MtnMech,2,8
+2100000004,1,Low,Firm,MtnMech,1,8
+2100000005,2,VeryHigh,Firm,MtnElec,1,8
+2100000006,2,Medium,Firm,MtnMech,2,8
+2100000007,3,Low,Firm,MtnMech,2,8
+2100000008,3,Medium,Firm,MtnElec,2,8
+2100000009,4,Low,Firm,MtnMech,1,8
+2100000010,7,High,Firm,MtnElec,1,8
+2100000011,8,Medium,Firm,MtnMech,1,8
+2100000012,9,Low,Firm,MtnMech,2,8
+2100000013,15,Medium,Forecast,MtnMech,2,8
+2100000014,16,Low,Forecast,MtnElec,1,8";
+
+fn main()
+{
+    let mut schedule_graph = build_plant();
+
+    let work_orders = import_work_orders(&mut schedule_graph);
+    println!("Imported {} work orders\n", work_orders.len());
+
+    let periods = (0..PERIOD_COUNT)
+        .map(|week| Period::new(fixtures::start_date() + chrono::Days::new(7 * week as u64), PeriodLength::Weekly))
+        .collect::<Vec<_>>();
+    let strategic_solution = plan_periods(&work_orders, &periods);
+    plan_days(&mut schedule_graph, &work_orders, &strategic_solution);
+
+    print_dashboard(&schedule_graph, &strategic_solution);
+    print_gantt(&schedule_graph, &periods);
+
+    let snapshot_path = std::env::args()
+        .nth(1)
+        .map(Into::into)
+        .unwrap_or_else(|| std::env::temp_dir().join("toy_plant.json"));
+    let json = serde_json::to_string_pretty(&schedule_graph).expect("a schedule graph always serializes");
+    std::fs::write(&snapshot_path, json).expect("the snapshot path is writable");
+    println!("\nWrote the snapshot to {}", snapshot_path.display());
+}
+
+/// The skills, weekly periods and technicians of the plant.
+fn build_plant() -> ScheduleGraph
+{
+    let mut schedule_graph = ScheduleGraph::default();
+    schedule_graph.set_config(GraphConfig {
+        period_length: PeriodLength::Weekly,
+        ..GraphConfig::default()
+    });
+
+    schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+    schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+    let mut start_date = fixtures::start_date();
+    for _ in 0..PERIOD_COUNT {
+        schedule_graph.add_period_starting(start_date).unwrap();
+        start_date = Period::new(start_date, PeriodLength::Weekly).end_date();
+    }
+
+    let availability_start = fixtures::start_date().and_hms_opt(0, 0, 0).unwrap();
+    let availability_end = start_date.pred_opt().unwrap().and_hms_opt(23, 59, 59).unwrap();
+    for (technician_id, skills) in TECHNICIANS {
+        let technician = skills
+            .iter()
+            .fold(
+                Technician::builder(technician_id)
+                    .add_availability(availability_start, availability_end)
+                    .unwrap(),
+                |technician_builder, skill| technician_builder.add_skill(Skill::new(skill)),
+            )
+            .build();
+        schedule_graph
+            .add_technician(technician, Availability::new(availability_start, availability_end))
+            .unwrap();
+    }
+
+    schedule_graph
+}
+
+fn import_work_orders(schedule_graph: &mut ScheduleGraph) -> Vec<WorkOrder>
+{
+    WORK_ORDER_EXPORT
+        .lines()
+        .map(|line| {
+            let fields = line.split(',').collect::<Vec<_>>();
+            let [
+                work_order_number,
+                basic_start_offset,
+                priority,
+                commitment,
+                skill,
+                number_of_people,
+                hours,
+            ] = fields[..]
+            else {
+                panic!("malformed line in the work order export: {line}");
+            };
+            let priority = match priority {
+                "VeryHigh" => Priority::VeryHigh,
+                "High" => Priority::High,
+                "Medium" => Priority::Medium,
+                _ => Priority::Low,
+            };
+            let commitment = match commitment {
+                "Forecast" => Commitment::Forecast,
+                _ => Commitment::Firm,
+            };
+            let number_of_people = number_of_people.parse().unwrap();
+            let hours = Work::from_hours(hours.parse().unwrap());
+
+            let work_order = WorkOrder::new(
+                work_order_number.parse().unwrap(),
+                fixtures::start_date() + chrono::Days::new(basic_start_offset.parse().unwrap()),
+                vec![Activity::new(ActivityNumber::new(10), number_of_people, Skill::new(skill)).with_work(hours * number_of_people)],
+            )
+            .unwrap()
+            .with_priority(priority)
+            .with_commitment(commitment);
+            schedule_graph.add_work_order(&work_order).unwrap();
+            work_order
+        })
+        .collect()
+}
+
+/// Puts every work order in the period of its basic start, and lets
+/// `CapacitySmoothing` level the load over the periods.
+fn plan_periods(work_orders: &[WorkOrder], periods: &[Period]) -> StrategicSolution
+{
+    let strategic_options = StrategicOptions::default();
+    let strategic_work_order_parameters = work_orders
+        .iter()
+        .map(|work_order| {
+            let mut work_load = HashMap::new();
+            for activity in work_order.activities() {
+                *work_load.entry(activity.skill()).or_default() += activity.work().unwrap_or_default();
+            }
+            let work_order_parameter = WorkOrderParameter {
+                locked_in_period: None,
+                excluded_periods: HashSet::new(),
+                latest_period: *periods.last().unwrap(),
+                weight: WorkOrderParameter::derive_weight(work_order, periods, &strategic_options),
+                carry_over_count: 0,
+                commitment: work_order.commitment(),
+                safety_critical: work_order.risk_classification().hse_critical,
+                work_load,
+            };
+            (work_order.work_order_number(), work_order_parameter)
+        })
+        .collect();
+
+    let mut strategic_capacity = StrategicResources::default();
+    for period in periods {
+        let operational_resources = TECHNICIANS
+            .iter()
+            .map(|(technician_id, skills)| {
+                let operational_resource = OperationalResource {
+                    id: *technician_id,
+                    total_hours: WEEKLY_HOURS,
+                    skill_hours: skills.iter().map(|skill| (Skill::new(skill), WEEKLY_HOURS)).collect(),
+                    reserved_hours: HashMap::new(),
+                };
+                (*technician_id, operational_resource)
+            })
+            .collect();
+        strategic_capacity.0.insert(*period, operational_resources);
+    }
+
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters,
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.to_vec(),
+    };
+
+    let mut strategic_solution = StrategicSolution(
+        work_orders
+            .iter()
+            .map(|work_order| {
+                let period = periods.iter().find(|period| period.contains(&work_order.basic_start())).copied();
+                (work_order.work_order_number(), period)
+            })
+            .collect(),
+    );
+    let capacity_moves = CapacitySmoothing::default().smooth(&strategic_instance, &mut strategic_solution);
+    println!("Capacity smoothing moved {} work orders", capacity_moves.len());
+    strategic_solution
+}
+
+/// Places the activities of the firm work orders on the first weekday of
+/// their period where enough technicians with the skill can work a day
+/// shift without breaking the labor rules. Forecast work is left for when
+/// it becomes firm.
+fn plan_days(schedule_graph: &mut ScheduleGraph, work_orders: &[WorkOrder], strategic_solution: &StrategicSolution)
+{
+    schedule_graph.set_decision_source(Some(DecisionSource::Optimizer {
+        run_id: "plan_toy_plant".to_string(),
+    }));
+    let shift = fixtures::day_shift();
+
+    let mut work_orders = work_orders
+        .iter()
+        .filter(|work_order| work_order.commitment() == Commitment::Firm)
+        .collect::<Vec<_>>();
+    work_orders.sort_by_key(|work_order| (work_order.priority(), work_order.work_order_number()));
+
+    for work_order in work_orders {
+        let Some(period) = strategic_solution.0[&work_order.work_order_number()] else {
+            continue;
+        };
+        for activity in work_order.activities() {
+            let weekdays = period
+                .days()
+                .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+                .collect::<Vec<_>>();
+            schedule_graph
+                .schedule_activity(work_order.work_order_number(), activity.activity_number(), &weekdays, shift, None)
+                .unwrap();
+        }
+    }
+    schedule_graph.set_decision_source(None);
+}
+
+fn print_dashboard(schedule_graph: &ScheduleGraph, strategic_solution: &StrategicSolution)
+{
+    println!("{}", schedule_graph.instance_report().to_markdown());
+
+    let mut work_orders_by_period = BTreeMap::<Option<Period>, usize>::new();
+    for period in strategic_solution.0.values() {
+        *work_orders_by_period.entry(*period).or_default() += 1;
+    }
+
+    println!("## Plan\n");
+    for (period, count) in work_orders_by_period {
+        match period {
+            Some(period) => println!("- Work orders in the week of {}: {count}", period.start_date()),
+            None => println!("- Work orders in the backlog: {count}"),
+        }
+    }
+    let conflict_report = schedule_graph.conflict_report();
+    println!(
+        "- Conflicts: {} hard, {} soft, publishable: {}",
+        conflict_report.hard.len(),
+        conflict_report.soft.len(),
+        conflict_report.is_publishable()
+    );
+    println!("- Labor rule violations: {}", schedule_graph.labor_rule_violations().len());
+    println!("- Machine-made share: {:.0}%", 100.0 * schedule_graph.authorship().machine_made_share());
+    println!("- Valid: {}", schedule_graph.validate().is_valid());
+}
+
+/// One row per technician and one column per day, marked with the letter of
+/// the assigned work order.
+fn print_gantt(schedule_graph: &ScheduleGraph, periods: &[Period])
+{
+    let days = periods.iter().flat_map(Period::days).collect::<Vec<NaiveDate>>();
+    let mut letters = BTreeMap::<WorkOrderNumber, char>::new();
+
+    println!("\n## Gantt\n");
+    println!("{:>6} {}", "", days.iter().map(|day| day_letter(*day)).collect::<String>());
+    for (technician_id, _) in TECHNICIANS {
+        let mut row = vec!['.'; days.len()];
+        for assignment in schedule_graph.assignments_for_technician(technician_id).unwrap() {
+            let next_letter = (b'A' + letters.len() as u8) as char;
+            let letter = *letters.entry(assignment.work_order).or_insert(next_letter);
+            for day in assignment.days {
+                if let Some(column) = days.iter().position(|planned_day| *planned_day == day) {
+                    row[column] = letter;
+                }
+            }
+        }
+        println!("{technician_id:>6} {}", row.into_iter().collect::<String>());
+    }
+
+    println!();
+    for (work_order_number, letter) in letters {
+        println!("{letter}: {work_order_number}");
+    }
+}
+
+fn day_letter(day: NaiveDate) -> char
+{
+    match day.weekday() {
+        Weekday::Mon => 'M',
+        Weekday::Tue | Weekday::Thu => 'T',
+        Weekday::Wed => 'W',
+        Weekday::Fri => 'F',
+        Weekday::Sat | Weekday::Sun => 'S',
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::StrategicSolution;
+use crate::solver::StrategicSolver;
+
+/// Levels the load of a `StrategicSolution` by shifting work orders among
+/// the draft periods to flatten the per-skill utilization peaks. Unlike the
+/// greedy scheduler it does not pull work forward, it only spreads the work
+/// that is already scheduled.
+///
+/// A work order is only moved when it is not urgent, not locked in a
+/// period, and the new period is neither excluded nor after its latest
+/// period. Periods in `StrategicInstance::period_locks` are not draft
+/// periods, so work is neither moved into nor out of them.
+///
+/// Forecast work orders are soft load. They are left out of the
+/// utilization, so the firm work is levelled as if they were not there, and
+/// they are never moved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapacitySmoothing
+{
+    /// Work orders with at least this weight are urgent and stay in their
+    /// period.
+    pub urgent_weight: i64,
+}
+
+/// A work order moved by `CapacitySmoothing`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapacityMove
+{
+    pub work_order_number: WorkOrderNumber,
+    pub from: Period,
+    pub to: Period,
+}
+
+impl Default for CapacitySmoothing
+{
+    fn default() -> Self
+    {
+        Self { urgent_weight: i64::MAX }
+    }
+}
+
+impl StrategicSolver for CapacitySmoothing
+{
+    fn solve(&mut self, strategic_instance: &StrategicInstance, mut initial_solution: StrategicSolution) -> StrategicSolution
+    {
+        self.smooth(strategic_instance, &mut initial_solution);
+        initial_solution
+    }
+}
+
+impl CapacitySmoothing
+{
+    /// Repeatedly shaves the highest utilization of a skill in a draft
+    /// period by moving one of its work orders to the draft period where
+    /// the utilization of both periods ends up lowest. Stops when the peak
+    /// cannot be lowered. Every move lowers the peak of the two periods
+    /// involved, so no utilization ends up above the initial peak.
+    ///
+    /// Returns the moves in the order they were applied.
+    pub fn smooth(&self, strategic_instance: &StrategicInstance, strategic_solution: &mut StrategicSolution) -> Vec<CapacityMove>
+    {
+        let draft_periods = strategic_instance
+            .strategic_periods
+            .iter()
+            .filter(|period| !strategic_instance.period_locks.contains(period))
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let mut capacity_moves = vec![];
+        loop {
+            let utilization = self.utilization(strategic_instance, strategic_solution, &draft_periods);
+            let Some((&(peak_period, peak_skill), &peak_utilization)) = utilization.iter().max_by(|(_, left), (_, right)| left.total_cmp(right))
+            else {
+                break;
+            };
+
+            let mut candidates = strategic_solution
+                .0
+                .iter()
+                .filter(|(_, period)| **period == Some(peak_period))
+                .map(|(work_order_number, _)| *work_order_number)
+                .collect::<Vec<_>>();
+            candidates.sort();
+
+            let mut best_move: Option<(CapacityMove, f64)> = None;
+            for work_order_number in candidates {
+                let Some(work_order_parameter) = strategic_instance.strategic_work_order_parameters.get(&work_order_number) else {
+                    continue;
+                };
+                if work_order_parameter.weight >= self.urgent_weight
+                    || work_order_parameter.locked_in_period.is_some()
+                    || work_order_parameter.work_load.get(&peak_skill).copied().unwrap_or_default() <= Work::ZERO
+                {
+                    continue;
+                }
+
+                for to in &draft_periods {
+                    if *to == peak_period || work_order_parameter.excluded_periods.contains(to) || *to > work_order_parameter.latest_period {
+                        continue;
+                    }
+
+                    let mut moved_solution = strategic_solution.clone();
+                    moved_solution.0.insert(work_order_number, Some(*to));
+                    let moved_utilization = self.utilization(strategic_instance, &moved_solution, &[peak_period, *to].into());
+                    let moved_peak = moved_utilization.values().copied().fold(0.0, f64::max);
+
+                    if moved_peak < peak_utilization && best_move.as_ref().is_none_or(|(_, best_peak)| moved_peak < *best_peak) {
+                        best_move = Some((
+                            CapacityMove {
+                                work_order_number,
+                                from: peak_period,
+                                to: *to,
+                            },
+                            moved_peak,
+                        ));
+                    }
+                }
+            }
+
+            let Some((capacity_move, _)) = best_move else {
+                break;
+            };
+            strategic_solution.0.insert(capacity_move.work_order_number, Some(capacity_move.to));
+            capacity_moves.push(capacity_move);
+        }
+
+        capacity_moves
+    }
+
+    /// The load divided by the capacity of each skill in each of the
+    /// periods. A skill with load and no capacity is infinitely utilized.
+    fn utilization(
+        &self,
+        strategic_instance: &StrategicInstance,
+        strategic_solution: &StrategicSolution,
+        periods: &BTreeSet<Period>,
+    ) -> BTreeMap<(Period, Skill), f64>
+    {
+        let mut load = BTreeMap::<(Period, Skill), Work>::new();
+        for (work_order_number, period) in &strategic_solution.0 {
+            let Some(period) = period.filter(|period| periods.contains(period)) else {
+                continue;
+            };
+            let Some(work_order_parameter) = strategic_instance
+                .strategic_work_order_parameters
+                .get(work_order_number)
+                .filter(|work_order_parameter| work_order_parameter.commitment == Commitment::Firm)
+            else {
+                continue;
+            };
+            for (skill, work) in &work_order_parameter.work_load {
+                *load.entry((period, *skill)).or_default() += *work;
+            }
+        }
+
+        load.into_iter()
+            .map(|((period, skill), work)| {
+                let capacity = strategic_instance.strategic_capacity.skill_hours(&period, &skill);
+                let utilization = if capacity > Work::ZERO { work / capacity } else { f64::INFINITY };
+                ((period, skill), utilization)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::StrategicResources;
+use crate::StrategicSolution;
+use crate::WorkOrderParameter;
+use crate::solver::StrategicSolver;
+
+/// `complete_solution` as a `StrategicSolver`. Only the work orders of
+/// the initial solution that are in a period of
+/// `StrategicInstance::period_locks` are kept, the rest is placed again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GreedySolver;
+
+impl StrategicSolver for GreedySolver
+{
+    fn solve(&mut self, strategic_instance: &StrategicInstance, initial_solution: StrategicSolution) -> StrategicSolution
+    {
+        complete_solution(strategic_instance, &initial_solution)
+    }
+}
+
+/// Builds the warm start of the strategic algorithm, see
+/// `StrategicScheduler::Greedy`. The work orders are placed by descending
+/// weight, ties broken by work order number, each in the earliest period
+/// where `StrategicResources::reserve_work_load` can reserve its work load
+/// from the remaining capacity. A work order that fits nowhere is left in
+/// the backlog.
+///
+/// A work order locked in a period is placed there first, whether it fits
+/// or not. Other work orders are never placed in a period of
+/// `StrategicInstance::period_locks` or in one of their excluded periods.
+/// Forecast work orders are soft load, they go in the earliest of those
+/// periods and do not use any capacity.
+///
+/// Safety-critical work orders are placed right after the locked work
+/// orders and may go in a locked period. Their placement is mandatory, one
+/// that fits nowhere goes in its earliest period that is not excluded.
+pub fn construct_initial_solution(strategic_instance: &StrategicInstance) -> StrategicSolution
+{
+    complete_solution(strategic_instance, &StrategicSolution::default())
+}
+
+/// `construct_initial_solution` around the part of a previous solution that
+/// may not change. A work order that the previous solution put in a period
+/// of `StrategicInstance::period_locks` stays there, like a work order
+/// locked in a period, so only the work in the draft periods and the
+/// backlog is planned again.
+pub fn complete_solution(strategic_instance: &StrategicInstance, previous_solution: &StrategicSolution) -> StrategicSolution
+{
+    place_work_orders(strategic_instance, previous_solution, |_| None)
+}
+
+/// `complete_solution` that tries the period of the solution first for
+/// every work order. A work order stays where it is as long as its work
+/// load still fits there, and only the work orders that overload their
+/// period are moved to the earliest period where they fit, or to the
+/// backlog. Work orders in the backlog are placed like in
+/// `complete_solution`.
+pub fn settle_solution(strategic_instance: &StrategicInstance, strategic_solution: &StrategicSolution) -> StrategicSolution
+{
+    place_work_orders(strategic_instance, strategic_solution, |work_order_number| {
+        strategic_solution.0.get(work_order_number).copied().flatten()
+    })
+}
+
+fn place_work_orders(
+    strategic_instance: &StrategicInstance,
+    previous_solution: &StrategicSolution,
+    preferred_period: impl Fn(&WorkOrderNumber) -> Option<Period>,
+) -> StrategicSolution
+{
+    let fixed_period = |work_order_number: &WorkOrderNumber, work_order_parameter: &WorkOrderParameter| {
+        work_order_parameter.locked_in_period.or_else(|| {
+            previous_solution
+                .0
+                .get(work_order_number)
+                .copied()
+                .flatten()
+                .filter(|period| strategic_instance.period_locks.contains(period))
+        })
+    };
+
+    let mut periods = strategic_instance.strategic_periods.clone();
+    periods.sort();
+
+    let mut remaining_resources = strategic_instance.strategic_capacity.clone();
+
+    let mut work_order_parameters = strategic_instance.strategic_work_order_parameters.iter().collect::<Vec<_>>();
+    work_order_parameters.sort_by_key(|(work_order_number, work_order_parameter)| {
+        (
+            fixed_period(work_order_number, work_order_parameter).is_none(),
+            !work_order_parameter.safety_critical,
+            std::cmp::Reverse(work_order_parameter.weight),
+            **work_order_number,
+        )
+    });
+
+    let mut strategic_solution = StrategicSolution::default();
+    for (work_order_number, work_order_parameter) in work_order_parameters {
+        let firm = work_order_parameter.commitment == Commitment::Firm;
+        let preferred_period = preferred_period(work_order_number).filter(|period| periods.contains(period));
+        let mut candidate_periods = preferred_period
+            .iter()
+            .chain(periods.iter().filter(|period| Some(**period) != preferred_period))
+            .filter(|period| {
+                (work_order_parameter.safety_critical || !strategic_instance.period_locks.contains(period))
+                    && !work_order_parameter.excluded_periods.contains(period)
+            });
+        let placement = match fixed_period(work_order_number, work_order_parameter) {
+            Some(period) => Some((period, false)),
+            None => candidate_periods
+                .clone()
+                .find(|period| !firm || remaining_resources.reserve_work_load(**period, &work_order_parameter.work_load).is_ok())
+                .map(|period| (*period, true))
+                .or_else(|| {
+                    candidate_periods
+                        .next()
+                        .filter(|_| work_order_parameter.safety_critical)
+                        .map(|period| (*period, false))
+                }),
+        };
+
+        if let Some((period, false)) = placement
+            && firm
+        {
+            reserve_available(&mut remaining_resources, period, &work_order_parameter.work_load);
+        }
+        let period = placement.map(|(period, _)| period);
+        strategic_solution.0.insert(*work_order_number, period);
+    }
+    strategic_solution
+}
+
+/// Reserves the work load of a work order that is placed whether it fits
+/// or not, as far as the period has hours left.
+fn reserve_available(remaining_resources: &mut StrategicResources, period: Period, work_load: &HashMap<Skill, Work>)
+{
+    let mut skills = work_load.keys().collect::<Vec<_>>();
+    skills.sort();
+    for skill in skills {
+        let available = remaining_resources.remaining(period, *skill);
+        remaining_resources
+            .reserve(period, *skill, work_load[skill].min(available))
+            .expect("the work is capped at the remaining hours");
+    }
+}
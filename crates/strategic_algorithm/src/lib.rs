@@ -1,12 +1,30 @@
+pub mod capacity_smoothing;
+pub mod greedy;
+pub mod objective;
+pub mod period_state;
+pub mod sharding;
+pub mod simulated_annealing;
+pub mod solver;
+pub mod strategic_options;
+pub mod unscheduled;
+pub mod work_center;
+
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-use schedule_hypergraph::schedule_graph::Period;
+use chrono::NaiveDate;
 use schedule_hypergraph::schedule_graph::ScheduleGraph;
-use schedule_hypergraph::schedule_graph::TechnicianId;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use scheduling_environment::Period;
 use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Priority;
 use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrder;
 use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_options::StrategicOptions;
 
 #[derive(Debug)]
 pub struct StrategicInstance
@@ -14,18 +32,12 @@ pub struct StrategicInstance
     pub strategic_work_order_parameters: HashMap<WorkOrderNumber, WorkOrderParameter>,
     // This comes from technician availability
     pub strategic_capacity: StrategicResources,
-    // pub strategic_clustering: StrategicClustering,
+    pub strategic_clustering: StrategicClustering,
     // This comes from the `assignment`.
     pub period_locks: HashSet<Period>,
     // TODO #04 #00 #01
-    // enum PeriodState {
-    //     Previous(Period),
-    //     Frozen(Period),
-    //     Draft(Period),
-    //     Draft2(Period),
-    // }
-    // Create this and have it change based on the value
-    // of the [`SystemClock`].
+    // [x] - The state of each period changes with the `SystemClock`, see
+    // `StrategicInstance::period_states`.
     pub strategic_periods: Vec<Period>,
     // TODO [ ] Should the options be here? Yes they, no they should not.
     // WARN [ ] Now you know why!
@@ -43,34 +55,432 @@ pub struct WorkOrderParameter
     pub excluded_periods: HashSet<Period>,
     pub latest_period: Period,
 
+    /// See `WorkOrderParameter::derive_weight`.
     pub weight: i64,
-    // This weight is derived from the [`StrategicOptions`]. This means that the code should
-    // work better
+    /// The number of times that the work order has been carried over from a
+    /// closed period, see `ScheduleGraph::carry_over_count`.
+    pub carry_over_count: usize,
+    /// The work load of a `Commitment::Forecast` work order is soft, it
+    /// does not count towards the utilization of a period.
+    pub commitment: Commitment,
+    /// The work order is HSE-critical, see `RiskClassification`. Safety
+    /// work is placed before all other work by `construct_initial_solution`.
+    pub safety_critical: bool,
     pub work_load: HashMap<Skill, Work>,
 }
 
+impl WorkOrderParameter
+{
+    /// Derives the weight of a work order from its `Priority`, due date and
+    /// age with the weights of the `StrategicOptions`. Every step of
+    /// priority multiplies the weight by `priority_weight`, and the weight
+    /// is multiplied by one plus `due_weight` for every strategic period
+    /// ending after the due date, so work due early in the horizon, or
+    /// already overdue, is scheduled first. Finally `age_weight` is added
+    /// per week between the basic start and the first strategic period.
+    /// The revision does not change the weight as it already decides the
+    /// periods the work can go in. The weight of HSE-critical work is
+    /// multiplied by `safety_weight_boost` at last. The weight saturates at
+    /// `i64::MAX`.
+    pub fn derive_weight(work_order: &WorkOrder, strategic_periods: &[Period], strategic_options: &StrategicOptions) -> i64
+    {
+        Self::weight_of(
+            work_order.priority(),
+            work_order.due_date(),
+            work_order.basic_start(),
+            work_order.risk_classification().hse_critical,
+            strategic_periods,
+            strategic_options,
+        )
+    }
+
+    fn weight_of(
+        priority: Priority,
+        due_date: Option<NaiveDate>,
+        basic_start: NaiveDate,
+        hse_critical: bool,
+        strategic_periods: &[Period],
+        strategic_options: &StrategicOptions,
+    ) -> i64
+    {
+        let priority_steps = match priority {
+            Priority::VeryHigh => 3,
+            Priority::High => 2,
+            Priority::Medium => 1,
+            Priority::Low => 0,
+        };
+        let priority_weight = strategic_options.priority_weight.saturating_pow(priority_steps);
+        let due_periods = due_date.map_or(0, |due_date| {
+            strategic_periods.iter().filter(|period| period.end_date() > due_date).count() as i64
+        });
+        let age_weeks = strategic_periods
+            .iter()
+            .map(Period::start_date)
+            .min()
+            .map_or(0, |horizon_start| (horizon_start - basic_start).num_days().max(0) / 7);
+
+        // The weights are configurable, so the arithmetic saturates instead
+        // of overflowing on extreme values.
+        let due_factor = strategic_options.due_weight.saturating_mul(due_periods).saturating_add(1);
+        let weight = priority_weight
+            .saturating_mul(due_factor)
+            .saturating_add(strategic_options.age_weight.saturating_mul(age_weeks));
+        if hse_critical {
+            weight.saturating_mul(strategic_options.safety_weight_boost)
+        } else {
+            weight
+        }
+    }
+
+    /// The parameter of a work order of the graph, with the weight of
+    /// `derive_weight`. The latest period is the one of the due date, the
+    /// first period if the work order is already overdue and the last one
+    /// if it has no due date. Without periods it is the period starting at
+    /// the basic start. The assignments of the graph do not lock the
+    /// work order, they are its warm start, see
+    /// `StrategicSolution::from_schedule_graph`.
+    pub fn from_schedule_graph(
+        schedule_graph: &ScheduleGraph,
+        work_order_number: WorkOrderNumber,
+        strategic_periods: &[Period],
+        strategic_options: &StrategicOptions,
+    ) -> Result<Self, ScheduleGraphErrors>
+    {
+        let mut periods = strategic_periods.to_vec();
+        periods.sort();
+        let basic_start = schedule_graph.work_order_basic_start(work_order_number)?;
+        let due_date = schedule_graph.work_order_due_date(work_order_number)?;
+        let latest_period = match due_date {
+            Some(due_date) => periods.iter().rev().find(|period| period.start_date() <= due_date).or(periods.first()),
+            None => periods.last(),
+        }
+        .copied()
+        .unwrap_or_else(|| Period::from_start_date(basic_start));
+        let risk_classification = schedule_graph.risk_classification(work_order_number)?;
+
+        Ok(WorkOrderParameter {
+            locked_in_period: None,
+            excluded_periods: schedule_graph.periods_excluding(work_order_number)?.into_iter().collect(),
+            latest_period,
+            weight: Self::weight_of(
+                schedule_graph.work_order_priority(work_order_number)?,
+                due_date,
+                basic_start,
+                risk_classification.hse_critical,
+                &periods,
+                strategic_options,
+            ),
+            carry_over_count: schedule_graph.carry_over_count(work_order_number),
+            commitment: schedule_graph.work_order_commitment(work_order_number)?,
+            safety_critical: risk_classification.hse_critical,
+            work_load: schedule_graph.work_order_work_load(work_order_number)?.into_iter().collect(),
+        })
+    }
+}
+
+/// The period of each work order. A work order without a period is left in
+/// the backlog.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StrategicSolution(pub HashMap<WorkOrderNumber, Option<Period>>);
+
+impl StrategicSolution
+{
+    /// The plan of the graph as the warm start of the solvers, see
+    /// `solver::warm_start`. A work order of the instance is in the earliest
+    /// of the strategic periods that one of its assignments belongs to, see
+    /// `ScheduleGraph::assignments_for_period`, and in the backlog if it
+    /// has none.
+    pub fn from_schedule_graph(schedule_graph: &ScheduleGraph, strategic_instance: &StrategicInstance) -> Result<Self, ScheduleGraphErrors>
+    {
+        let mut strategic_solution = StrategicSolution(
+            strategic_instance
+                .strategic_work_order_parameters
+                .keys()
+                .map(|work_order_number| (*work_order_number, None))
+                .collect(),
+        );
+
+        let mut periods = strategic_instance.strategic_periods.clone();
+        periods.sort();
+        for period in periods {
+            for assignment in schedule_graph.assignments_for_period(period)? {
+                if let Some(scheduled_period @ None) = strategic_solution.0.get_mut(&assignment.work_order) {
+                    *scheduled_period = Some(period);
+                }
+            }
+        }
+        Ok(strategic_solution)
+    }
+}
+
+/// How much it is worth to schedule two work orders in the same period, e.g.
+/// because they share a functional location. A pair is keyed with the lower
+/// work order number first, see `StrategicClustering::value`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StrategicClustering(pub HashMap<(WorkOrderNumber, WorkOrderNumber), i64>);
+
+impl StrategicClustering
+{
+    pub fn value(&self, work_order_number: WorkOrderNumber, other_work_order_number: WorkOrderNumber) -> i64
+    {
+        let key = if work_order_number <= other_work_order_number {
+            (work_order_number, other_work_order_number)
+        } else {
+            (other_work_order_number, work_order_number)
+        };
+        self.0.get(&key).copied().unwrap_or_default()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StrategicResources(pub HashMap<Period, HashMap<TechnicianId, OperationalResource>>);
 
-#[derive(Clone, PartialEq, Debug, Default)]
+impl StrategicResources
+{
+    /// Sums the `Available` hours of each technician per period and per
+    /// skill, see `ScheduleGraph::capacity_calendar`. The total hours of a
+    /// technician count each available day once, even though the hours of
+    /// the day are counted for each of its skills. The hours purchased from
+    /// contractor pools are left out, as they are not technicians.
+    pub fn from_schedule_graph(schedule_graph: &ScheduleGraph, periods: &[Period]) -> Result<Self, ScheduleGraphErrors>
+    {
+        let capacity_calendar = schedule_graph.capacity_calendar(periods)?;
+
+        let mut strategic_resources = StrategicResources::default();
+        for (period, period_capacity) in &capacity_calendar.0 {
+            let operational_resources = strategic_resources.0.entry(*period).or_default();
+            let mut technician_days = HashMap::<TechnicianId, BTreeMap<_, Work>>::new();
+            for (skill, skill_capacity) in &period_capacity.skills {
+                for (technician_id, technician_capacity) in &skill_capacity.technicians {
+                    operational_resources
+                        .entry(*technician_id)
+                        .or_insert_with(|| OperationalResource {
+                            id: *technician_id,
+                            total_hours: Work::ZERO,
+                            skill_hours: HashMap::new(),
+                            reserved_hours: HashMap::new(),
+                        })
+                        .skill_hours
+                        .insert(*skill, technician_capacity.total_hours);
+
+                    let days = technician_days.entry(*technician_id).or_default();
+                    for (day, hours) in &technician_capacity.days {
+                        let day_hours = days.entry(*day).or_default();
+                        *day_hours = (*day_hours).max(*hours);
+                    }
+                }
+            }
+            for (technician_id, days) in technician_days {
+                operational_resources.get_mut(&technician_id).unwrap().total_hours = days.values().sum();
+            }
+        }
+        Ok(strategic_resources)
+    }
+
+    /// The hours of all technicians with the skill in the period.
+    pub fn skill_hours(&self, period: &Period, skill: &Skill) -> Work
+    {
+        self.0
+            .get(period)
+            .into_iter()
+            .flat_map(|operational_resources| operational_resources.values())
+            .filter_map(|operational_resource| operational_resource.skill_hours.get(skill))
+            .sum()
+    }
+
+    /// The hours of the skill in the period that are not reserved, see
+    /// `OperationalResource::remaining`.
+    pub fn remaining(&self, period: Period, skill: Skill) -> Work
+    {
+        self.0
+            .get(&period)
+            .into_iter()
+            .flat_map(|operational_resources| operational_resources.values())
+            .map(|operational_resource| operational_resource.remaining(&skill))
+            .sum()
+    }
+
+    /// Reserves the work from the technicians with the skill in the period.
+    /// The technicians with the fewest skills are used first, ties broken by
+    /// the lowest `TechnicianId`, so the hours of a multi-skilled technician
+    /// are kept for the skills that the others cannot cover.
+    pub fn reserve(&mut self, period: Period, skill: Skill, work: Work) -> Result<(), StrategicResourceErrors>
+    {
+        let remaining = self.remaining(period, skill);
+        if work > remaining {
+            return Err(StrategicResourceErrors::OverAllocation {
+                period,
+                skill,
+                requested: work,
+                available: remaining,
+            });
+        }
+
+        let mut unreserved_work = work;
+        for operational_resource in self.sorted_operational_resources(period) {
+            let reserved_hours = unreserved_work.min(operational_resource.remaining(&skill));
+            if reserved_hours > Work::ZERO {
+                *operational_resource.reserved_hours.entry(skill).or_default() += reserved_hours;
+                unreserved_work -= reserved_hours;
+            }
+        }
+        Ok(())
+    }
+
+    /// `reserve` of every skill of the work load, all or nothing. The
+    /// skills are reserved in order, and on an error the resources of the
+    /// period are left as they were.
+    pub fn reserve_work_load(&mut self, period: Period, work_load: &HashMap<Skill, Work>) -> Result<(), StrategicResourceErrors>
+    {
+        let previous_resources = self.0.get(&period).cloned();
+        let mut skills = work_load.keys().collect::<Vec<_>>();
+        skills.sort();
+        for skill in skills {
+            if let Err(error) = self.reserve(period, *skill, work_load[skill]) {
+                if let Some(previous_resources) = previous_resources {
+                    self.0.insert(period, previous_resources);
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Gives work of the skill that was reserved in the period back. The
+    /// reservations are released in the reverse order of `reserve`, so the
+    /// technicians with the most skills get their hours back first.
+    pub fn release(&mut self, period: Period, skill: Skill, work: Work) -> Result<(), StrategicResourceErrors>
+    {
+        let releasable = self
+            .0
+            .get(&period)
+            .into_iter()
+            .flat_map(|operational_resources| operational_resources.values())
+            .filter_map(|operational_resource| operational_resource.reserved_hours.get(&skill))
+            .sum::<Work>();
+        if work > releasable {
+            return Err(StrategicResourceErrors::OverRelease {
+                period,
+                skill,
+                requested: work,
+                releasable,
+            });
+        }
+
+        let mut unreleased_work = work;
+        for operational_resource in self.sorted_operational_resources(period).into_iter().rev() {
+            let Some(reserved_hours) = operational_resource.reserved_hours.get_mut(&skill) else {
+                continue;
+            };
+            let released_hours = unreleased_work.min(*reserved_hours);
+            *reserved_hours -= released_hours;
+            unreleased_work -= released_hours;
+            if *reserved_hours == Work::ZERO {
+                operational_resource.reserved_hours.remove(&skill);
+            }
+        }
+        Ok(())
+    }
+
+    fn sorted_operational_resources(&mut self, period: Period) -> Vec<&mut OperationalResource>
+    {
+        let mut operational_resources = self
+            .0
+            .get_mut(&period)
+            .into_iter()
+            .flat_map(|operational_resources| operational_resources.values_mut())
+            .collect::<Vec<_>>();
+        operational_resources.sort_by_key(|operational_resource| (operational_resource.skill_hours.len(), operational_resource.id));
+        operational_resources
+    }
+}
+
+/// The errors of `StrategicResources::reserve` and
+/// `StrategicResources::release`. The resources are unchanged on an error.
+#[derive(Clone, PartialEq, Debug)]
+pub enum StrategicResourceErrors
+{
+    /// More work was reserved than the skill has left in the period.
+    OverAllocation
+    {
+        period: Period,
+        skill: Skill,
+        requested: Work,
+        available: Work,
+    },
+    /// More work was released than was reserved from the skill in the
+    /// period.
+    OverRelease
+    {
+        period: Period,
+        skill: Skill,
+        requested: Work,
+        releasable: Work,
+    },
+}
+
+#[derive(Clone, PartialEq, Debug)]
 pub struct OperationalResource
 {
     pub id: TechnicianId,
     pub total_hours: Work,
+    /// The hours the technician can work in each skill. A technician with
+    /// several skills can work all of them, so the skill hours add up to
+    /// more than the `total_hours`.
     pub skill_hours: HashMap<Skill, Work>,
+    /// The hours reserved from each skill, see `StrategicResources::reserve`.
+    pub reserved_hours: HashMap<Skill, Work>,
 }
 
-impl From<&ScheduleGraph> for StrategicInstance {
-    fn from(value: &ScheduleGraph) -> Self {
+impl OperationalResource
+{
+    /// The hours of the skill that are not reserved. A reservation of any
+    /// skill uses the hours of the technician, so the remaining hours of
+    /// every skill are capped at the `total_hours` less all reservations.
+    pub fn remaining(&self, skill: &Skill) -> Work
+    {
+        let Some(skill_hours) = self.skill_hours.get(skill) else {
+            return Work::ZERO;
+        };
+        let reserved_hours = self.reserved_hours.values().copied().sum::<Work>();
+        let skill_remaining = *skill_hours - self.reserved_hours.get(skill).copied().unwrap_or_default();
+        skill_remaining.min(self.total_hours - reserved_hours).max(Work::ZERO)
+    }
+}
 
-        // TODO [ ] - You have to derive the StrategicInstance
-        value.
+impl StrategicInstance
+{
+    /// The instance of every work order of the graph in the periods. The
+    /// capacity is the one of `StrategicResources::from_schedule_graph`,
+    /// see `WorkOrderParameter::from_schedule_graph` for the work orders.
+    /// The periods that are closed in the graph are locked, and no work
+    /// orders are clustered.
+    pub fn from_schedule_graph(
+        schedule_graph: &ScheduleGraph,
+        strategic_periods: &[Period],
+        strategic_options: &StrategicOptions,
+    ) -> Result<Self, ScheduleGraphErrors>
+    {
+        let strategic_work_order_parameters = schedule_graph
+            .work_order_numbers()
+            .into_iter()
+            .map(|work_order_number| {
+                let work_order_parameter =
+                    WorkOrderParameter::from_schedule_graph(schedule_graph, work_order_number, strategic_periods, strategic_options)?;
+                Ok((work_order_number, work_order_parameter))
+            })
+            .collect::<Result<_, ScheduleGraphErrors>>()?;
 
-        Self {
-            strategic_work_order_parameters: todo!(),
-            strategic_capacity: todo!(),
-            period_locks: todo!(),
-            strategic_periods: todo!(),
-        }
+        Ok(StrategicInstance {
+            strategic_work_order_parameters,
+            strategic_capacity: StrategicResources::from_schedule_graph(schedule_graph, strategic_periods)?,
+            strategic_clustering: StrategicClustering::default(),
+            period_locks: strategic_periods
+                .iter()
+                .filter(|period| schedule_graph.is_period_closed(**period))
+                .copied()
+                .collect(),
+            strategic_periods: strategic_periods.to_vec(),
+        })
     }
 }
@@ -0,0 +1,389 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::StrategicSolution;
+use crate::strategic_options::ConstraintMode;
+use crate::strategic_options::ObjectiveMode;
+use crate::strategic_options::StrategicOptions;
+
+/// Scores a `StrategicSolution` with the weights of the `StrategicOptions`.
+/// A lower value is a better solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategicObjective
+{
+    pub strategic_options: StrategicOptions,
+    /// The previously published solution. Work orders that move away from
+    /// it are penalized with `StrategicOptions::stability_weight`.
+    pub reference_solution: Option<StrategicSolution>,
+}
+
+/// The score of a `StrategicSolution` decomposed per work order, so that a
+/// planner can see why a work order ended up where it did.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectiveValue
+{
+    pub work_orders: BTreeMap<WorkOrderNumber, WorkOrderObjective>,
+}
+
+/// The contribution of a single work order to the `ObjectiveValue`. Every
+/// term is already multiplied by its weight in the `StrategicOptions`,
+/// except for the unweighted measures that `ObjectiveMode::Lexicographic`
+/// ranks by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorkOrderObjective
+{
+    /// The period that the terms are attributed to, `None` for the backlog.
+    pub period: Option<Period>,
+    /// Penalty for the periods that the work order is scheduled after its
+    /// latest period. A work order left in the backlog is late by one
+    /// period more than the last strategic period.
+    pub tardiness: i64,
+    /// Reward for scheduling the work order.
+    pub weight: i64,
+    /// Reward for the work orders clustered with it in the same period. A
+    /// pair rewards both of its work orders.
+    pub clustering: i64,
+    /// The share of the penalty for the overloaded hours in the period
+    /// that matches the share of the load of the work order.
+    pub capacity_penalty: i64,
+    /// Penalty for the carry-overs of a work order left in the backlog.
+    pub escalation: i64,
+    /// Penalty for moving the work order away from its period in the
+    /// reference solution. The earlier the period it left, the closer it
+    /// was to execution and the higher the penalty.
+    pub stability: i64,
+    /// The work order breaks a `ConstraintMode::Hard` constraint.
+    pub hard_violation: bool,
+    /// The unweighted periods behind `tardiness`.
+    pub periods_late: i64,
+    /// The unweighted share of the overloaded hours behind
+    /// `capacity_penalty`. Also set when the capacity is hard.
+    pub overloaded_work: Work,
+    /// The unweighted periods behind `stability`.
+    pub instability: i64,
+}
+
+impl WorkOrderObjective
+{
+    pub fn value(&self) -> i64
+    {
+        self.tardiness + self.capacity_penalty + self.escalation + self.stability - self.weight - self.clustering
+    }
+}
+
+/// The terms of the work orders attributed to the same period, see
+/// `ObjectiveValue::periods`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeriodObjective
+{
+    /// `None` for the backlog.
+    pub period: Option<Period>,
+    pub tardiness: i64,
+    pub weight: i64,
+    pub clustering: i64,
+    pub capacity_penalty: i64,
+    pub escalation: i64,
+    pub stability: i64,
+    pub hard_violations: usize,
+}
+
+impl PeriodObjective
+{
+    pub fn value(&self) -> i64
+    {
+        self.tardiness + self.capacity_penalty + self.escalation + self.stability - self.weight - self.clustering
+    }
+}
+
+/// The rank of an `ObjectiveValue` under the `ObjectiveMode` of the
+/// `StrategicOptions`, see `StrategicObjective::key`. The fields are compared
+/// in order and the lower key is the better solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ObjectiveKey
+{
+    /// The work orders breaking a hard constraint.
+    pub hard_violations: usize,
+    /// The work orders scheduled after their latest period or left in the
+    /// backlog past it.
+    pub deadline_misses: usize,
+    pub overloaded_work: Work,
+    /// The sum of the `WorkOrderObjective::instability`.
+    pub instability: i64,
+    /// `ObjectiveValue::value`.
+    pub value: i64,
+}
+
+impl ObjectiveValue
+{
+    /// The sum of the values of the work orders.
+    pub fn value(&self) -> i64
+    {
+        self.work_orders.values().map(WorkOrderObjective::value).sum()
+    }
+
+    /// A solution is feasible when no work order breaks a hard constraint.
+    pub fn is_feasible(&self) -> bool
+    {
+        self.work_orders.values().all(|work_order_objective| !work_order_objective.hard_violation)
+    }
+
+    /// The objective summed per period, so that a planner can see which
+    /// period drives the score. The periods are in chronological order
+    /// with the backlog last, and only periods with work orders are
+    /// included. The values of the periods add up to `ObjectiveValue::value`.
+    pub fn periods(&self) -> Vec<PeriodObjective>
+    {
+        let mut periods = BTreeMap::<(bool, Option<Period>), PeriodObjective>::new();
+        for work_order_objective in self.work_orders.values() {
+            let period = work_order_objective.period;
+            let period_objective = periods.entry((period.is_none(), period)).or_insert(PeriodObjective {
+                period,
+                ..PeriodObjective::default()
+            });
+            period_objective.tardiness += work_order_objective.tardiness;
+            period_objective.weight += work_order_objective.weight;
+            period_objective.clustering += work_order_objective.clustering;
+            period_objective.capacity_penalty += work_order_objective.capacity_penalty;
+            period_objective.escalation += work_order_objective.escalation;
+            period_objective.stability += work_order_objective.stability;
+            period_objective.hard_violations += usize::from(work_order_objective.hard_violation);
+        }
+        periods.into_values().collect()
+    }
+
+    /// `ObjectiveValue::periods` as a markdown table.
+    pub fn period_report_markdown(&self) -> String
+    {
+        let mut markdown = String::new();
+
+        writeln!(markdown, "# Strategic Objective by Period\n").unwrap();
+        writeln!(
+            markdown,
+            "| Period | Tardiness | Weight | Clustering | Capacity penalty | Escalation | Stability | Hard violations | Value |"
+        )
+        .unwrap();
+        writeln!(markdown, "|---|---|---|---|---|---|---|---|---|").unwrap();
+        for period_objective in self.periods() {
+            let period = match period_objective.period {
+                Some(period) => period.start_date().to_string(),
+                None => "Backlog".to_string(),
+            };
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+                period,
+                period_objective.tardiness,
+                period_objective.weight,
+                period_objective.clustering,
+                period_objective.capacity_penalty,
+                period_objective.escalation,
+                period_objective.stability,
+                period_objective.hard_violations,
+                period_objective.value()
+            )
+            .unwrap();
+        }
+        writeln!(markdown, "\nTotal: {}", self.value()).unwrap();
+
+        markdown
+    }
+}
+
+impl StrategicObjective
+{
+    pub fn new(strategic_options: StrategicOptions) -> Self
+    {
+        Self {
+            strategic_options,
+            reference_solution: None,
+        }
+    }
+
+    /// Scores the solution against the previously published solution, so
+    /// that the plan does not change more than it has to.
+    pub fn with_reference_solution(mut self, reference_solution: StrategicSolution) -> Self
+    {
+        self.reference_solution = Some(reference_solution);
+        self
+    }
+
+    /// Breaking fewer hard constraints always ranks first. After that
+    /// `ObjectiveMode::WeightedSum` only compares the value, while
+    /// `ObjectiveMode::Lexicographic` compares the deadline misses, the
+    /// overloaded hours and the instability before the value.
+    pub fn key(&self, objective_value: &ObjectiveValue) -> ObjectiveKey
+    {
+        let hard_violations = objective_value
+            .work_orders
+            .values()
+            .filter(|work_order_objective| work_order_objective.hard_violation)
+            .count();
+        match self.strategic_options.objective_mode {
+            ObjectiveMode::WeightedSum => ObjectiveKey {
+                hard_violations,
+                value: objective_value.value(),
+                ..ObjectiveKey::default()
+            },
+            ObjectiveMode::Lexicographic => ObjectiveKey {
+                hard_violations,
+                deadline_misses: objective_value
+                    .work_orders
+                    .values()
+                    .filter(|work_order_objective| work_order_objective.periods_late > 0)
+                    .count(),
+                overloaded_work: objective_value
+                    .work_orders
+                    .values()
+                    .map(|work_order_objective| work_order_objective.overloaded_work)
+                    .sum(),
+                instability: objective_value
+                    .work_orders
+                    .values()
+                    .map(|work_order_objective| work_order_objective.instability)
+                    .sum(),
+                value: objective_value.value(),
+            },
+        }
+    }
+
+    /// Work orders of the solution without a `WorkOrderParameter` are not
+    /// scored.
+    ///
+    /// The capacity is a hard constraint for `ConstraintMode::Hard`, so the
+    /// firm work orders of an overloaded skill in a period are violations
+    /// and carry no capacity penalty. Forecast work orders never load the
+    /// capacity. A work order in one of its excluded periods is always a
+    /// violation.
+    ///
+    /// A work order that moved away from a period of the reference
+    /// solution is penalized by `stability_weight` for that period and for
+    /// every strategic period after it. Work orders that were in the
+    /// backlog of the reference solution, or not in it at all, move freely.
+    pub fn evaluate(&self, strategic_instance: &StrategicInstance, strategic_solution: &StrategicSolution) -> ObjectiveValue
+    {
+        let strategic_options = &self.strategic_options;
+        let overloads = self.overloads(strategic_instance, strategic_solution);
+
+        let mut objective_value = ObjectiveValue::default();
+        for (work_order_number, period) in &strategic_solution.0 {
+            let Some(work_order_parameter) = strategic_instance.strategic_work_order_parameters.get(work_order_number) else {
+                continue;
+            };
+            let mut work_order_objective = WorkOrderObjective {
+                period: *period,
+                ..WorkOrderObjective::default()
+            };
+
+            let periods_late = strategic_instance
+                .strategic_periods
+                .iter()
+                .filter(|strategic_period| **strategic_period > work_order_parameter.latest_period)
+                .filter(|strategic_period| period.is_none_or(|period| **strategic_period <= period))
+                .count() as i64
+                + i64::from(period.is_none());
+            work_order_objective.periods_late = periods_late;
+            work_order_objective.tardiness = strategic_options.tardiness_weight * work_order_parameter.weight * periods_late;
+            work_order_objective.instability = self.closeness_to_execution(strategic_instance, *work_order_number, *period);
+            work_order_objective.stability = strategic_options.stability_weight * work_order_objective.instability;
+
+            let Some(period) = period else {
+                work_order_objective.escalation = strategic_options.escalation_weight * work_order_parameter.carry_over_count as i64;
+                objective_value.work_orders.insert(*work_order_number, work_order_objective);
+                continue;
+            };
+
+            work_order_objective.weight = strategic_options.throughput_weight * work_order_parameter.weight;
+            work_order_objective.clustering = strategic_options.clustering_weight
+                * strategic_solution
+                    .0
+                    .iter()
+                    .filter(|(other_work_order_number, other_period)| {
+                        *other_work_order_number != work_order_number && **other_period == Some(*period)
+                    })
+                    .map(|(other_work_order_number, _)| {
+                        strategic_instance
+                            .strategic_clustering
+                            .value(*work_order_number, *other_work_order_number)
+                    })
+                    .sum::<i64>();
+
+            work_order_objective.hard_violation = work_order_parameter.excluded_periods.contains(period)
+                || (strategic_options.latest_period == ConstraintMode::Hard && periods_late > 0);
+
+            if work_order_parameter.commitment == Commitment::Firm {
+                let mut overloaded_hours = 0.0;
+                for (skill, work) in &work_order_parameter.work_load {
+                    if let Some((load, overload)) = overloads.get(&(*period, *skill)) {
+                        overloaded_hours += overload.hours() * (*work / *load);
+                    }
+                }
+                work_order_objective.overloaded_work = Work::from_hours(overloaded_hours);
+                match strategic_options.capacity {
+                    ConstraintMode::Hard => work_order_objective.hard_violation |= overloaded_hours > 0.0,
+                    ConstraintMode::Soft => {
+                        work_order_objective.capacity_penalty = (strategic_options.capacity_penalty_weight as f64 * overloaded_hours).round() as i64
+                    }
+                }
+            }
+
+            objective_value.work_orders.insert(*work_order_number, work_order_objective);
+        }
+        objective_value
+    }
+
+    /// The number of strategic periods from the period of the work order in
+    /// the reference solution to the end of the horizon, or zero if the
+    /// work order has not moved away from it.
+    fn closeness_to_execution(&self, strategic_instance: &StrategicInstance, work_order_number: WorkOrderNumber, period: Option<Period>) -> i64
+    {
+        let Some(reference_period) = self
+            .reference_solution
+            .as_ref()
+            .and_then(|reference_solution| reference_solution.0.get(&work_order_number).copied().flatten())
+            .filter(|reference_period| Some(*reference_period) != period)
+        else {
+            return 0;
+        };
+        strategic_instance
+            .strategic_periods
+            .iter()
+            .filter(|strategic_period| **strategic_period >= reference_period)
+            .count() as i64
+    }
+
+    /// The firm load and the hours above the capacity of each overloaded
+    /// skill in each period.
+    fn overloads(&self, strategic_instance: &StrategicInstance, strategic_solution: &StrategicSolution) -> BTreeMap<(Period, Skill), (Work, Work)>
+    {
+        let mut load = BTreeMap::<(Period, Skill), Work>::new();
+        for (work_order_number, period) in &strategic_solution.0 {
+            let Some(period) = period else {
+                continue;
+            };
+            let Some(work_order_parameter) = strategic_instance
+                .strategic_work_order_parameters
+                .get(work_order_number)
+                .filter(|work_order_parameter| work_order_parameter.commitment == Commitment::Firm)
+            else {
+                continue;
+            };
+            for (skill, work) in &work_order_parameter.work_load {
+                *load.entry((*period, *skill)).or_default() += *work;
+            }
+        }
+
+        load.into_iter()
+            .filter_map(|((period, skill), work)| {
+                let overload = work - strategic_instance.strategic_capacity.skill_hours(&period, &skill);
+                (overload > Work::ZERO).then_some(((period, skill), (work, overload)))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,94 @@
+use scheduling_environment::Period;
+use scheduling_environment::SystemClock;
+
+use crate::StrategicInstance;
+
+/// Where a strategic period is relative to the current day of the
+/// `SystemClock`. Only the draft periods are planned by the strategic
+/// algorithm, the previous and frozen periods are kept as they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodState
+{
+    /// The period has ended.
+    Previous(Period),
+    /// The period is in progress and its work is handed over to execution.
+    Frozen(Period),
+    /// The first period that starts after the current day.
+    Draft(Period),
+    /// The periods after the `PeriodState::Draft` period.
+    Draft2(Period),
+}
+
+impl PeriodState
+{
+    /// Classifies each of the `periods` by the current day of the
+    /// `SystemClock`. The states are in the order of the `periods`.
+    pub fn classify(periods: &[Period], system_clock: &dyn SystemClock) -> Vec<PeriodState>
+    {
+        let today = system_clock.now().date();
+        let first_draft_start_date = periods.iter().map(Period::start_date).filter(|start_date| *start_date > today).min();
+
+        periods
+            .iter()
+            .map(|period| {
+                if period.end_date() <= today {
+                    PeriodState::Previous(*period)
+                } else if period.contains(&today) {
+                    PeriodState::Frozen(*period)
+                } else if Some(period.start_date()) == first_draft_start_date {
+                    PeriodState::Draft(*period)
+                } else {
+                    PeriodState::Draft2(*period)
+                }
+            })
+            .collect()
+    }
+
+    pub fn period(&self) -> Period
+    {
+        match self {
+            PeriodState::Previous(period) | PeriodState::Frozen(period) | PeriodState::Draft(period) | PeriodState::Draft2(period) => *period,
+        }
+    }
+
+    /// Whether the strategic algorithm may move work into or out of the
+    /// period.
+    pub fn is_draft(&self) -> bool
+    {
+        matches!(self, PeriodState::Draft(_) | PeriodState::Draft2(_))
+    }
+}
+
+impl StrategicInstance
+{
+    /// The `PeriodState` of each of the `strategic_periods`.
+    pub fn period_states(&self, system_clock: &dyn SystemClock) -> Vec<PeriodState>
+    {
+        PeriodState::classify(&self.strategic_periods, system_clock)
+    }
+
+    /// The strategic periods that can be planned. A period in
+    /// `period_locks` is not a draft period, even if it is in the future.
+    pub fn draft_periods(&self, system_clock: &dyn SystemClock) -> Vec<Period>
+    {
+        self.period_states(system_clock)
+            .into_iter()
+            .filter(PeriodState::is_draft)
+            .map(|period_state| period_state.period())
+            .filter(|period| !self.period_locks.contains(period))
+            .collect()
+    }
+
+    /// Adds the previous and frozen periods to the `period_locks`. Every
+    /// solver treats the locks as hard constraints, so a warm started
+    /// solver, see `solver::warm_start`, only re-plans the work in the draft
+    /// periods and the backlog.
+    pub fn lock_settled_periods(&mut self, system_clock: &dyn SystemClock)
+    {
+        for period_state in self.period_states(system_clock) {
+            if !period_state.is_draft() {
+                self.period_locks.insert(period_state.period());
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::thread;
+
+use schedule_hypergraph::sharding::WorkCenterPartition;
+use scheduling_environment::technician::Skill;
+
+use crate::StrategicClustering;
+use crate::StrategicInstance;
+use crate::StrategicResources;
+use crate::StrategicSolution;
+use crate::greedy::settle_solution;
+use crate::solver::StrategicSolver;
+use crate::solver::warm_start;
+
+/// Optimizes every `WorkCenterShard` of the partition on its own thread,
+/// each with a clone of the `solver`, and merges the shard solutions with
+/// `merge_shard_solutions`. The partition comes from
+/// `ScheduleGraph::partition_by_work_center`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShardedSolver<S>
+{
+    pub work_center_partition: WorkCenterPartition,
+    pub solver: S,
+}
+
+impl<S> StrategicSolver for ShardedSolver<S>
+where
+    S: StrategicSolver + Clone + Send,
+{
+    fn solve(&mut self, strategic_instance: &StrategicInstance, initial_solution: StrategicSolution) -> StrategicSolution
+    {
+        let shard_instances = self
+            .work_center_partition
+            .shards
+            .keys()
+            .map(|work_center| strategic_instance.shard(&self.work_center_partition, *work_center))
+            .collect::<Vec<_>>();
+
+        let shard_solutions = thread::scope(|scope| {
+            let shard_solvers = shard_instances
+                .iter()
+                .map(|shard_instance| {
+                    let mut solver = self.solver.clone();
+                    let shard_initial_solution = warm_start(shard_instance, &initial_solution);
+                    scope.spawn(move || solver.solve(shard_instance, shard_initial_solution))
+                })
+                .collect::<Vec<_>>();
+            shard_solvers
+                .into_iter()
+                .map(|shard_solver| shard_solver.join().expect("a shard solver panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        merge_shard_solutions(strategic_instance, &initial_solution, shard_solutions)
+    }
+}
+
+impl StrategicInstance
+{
+    /// The part of the instance that belongs to the `WorkCenterShard` of
+    /// the work center. It has the work orders of the shard, and the hours
+    /// of the work center of both the technicians of the shard and the
+    /// boundary technicians. The periods and the period locks are those of
+    /// the instance.
+    ///
+    /// A boundary technician is in the shard of every work center they
+    /// work in, so the shards together can reserve more of their hours
+    /// than they have. That is the strategic counterpart of
+    /// `ScheduleGraph::boundary_conflicts`, and it is settled by
+    /// `merge_shard_solutions`.
+    pub fn shard(&self, work_center_partition: &WorkCenterPartition, work_center: Skill) -> StrategicInstance
+    {
+        let work_orders = work_center_partition
+            .shards
+            .get(&work_center)
+            .map(|work_center_shard| &work_center_shard.work_orders);
+        let in_shard = |work_order_number| work_orders.is_some_and(|work_orders| work_orders.contains(work_order_number));
+        let technicians = work_center_partition
+            .shards
+            .get(&work_center)
+            .map(|work_center_shard| &work_center_shard.technicians);
+
+        let mut strategic_capacity = StrategicResources::default();
+        for (period, operational_resources) in &self.strategic_capacity.0 {
+            let shard_resources = strategic_capacity.0.entry(*period).or_default();
+            for (technician_id, operational_resource) in operational_resources {
+                let Some(skill_hours) = operational_resource.skill_hours.get(&work_center) else {
+                    continue;
+                };
+                if !technicians.is_some_and(|technicians| technicians.contains(technician_id))
+                    && !work_center_partition.boundary_technicians.contains(technician_id)
+                {
+                    continue;
+                }
+                let mut shard_resource = operational_resource.clone();
+                shard_resource.skill_hours = HashMap::from([(work_center, *skill_hours)]);
+                shard_resource.reserved_hours.retain(|skill, _| *skill == work_center);
+                shard_resources.insert(*technician_id, shard_resource);
+            }
+        }
+
+        StrategicInstance {
+            strategic_work_order_parameters: self
+                .strategic_work_order_parameters
+                .iter()
+                .filter(|(work_order_number, _)| in_shard(work_order_number))
+                .map(|(work_order_number, work_order_parameter)| (*work_order_number, work_order_parameter.clone()))
+                .collect(),
+            strategic_capacity,
+            strategic_clustering: StrategicClustering(
+                self.strategic_clustering
+                    .0
+                    .iter()
+                    .filter(|((work_order_number, other_work_order_number), _)| in_shard(work_order_number) && in_shard(other_work_order_number))
+                    .map(|(work_order_pair, value)| (*work_order_pair, *value))
+                    .collect(),
+            ),
+            period_locks: self.period_locks.clone(),
+            strategic_periods: self.strategic_periods.clone(),
+        }
+    }
+}
+
+/// Puts the solutions of the shards together into a solution of the whole
+/// instance. The boundary work orders, which are in no shard, start in
+/// their period of the initial solution. The merged solution is then
+/// settled with `settle_solution` against the capacity of the whole
+/// instance: the work orders that overbook the boundary technicians are
+/// moved to the earliest period where they fit, or to the backlog, and the
+/// rest stay where their shard put them.
+pub fn merge_shard_solutions(
+    strategic_instance: &StrategicInstance,
+    initial_solution: &StrategicSolution,
+    shard_solutions: impl IntoIterator<Item = StrategicSolution>,
+) -> StrategicSolution
+{
+    let mut merged_solution = warm_start(strategic_instance, initial_solution);
+    for shard_solution in shard_solutions {
+        for (work_order_number, period) in shard_solution.0 {
+            if let Some(merged_period) = merged_solution.0.get_mut(&work_order_number) {
+                *merged_period = period;
+            }
+        }
+    }
+    settle_solution(strategic_instance, &merged_solution)
+}
@@ -0,0 +1,391 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use rand::Rng;
+use rand::RngCore;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::StrategicSolution;
+use crate::objective::ObjectiveKey;
+use crate::objective::ObjectiveValue;
+use crate::objective::StrategicObjective;
+use crate::solver::Move;
+use crate::solver::Neighborhood;
+use crate::solver::StrategicSolver;
+
+/// Improves a `StrategicSolution`, typically the one of
+/// `greedy::construct_initial_solution`, by simulated annealing over the
+/// `StrategicObjective`, see `StrategicScheduler::SimulatedAnnealing`.
+///
+/// Every iteration proposes a random `AnnealingMove`. A move that breaks
+/// more hard constraints is rejected, one that breaks fewer is accepted,
+/// and otherwise a move that worsens the objective by `delta` is accepted
+/// with probability `exp(-delta / temperature)`. With
+/// `ObjectiveMode::Lexicographic` the goals ranked before the value are
+/// treated like the hard constraints. The best solution found is
+/// written back. The same seed, budget and input give the same solution,
+/// unless the `time_budget` runs out first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedAnnealing
+{
+    pub initial_temperature: f64,
+    pub cooling_schedule: CoolingSchedule,
+    pub seed: u64,
+    pub iteration_budget: usize,
+    /// Stops the search early, also when iterations are left.
+    pub time_budget: Option<Duration>,
+}
+
+/// How the temperature decreases with the iterations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoolingSchedule
+{
+    /// The temperature is multiplied by `alpha` after every iteration.
+    Geometric
+    {
+        alpha: f64
+    },
+    /// The temperature reaches zero at the end of the iteration budget.
+    Linear,
+}
+
+/// The moves of the `PeriodNeighborhood`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnealingMove
+{
+    /// Moves the work order to another draft period or to the backlog.
+    ShiftPeriod
+    {
+        work_order_number: WorkOrderNumber,
+        to: Option<Period>,
+    },
+    /// Exchanges the periods of two work orders.
+    Swap
+    {
+        work_order_number: WorkOrderNumber,
+        other_work_order_number: WorkOrderNumber,
+    },
+}
+
+/// The result of `SimulatedAnnealing::optimize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnealingOutcome
+{
+    /// The objective of the best solution.
+    pub objective_value: ObjectiveValue,
+    pub iterations: usize,
+    pub accepted_moves: usize,
+}
+
+impl Default for SimulatedAnnealing
+{
+    fn default() -> Self
+    {
+        Self {
+            initial_temperature: 100.0,
+            cooling_schedule: CoolingSchedule::Geometric { alpha: 0.999 },
+            seed: 0,
+            iteration_budget: 10_000,
+            time_budget: None,
+        }
+    }
+}
+
+impl SimulatedAnnealing
+{
+    /// Searches the `PeriodNeighborhood`. Work orders locked in a period are
+    /// never moved. Periods in `StrategicInstance::period_locks` are not
+    /// draft periods, so work is neither moved into nor out of them, and no
+    /// work order is moved into one of its excluded periods.
+    pub fn optimize(
+        &self,
+        strategic_instance: &StrategicInstance,
+        strategic_objective: &StrategicObjective,
+        strategic_solution: &mut StrategicSolution,
+    ) -> AnnealingOutcome
+    {
+        self.optimize_with(&PeriodNeighborhood, strategic_instance, strategic_objective, strategic_solution)
+    }
+
+    /// Searches a custom `Neighborhood`. The search stops early if the
+    /// neighborhood of the current solution is empty.
+    pub fn optimize_with<N: Neighborhood>(
+        &self,
+        neighborhood: &N,
+        strategic_instance: &StrategicInstance,
+        strategic_objective: &StrategicObjective,
+        strategic_solution: &mut StrategicSolution,
+    ) -> AnnealingOutcome
+    {
+        let started_at = Instant::now();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        let mut current_value = strategic_objective.evaluate(strategic_instance, strategic_solution);
+        let mut best_solution = strategic_solution.clone();
+        let mut best_value = current_value.clone();
+        let mut temperature = self.initial_temperature;
+        let mut annealing_outcome = AnnealingOutcome {
+            objective_value: ObjectiveValue::default(),
+            iterations: 0,
+            accepted_moves: 0,
+        };
+
+        while annealing_outcome.iterations < self.iteration_budget && self.time_budget.is_none_or(|time_budget| started_at.elapsed() < time_budget) {
+            let Some(neighborhood_move) = neighborhood.random_move(&mut rng, strategic_instance, strategic_solution) else {
+                break;
+            };
+            annealing_outcome.iterations += 1;
+
+            let mut candidate_solution = strategic_solution.clone();
+            if neighborhood_move.apply(strategic_instance, &mut candidate_solution) {
+                let candidate_value = strategic_objective.evaluate(strategic_instance, &candidate_solution);
+                if self.accept(
+                    &mut rng,
+                    strategic_objective.key(&current_value),
+                    strategic_objective.key(&candidate_value),
+                    temperature,
+                ) {
+                    annealing_outcome.accepted_moves += 1;
+                    *strategic_solution = candidate_solution;
+                    current_value = candidate_value;
+                    if strategic_objective.key(&current_value) < strategic_objective.key(&best_value) {
+                        best_solution = strategic_solution.clone();
+                        best_value = current_value.clone();
+                    }
+                }
+            }
+
+            temperature = match self.cooling_schedule {
+                CoolingSchedule::Geometric { alpha } => temperature * alpha,
+                CoolingSchedule::Linear => self.initial_temperature * (1.0 - annealing_outcome.iterations as f64 / self.iteration_budget as f64),
+            };
+        }
+
+        *strategic_solution = best_solution;
+        annealing_outcome.objective_value = best_value;
+        annealing_outcome
+    }
+
+    fn accept(&self, rng: &mut StdRng, current_key: ObjectiveKey, candidate_key: ObjectiveKey, temperature: f64) -> bool
+    {
+        let goals = |objective_key: ObjectiveKey| {
+            (
+                objective_key.hard_violations,
+                objective_key.deadline_misses,
+                objective_key.overloaded_work,
+                objective_key.instability,
+            )
+        };
+        if goals(candidate_key) != goals(current_key) {
+            return goals(candidate_key) < goals(current_key);
+        }
+
+        let delta = (candidate_key.value - current_key.value) as f64;
+        delta <= 0.0 || (temperature > 0.0 && rng.random::<f64>() < (-delta / temperature).exp())
+    }
+}
+
+/// Moves a work order to another draft period or to the backlog, or swaps
+/// the periods of two work orders, with equal probability. Work orders
+/// locked in a period or placed in a period of
+/// `StrategicInstance::period_locks` are not moved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PeriodNeighborhood;
+
+impl Neighborhood for PeriodNeighborhood
+{
+    type Move = AnnealingMove;
+
+    fn random_move(
+        &self,
+        rng: &mut dyn RngCore,
+        strategic_instance: &StrategicInstance,
+        strategic_solution: &StrategicSolution,
+    ) -> Option<AnnealingMove>
+    {
+        let movable_work_orders = movable_work_orders(strategic_instance, strategic_solution);
+        if movable_work_orders.is_empty() {
+            return None;
+        }
+        let work_order_number = movable_work_orders[rng.random_range(0..movable_work_orders.len())];
+        Some(random_move_of(rng, strategic_instance, work_order_number, &movable_work_orders))
+    }
+}
+
+/// `PeriodNeighborhood` that draws the work order to move with a
+/// probability proportional to how much it hurts the objective, so that
+/// the search spends its iterations where the solution is worst on large
+/// instances. The weight of a work order is one plus its weighted
+/// tardiness and capacity penalty in the `StrategicObjective`. The other
+/// work order of a swap and the period of a shift are drawn uniformly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedPeriodNeighborhood
+{
+    pub strategic_objective: StrategicObjective,
+}
+
+impl Neighborhood for WeightedPeriodNeighborhood
+{
+    type Move = AnnealingMove;
+
+    fn random_move(
+        &self,
+        rng: &mut dyn RngCore,
+        strategic_instance: &StrategicInstance,
+        strategic_solution: &StrategicSolution,
+    ) -> Option<AnnealingMove>
+    {
+        let movable_work_orders = movable_work_orders(strategic_instance, strategic_solution);
+        if movable_work_orders.is_empty() {
+            return None;
+        }
+
+        let objective_value = self.strategic_objective.evaluate(strategic_instance, strategic_solution);
+        let weights = movable_work_orders
+            .iter()
+            .map(|work_order_number| {
+                1 + objective_value.work_orders.get(work_order_number).map_or(0, |work_order_objective| {
+                    work_order_objective.tardiness.max(0) + work_order_objective.capacity_penalty.max(0)
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut drawn_weight = rng.random_range(0..weights.iter().sum::<i64>());
+        let position = weights
+            .iter()
+            .position(|weight| {
+                drawn_weight -= weight;
+                drawn_weight < 0
+            })
+            .expect("the drawn weight is below the total weight");
+
+        Some(random_move_of(
+            rng,
+            strategic_instance,
+            movable_work_orders[position],
+            &movable_work_orders,
+        ))
+    }
+}
+
+/// The work orders that are neither locked in a period nor placed in a
+/// period of `StrategicInstance::period_locks`. Sorted, so that the rng
+/// alone decides the moves.
+fn movable_work_orders(strategic_instance: &StrategicInstance, strategic_solution: &StrategicSolution) -> Vec<WorkOrderNumber>
+{
+    let mut movable_work_orders = strategic_solution
+        .0
+        .iter()
+        .filter(|(work_order_number, period)| {
+            strategic_instance
+                .strategic_work_order_parameters
+                .get(work_order_number)
+                .is_some_and(|work_order_parameter| work_order_parameter.locked_in_period.is_none())
+                && period.is_none_or(|period| !strategic_instance.period_locks.contains(&period))
+        })
+        .map(|(work_order_number, _)| *work_order_number)
+        .collect::<Vec<_>>();
+    movable_work_orders.sort();
+    movable_work_orders
+}
+
+/// Swaps the work order with another movable work order, or shifts it to
+/// a draft period or the backlog, with equal probability.
+fn random_move_of(
+    rng: &mut dyn RngCore,
+    strategic_instance: &StrategicInstance,
+    work_order_number: WorkOrderNumber,
+    movable_work_orders: &[WorkOrderNumber],
+) -> AnnealingMove
+{
+    if movable_work_orders.len() > 1 && rng.random_bool(0.5) {
+        let other_work_order_number = movable_work_orders[rng.random_range(0..movable_work_orders.len())];
+        return AnnealingMove::Swap {
+            work_order_number,
+            other_work_order_number,
+        };
+    }
+
+    let mut draft_periods = strategic_instance
+        .strategic_periods
+        .iter()
+        .filter(|period| !strategic_instance.period_locks.contains(period))
+        .copied()
+        .collect::<Vec<_>>();
+    draft_periods.sort();
+    // The backlog is the last option.
+    let target = rng.random_range(0..=draft_periods.len());
+    AnnealingMove::ShiftPeriod {
+        work_order_number,
+        to: draft_periods.get(target).copied(),
+    }
+}
+
+/// A work order can go in the backlog and in the periods that are neither
+/// locked nor excluded.
+fn allowed(strategic_instance: &StrategicInstance, work_order_number: &WorkOrderNumber, period: &Option<Period>) -> bool
+{
+    let Some(work_order_parameter) = strategic_instance.strategic_work_order_parameters.get(work_order_number) else {
+        return false;
+    };
+    period.is_none_or(|period| !strategic_instance.period_locks.contains(&period) && !work_order_parameter.excluded_periods.contains(&period))
+}
+
+impl Move for AnnealingMove
+{
+    fn apply(&self, strategic_instance: &StrategicInstance, strategic_solution: &mut StrategicSolution) -> bool
+    {
+        match self {
+            AnnealingMove::ShiftPeriod { work_order_number, to } => {
+                let Some(period) = strategic_solution.0.get(work_order_number) else {
+                    return false;
+                };
+                if period == to || !allowed(strategic_instance, work_order_number, to) {
+                    return false;
+                }
+                strategic_solution.0.insert(*work_order_number, *to);
+            }
+            AnnealingMove::Swap {
+                work_order_number,
+                other_work_order_number,
+            } => {
+                let (Some(period), Some(other_period)) = (
+                    strategic_solution.0.get(work_order_number).copied(),
+                    strategic_solution.0.get(other_work_order_number).copied(),
+                ) else {
+                    return false;
+                };
+                if period == other_period
+                    || !allowed(strategic_instance, work_order_number, &other_period)
+                    || !allowed(strategic_instance, other_work_order_number, &period)
+                {
+                    return false;
+                }
+                strategic_solution.0.insert(*work_order_number, other_period);
+                strategic_solution.0.insert(*other_work_order_number, period);
+            }
+        }
+        true
+    }
+}
+
+/// `SimulatedAnnealing` over a `StrategicObjective` as a `StrategicSolver`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnealingSolver
+{
+    pub simulated_annealing: SimulatedAnnealing,
+    pub strategic_objective: StrategicObjective,
+}
+
+impl StrategicSolver for AnnealingSolver
+{
+    fn solve(&mut self, strategic_instance: &StrategicInstance, mut initial_solution: StrategicSolution) -> StrategicSolution
+    {
+        self.simulated_annealing
+            .optimize(strategic_instance, &self.strategic_objective, &mut initial_solution);
+        initial_solution
+    }
+}
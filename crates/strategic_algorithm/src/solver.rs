@@ -0,0 +1,75 @@
+use rand::RngCore;
+
+use crate::StrategicInstance;
+use crate::StrategicSolution;
+use crate::capacity_smoothing::CapacitySmoothing;
+use crate::greedy::GreedySolver;
+use crate::objective::StrategicObjective;
+use crate::simulated_annealing::AnnealingSolver;
+use crate::simulated_annealing::SimulatedAnnealing;
+use crate::strategic_options::StrategicOptions;
+use crate::strategic_options::StrategicScheduler;
+
+/// Turns a `StrategicInstance` and an initial solution into a new solution.
+/// Implement it to plug in another solver, e.g. tabu search, large
+/// neighborhood search or an external MIP solver. The built-in solvers are
+/// `GreedySolver`, `AnnealingSolver` and `CapacitySmoothing`.
+pub trait StrategicSolver
+{
+    fn solve(&mut self, strategic_instance: &StrategicInstance, initial_solution: StrategicSolution) -> StrategicSolution;
+}
+
+/// A change of a `StrategicSolution`.
+pub trait Move
+{
+    /// Returns false, and leaves the solution unchanged, if the move
+    /// changes nothing or is not allowed by the instance.
+    fn apply(&self, strategic_instance: &StrategicInstance, strategic_solution: &mut StrategicSolution) -> bool;
+}
+
+/// The moves that a local search can make from a solution, see
+/// `SimulatedAnnealing::optimize_with`.
+pub trait Neighborhood
+{
+    type Move: Move;
+
+    /// A random move from the solution, or `None` if the neighborhood is
+    /// empty. The moves only depend on the `rng`, so a seeded `rng` makes
+    /// the search reproducible.
+    fn random_move(
+        &self,
+        rng: &mut dyn RngCore,
+        strategic_instance: &StrategicInstance,
+        strategic_solution: &StrategicSolution,
+    ) -> Option<Self::Move>;
+}
+
+/// The initial solution of a partial re-optimization. The previous solution
+/// is restricted to the work orders of the instance, and new work orders
+/// start in the backlog. Together with
+/// `StrategicInstance::lock_settled_periods` the work in the locked periods
+/// is kept as it is, which keeps the plan from changing more than it has
+/// to.
+pub fn warm_start(strategic_instance: &StrategicInstance, previous_solution: &StrategicSolution) -> StrategicSolution
+{
+    StrategicSolution(
+        strategic_instance
+            .strategic_work_order_parameters
+            .keys()
+            .map(|work_order_number| (*work_order_number, previous_solution.0.get(work_order_number).copied().flatten()))
+            .collect(),
+    )
+}
+
+/// The built-in solver of the `StrategicScheduler` of the options.
+pub fn built_in_solver(strategic_options: &StrategicOptions) -> Box<dyn StrategicSolver>
+{
+    match strategic_options.scheduler {
+        StrategicScheduler::Greedy => Box::new(GreedySolver),
+        StrategicScheduler::SimulatedAnnealing => Box::new(AnnealingSolver {
+            simulated_annealing: SimulatedAnnealing::default(),
+            strategic_objective: StrategicObjective::new(strategic_options.clone()),
+        }),
+        StrategicScheduler::CapacitySmoothing => Box::new(CapacitySmoothing::default()),
+    }
+}
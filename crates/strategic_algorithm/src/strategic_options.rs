@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+/// Tuning of the strategic algorithm. Start from a `StrategicPreset` and
+/// override single fields with struct update syntax:
+///
+/// ```
+/// use strategic_algorithm::strategic_options::StrategicOptions;
+/// use strategic_algorithm::strategic_options::StrategicPreset;
+///
+/// let strategic_options = StrategicOptions {
+///     clustering_weight: 0,
+///     ..StrategicOptions::preset(StrategicPreset::StabilityFirst)
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategicOptions
+{
+    /// Penalty per period that a work order is scheduled after its latest
+    /// period.
+    pub tardiness_weight: i64,
+    /// Reward per scheduled work order. This is what drives work out of the
+    /// backlog.
+    pub throughput_weight: i64,
+    /// Penalty per work order that moved compared to the previous solution.
+    pub stability_weight: i64,
+    /// Penalty per carry-over of a work order that is left unscheduled, so
+    /// that chronic slippers are escalated.
+    pub escalation_weight: i64,
+    /// Reward for scheduling work orders sharing a location in the same period.
+    pub clustering_weight: i64,
+    /// The factor between the weights of two adjacent `Priority`s, see
+    /// `WorkOrderParameter::derive_weight`.
+    pub priority_weight: i64,
+    /// Weight added per week that a work order has waited since its basic
+    /// start when the strategic periods begin.
+    pub age_weight: i64,
+    /// Multiplier per strategic period that ends after the due date of a
+    /// work order.
+    pub due_weight: i64,
+    /// Multiplier of the weight of HSE-critical work orders.
+    pub safety_weight_boost: i64,
+    /// Penalty per hour of load above the `StrategicResources` capacity. Only
+    /// used when `capacity` is `ConstraintMode::Soft`.
+    pub capacity_penalty_weight: i64,
+    pub capacity: ConstraintMode,
+    pub excluded_periods: ConstraintMode,
+    pub latest_period: ConstraintMode,
+    pub objective_mode: ObjectiveMode,
+    pub scheduler: StrategicScheduler,
+}
+
+/// Whether a constraint rejects a solution or only adds a penalty to the
+/// objective.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintMode
+{
+    Hard,
+    Soft,
+}
+
+/// How the `StrategicObjective` ranks two solutions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveMode
+{
+    /// Rank by the sum of the weighted terms.
+    WeightedSum,
+    /// Rank by the work orders that miss their latest period, then by the
+    /// overloaded hours, then by the instability, and only then by the sum
+    /// of the weighted terms, see `ObjectiveKey`. For planners that want a
+    /// strict priority of the goals instead of tuning weights.
+    Lexicographic,
+}
+
+/// The solver used by the strategic algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategicScheduler
+{
+    /// Place each work order in its earliest feasible period.
+    Greedy,
+    /// Improve the greedy solution with simulated annealing.
+    SimulatedAnnealing,
+    /// Shift work orders among the draft periods to flatten the per-skill
+    /// utilization peaks, see `CapacitySmoothing`.
+    CapacitySmoothing,
+}
+
+/// Named bundles of `StrategicOptions` so that the weights do not have to be
+/// tuned from scratch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategicPreset
+{
+    /// Keep the previous solution unless there is a good reason to move.
+    StabilityFirst,
+    /// Schedule as much work as the capacity allows, accepting overload.
+    ThroughputFirst,
+    /// Schedule every work order before its latest period.
+    DeadlineFirst,
+}
+
+impl StrategicOptions
+{
+    pub fn preset(strategic_preset: StrategicPreset) -> Self
+    {
+        match strategic_preset {
+            StrategicPreset::StabilityFirst => Self {
+                tardiness_weight: 10,
+                throughput_weight: 1,
+                stability_weight: 100,
+                escalation_weight: 10,
+                clustering_weight: 5,
+                priority_weight: 10,
+                age_weight: 0,
+                due_weight: 1,
+                safety_weight_boost: 10,
+                capacity_penalty_weight: 50,
+                capacity: ConstraintMode::Hard,
+                excluded_periods: ConstraintMode::Hard,
+                latest_period: ConstraintMode::Soft,
+                objective_mode: ObjectiveMode::WeightedSum,
+                scheduler: StrategicScheduler::Greedy,
+            },
+            StrategicPreset::ThroughputFirst => Self {
+                tardiness_weight: 10,
+                throughput_weight: 100,
+                stability_weight: 1,
+                escalation_weight: 20,
+                clustering_weight: 10,
+                priority_weight: 10,
+                age_weight: 1,
+                due_weight: 1,
+                safety_weight_boost: 10,
+                capacity_penalty_weight: 20,
+                capacity: ConstraintMode::Soft,
+                excluded_periods: ConstraintMode::Hard,
+                latest_period: ConstraintMode::Soft,
+                objective_mode: ObjectiveMode::WeightedSum,
+                scheduler: StrategicScheduler::SimulatedAnnealing,
+            },
+            StrategicPreset::DeadlineFirst => Self {
+                tardiness_weight: 100,
+                throughput_weight: 10,
+                stability_weight: 5,
+                escalation_weight: 50,
+                clustering_weight: 1,
+                priority_weight: 10,
+                age_weight: 0,
+                due_weight: 1,
+                safety_weight_boost: 10,
+                capacity_penalty_weight: 50,
+                capacity: ConstraintMode::Hard,
+                excluded_periods: ConstraintMode::Hard,
+                latest_period: ConstraintMode::Hard,
+                objective_mode: ObjectiveMode::WeightedSum,
+                scheduler: StrategicScheduler::SimulatedAnnealing,
+            },
+        }
+    }
+}
+
+impl Default for StrategicOptions
+{
+    fn default() -> Self
+    {
+        Self::preset(StrategicPreset::DeadlineFirst)
+    }
+}
+
+impl FromStr for StrategicPreset
+{
+    type Err = String;
+
+    /// Parses the preset names used in configuration files, e.g.
+    /// "stability-first".
+    fn from_str(name: &str) -> Result<Self, Self::Err>
+    {
+        match name {
+            "stability-first" => Ok(StrategicPreset::StabilityFirst),
+            "throughput-first" => Ok(StrategicPreset::ThroughputFirst),
+            "deadline-first" => Ok(StrategicPreset::DeadlineFirst),
+            _ => Err(format!("unknown strategic preset: {name}")),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use schedule_hypergraph::unscheduled::UnscheduledReason;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::StrategicSolution;
+
+/// Why each work order in the backlog of the solution was not placed, to
+/// be kept with `ScheduleGraph::record_unscheduled_reasons`. The periods a
+/// work order may go in follow the rules of `construct_initial_solution`,
+/// and the capacity of a period is what is left after the firm work of the
+/// solution. The dependencies between work orders are read from the
+/// `ScheduleGraph`.
+pub fn unscheduled_reasons(
+    schedule_graph: &ScheduleGraph,
+    strategic_instance: &StrategicInstance,
+    strategic_solution: &StrategicSolution,
+) -> Result<BTreeMap<WorkOrderNumber, Vec<UnscheduledReason>>, ScheduleGraphErrors>
+{
+    let in_backlog = |work_order_number: &WorkOrderNumber| strategic_solution.0.get(work_order_number).copied().flatten().is_none();
+
+    let mut load = HashMap::<(Period, Skill), Work>::new();
+    for (work_order_number, period) in &strategic_solution.0 {
+        let Some(period) = period else {
+            continue;
+        };
+        let Some(work_order_parameter) = strategic_instance
+            .strategic_work_order_parameters
+            .get(work_order_number)
+            .filter(|work_order_parameter| work_order_parameter.commitment == Commitment::Firm)
+        else {
+            continue;
+        };
+        for (skill, work) in &work_order_parameter.work_load {
+            *load.entry((*period, *skill)).or_default() += *work;
+        }
+    }
+
+    let mut periods = strategic_instance.strategic_periods.clone();
+    periods.sort();
+
+    let mut unscheduled_reasons = BTreeMap::new();
+    for (work_order_number, work_order_parameter) in &strategic_instance.strategic_work_order_parameters {
+        if !in_backlog(work_order_number) {
+            continue;
+        }
+
+        let mut reasons = schedule_graph
+            .work_order_predecessors(*work_order_number)?
+            .into_iter()
+            .filter(|predecessor| strategic_instance.strategic_work_order_parameters.contains_key(predecessor) && in_backlog(predecessor))
+            .map(UnscheduledReason::WaitingOnPredecessor)
+            .collect::<Vec<_>>();
+
+        let candidate_periods = periods
+            .iter()
+            .filter(|period| {
+                (work_order_parameter.safety_critical || !strategic_instance.period_locks.contains(period))
+                    && !work_order_parameter.excluded_periods.contains(period)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        if candidate_periods.is_empty() {
+            reasons.push(UnscheduledReason::Excluded);
+        } else if work_order_parameter.commitment == Commitment::Firm {
+            let mut work_load = work_order_parameter.work_load.iter().collect::<Vec<_>>();
+            work_load.sort();
+            for (skill, work) in work_load {
+                let periods = candidate_periods
+                    .iter()
+                    .filter(|period| {
+                        let remaining_hours = strategic_instance.strategic_capacity.skill_hours(period, skill)
+                            - load.get(&(**period, *skill)).copied().unwrap_or_default();
+                        *work > remaining_hours
+                    })
+                    .copied()
+                    .collect::<Vec<_>>();
+                if !periods.is_empty() {
+                    reasons.push(UnscheduledReason::NoSkillCapacity { skill: *skill, periods });
+                }
+            }
+        }
+        unscheduled_reasons.insert(*work_order_number, reasons);
+    }
+    Ok(unscheduled_reasons)
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+
+use crate::OperationalResource;
+use crate::StrategicResources;
+
+/// The hours of each work center in each period. The `Skill` of a
+/// technician is the work center that they work in, and a technician with
+/// several skills counts in each of their work centers.
+///
+/// This is the matrix that the strategic optimizer works on. The
+/// individual technicians stay in the `StrategicResources` that the view
+/// was aggregated from, see `WorkCenterResources::drill_down`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct WorkCenterResources(pub HashMap<Period, HashMap<Skill, Work>>);
+
+impl From<&StrategicResources> for WorkCenterResources
+{
+    fn from(strategic_resources: &StrategicResources) -> Self
+    {
+        let mut work_center_resources = WorkCenterResources::default();
+        for (period, operational_resources) in &strategic_resources.0 {
+            let work_centers = work_center_resources.0.entry(*period).or_default();
+            for operational_resource in operational_resources.values() {
+                for (skill, skill_hours) in &operational_resource.skill_hours {
+                    *work_centers.entry(*skill).or_default() += *skill_hours;
+                }
+            }
+        }
+        work_center_resources
+    }
+}
+
+impl WorkCenterResources
+{
+    pub fn hours(&self, period: &Period, skill: &Skill) -> Work
+    {
+        self.0
+            .get(period)
+            .and_then(|work_centers| work_centers.get(skill))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The technicians of the work center in the period, sorted by their id.
+    pub fn drill_down<'a>(&self, period: &Period, skill: &Skill, strategic_resources: &'a StrategicResources) -> Vec<&'a OperationalResource>
+    {
+        let mut operational_resources = strategic_resources
+            .0
+            .get(period)
+            .into_iter()
+            .flat_map(|operational_resources| operational_resources.values())
+            .filter(|operational_resource| operational_resource.skill_hours.contains_key(skill))
+            .collect::<Vec<_>>();
+        operational_resources.sort_by_key(|operational_resource| operational_resource.id);
+        operational_resources
+    }
+
+    /// Distributes the hours of each work center back onto the technicians
+    /// of `strategic_resources`, in proportion to their current skill hours.
+    /// The `total_hours` of a technician become the hours of their largest
+    /// work center.
+    ///
+    /// A work center whose technicians have no hours keeps none, as there
+    /// is nobody to distribute them to.
+    pub fn to_strategic_resources(&self, strategic_resources: &StrategicResources) -> StrategicResources
+    {
+        let current_work_center_resources = WorkCenterResources::from(strategic_resources);
+
+        let mut distributed_resources = strategic_resources.clone();
+        for (period, operational_resources) in &mut distributed_resources.0 {
+            for operational_resource in operational_resources.values_mut() {
+                for (skill, skill_hours) in &mut operational_resource.skill_hours {
+                    let current_hours = current_work_center_resources.hours(period, skill);
+                    if current_hours > Work::ZERO {
+                        *skill_hours = Work::from_hours(skill_hours.hours() * (self.hours(period, skill) / current_hours));
+                    }
+                }
+                operational_resource.total_hours = operational_resource.skill_hours.values().copied().max().unwrap_or_default();
+            }
+        }
+        distributed_resources
+    }
+}
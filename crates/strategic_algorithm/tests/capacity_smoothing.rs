@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use schedule_hypergraph::fixtures;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::capacity_smoothing::CapacityMove;
+use strategic_algorithm::capacity_smoothing::CapacitySmoothing;
+
+#[test]
+fn test_capacity_smoothing()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    // 40 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_parameter = |weight: i64, latest_period: Period| WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period,
+        weight,
+        carry_over_count: 0,
+        commitment: Commitment::Firm,
+        safety_critical: false,
+        work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(20.0))]),
+    };
+    let mut strategic_work_order_parameters = HashMap::from([
+        (WorkOrderNumber::new(1122334455).unwrap(), work_order_parameter(1, periods[2])),
+        (WorkOrderNumber::new(1122334466).unwrap(), work_order_parameter(1, periods[0])),
+        (WorkOrderNumber::new(1122334477).unwrap(), work_order_parameter(100, periods[2])),
+        (WorkOrderNumber::new(1122334488).unwrap(), work_order_parameter(1, periods[2])),
+    ]);
+    strategic_work_order_parameters
+        .get_mut(&WorkOrderNumber::new(1122334488).unwrap())
+        .unwrap()
+        .excluded_periods = HashSet::from([periods[1]]);
+    // Forecast work does not load the first period any further.
+    strategic_work_order_parameters.insert(
+        WorkOrderNumber::new(1122334499).unwrap(),
+        WorkOrderParameter {
+            commitment: Commitment::Forecast,
+            ..work_order_parameter(1, periods[2])
+        },
+    );
+
+    // The third period is locked, so it is not a draft period.
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters,
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::from([periods[2]]),
+        strategic_periods: periods.clone(),
+    };
+
+    // The first period is loaded to 200 percent.
+    let mut strategic_solution = StrategicSolution(HashMap::from([
+        (WorkOrderNumber::new(1122334455).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334466).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334477).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334488).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334499).unwrap(), Some(periods[0])),
+    ]));
+
+    let capacity_smoothing = CapacitySmoothing { urgent_weight: 100 };
+    let capacity_moves = capacity_smoothing.smooth(&strategic_instance, &mut strategic_solution);
+
+    // 1122334466 is due in the first period, 1122334477 is urgent and
+    // 1122334488 is excluded from the second period.
+    assert_eq!(
+        capacity_moves,
+        vec![CapacityMove {
+            work_order_number: WorkOrderNumber::new(1122334455).unwrap(),
+            from: periods[0],
+            to: periods[1],
+        }]
+    );
+    assert_eq!(strategic_solution.0[&WorkOrderNumber::new(1122334455).unwrap()], Some(periods[1]));
+    assert_eq!(strategic_solution.0[&WorkOrderNumber::new(1122334488).unwrap()], Some(periods[0]));
+    assert_eq!(strategic_solution.0[&WorkOrderNumber::new(1122334499).unwrap()], Some(periods[0]));
+
+    // The solution is level, so smoothing again does nothing.
+    assert_eq!(capacity_smoothing.smooth(&strategic_instance, &mut strategic_solution), vec![]);
+}
@@ -0,0 +1,139 @@
+use schedule_hypergraph::fixtures;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::solver::built_in_solver;
+use strategic_algorithm::solver::warm_start;
+use strategic_algorithm::strategic_options::StrategicOptions;
+use strategic_algorithm::strategic_options::StrategicPreset;
+use strategic_algorithm::strategic_options::StrategicScheduler;
+
+#[test]
+fn test_mid_size_instance_composition()
+{
+    let schedule_graph = fixtures::mid_size_instance();
+
+    let instance_report = schedule_graph.instance_report();
+
+    assert_eq!(instance_report.periods, fixtures::periods());
+    assert_eq!(instance_report.technicians.len(), fixtures::TECHNICIAN_COUNT);
+    assert_eq!(instance_report.work_order_count(), fixtures::WORK_ORDER_COUNT);
+    assert_eq!(instance_report.work_orders_by_period.get(&None), None);
+    assert_eq!(
+        instance_report.assignment_count,
+        fixtures::WORK_ORDER_COUNT.div_ceil(fixtures::ASSIGNED_WORK_ORDER_STEP)
+    );
+    assert_eq!(
+        instance_report.exclusion_count,
+        fixtures::WORK_ORDER_COUNT / fixtures::EXCLUDED_WORK_ORDER_STEP
+    );
+
+    assert_eq!(schedule_graph.detect_conflicts(), vec![]);
+    assert!(schedule_graph.validate().is_valid());
+
+    for work_order_number in fixtures::work_order_numbers() {
+        assert_eq!(schedule_graph.diagnose_infeasibility(work_order_number), Ok(None));
+    }
+}
+
+#[test]
+fn test_mid_size_instance_strategic_resources()
+{
+    let schedule_graph = fixtures::mid_size_instance();
+    let periods = fixtures::periods();
+
+    let capacity_calendar = schedule_graph.capacity_calendar(&periods).unwrap();
+    let strategic_resources = StrategicResources::from_schedule_graph(&schedule_graph, &periods).unwrap();
+
+    assert_eq!(strategic_resources.0.len(), fixtures::PERIOD_COUNT);
+    for period in &periods {
+        let operational_resources = &strategic_resources.0[period];
+        assert_eq!(operational_resources.len(), fixtures::TECHNICIAN_COUNT);
+
+        let skill_hours = |skill: Skill| -> Work {
+            operational_resources
+                .values()
+                .filter_map(|operational_resource| operational_resource.skill_hours.get(&skill))
+                .sum()
+        };
+        assert!(skill_hours(Skill::MTN_MECH) > Work::ZERO);
+        assert!(skill_hours(Skill::MTN_ELEC) > Work::ZERO);
+        assert_eq!(
+            capacity_calendar.0[period].total_hours,
+            operational_resources.values().map(|resource| resource.total_hours).sum()
+        );
+    }
+
+    let strategic_options = StrategicOptions::preset("deadline-first".parse::<StrategicPreset>().unwrap());
+    assert_eq!(strategic_options, StrategicOptions::default());
+}
+
+#[test]
+fn test_mid_size_instance_strategic_instance()
+{
+    let schedule_graph = fixtures::mid_size_instance();
+    let periods = fixtures::periods();
+    let strategic_options = StrategicOptions::default();
+
+    let strategic_instance = StrategicInstance::from_schedule_graph(&schedule_graph, &periods, &strategic_options).unwrap();
+
+    assert_eq!(strategic_instance.strategic_periods, periods);
+    assert!(strategic_instance.period_locks.is_empty());
+    assert_eq!(
+        strategic_instance.strategic_capacity,
+        StrategicResources::from_schedule_graph(&schedule_graph, &periods).unwrap()
+    );
+    assert_eq!(strategic_instance.strategic_work_order_parameters.len(), fixtures::WORK_ORDER_COUNT);
+    for work_order_number in fixtures::work_order_numbers() {
+        let work_order_parameter = &strategic_instance.strategic_work_order_parameters[&work_order_number];
+        assert_eq!(work_order_parameter.locked_in_period, None);
+        assert_eq!(
+            work_order_parameter.excluded_periods,
+            schedule_graph.periods_excluding(work_order_number).unwrap().into_iter().collect()
+        );
+        assert_eq!(
+            work_order_parameter.work_load,
+            schedule_graph.work_order_work_load(work_order_number).unwrap().into_iter().collect()
+        );
+        assert!(work_order_parameter.weight > 0);
+        assert!(periods.contains(&work_order_parameter.latest_period));
+    }
+}
+
+#[test]
+fn test_mid_size_instance_schedulers()
+{
+    let schedule_graph = fixtures::mid_size_instance();
+    let periods = fixtures::periods();
+
+    let strategic_instance = StrategicInstance::from_schedule_graph(&schedule_graph, &periods, &StrategicOptions::default()).unwrap();
+    let graph_solution = StrategicSolution::from_schedule_graph(&schedule_graph, &strategic_instance).unwrap();
+    assert_eq!(
+        graph_solution.0.values().filter(|period| period.is_some()).count(),
+        fixtures::WORK_ORDER_COUNT.div_ceil(fixtures::ASSIGNED_WORK_ORDER_STEP)
+    );
+
+    for scheduler in [
+        StrategicScheduler::Greedy,
+        StrategicScheduler::SimulatedAnnealing,
+        StrategicScheduler::CapacitySmoothing,
+    ] {
+        let strategic_options = StrategicOptions {
+            scheduler,
+            ..StrategicOptions::default()
+        };
+        let strategic_solution = built_in_solver(&strategic_options).solve(&strategic_instance, warm_start(&strategic_instance, &graph_solution));
+
+        assert_eq!(strategic_solution.0.len(), fixtures::WORK_ORDER_COUNT, "{scheduler:?}");
+        for (work_order_number, period) in &strategic_solution.0 {
+            let work_order_parameter = &strategic_instance.strategic_work_order_parameters[work_order_number];
+            assert!(
+                period.is_none_or(|period| periods.contains(&period) && !work_order_parameter.excluded_periods.contains(&period)),
+                "{scheduler:?}"
+            );
+        }
+        assert!(strategic_solution.0.values().any(Option::is_some), "{scheduler:?}");
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use schedule_hypergraph::fixtures;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::greedy::construct_initial_solution;
+
+#[test]
+fn test_construct_initial_solution()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    // 40 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_parameter = |weight: i64, work: Work| WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period: periods[2],
+        weight,
+        carry_over_count: 0,
+        commitment: Commitment::Firm,
+        safety_critical: false,
+        work_load: HashMap::from([(Skill::MTN_MECH, work)]),
+    };
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: HashMap::from([
+            (
+                WorkOrderNumber::new(1122334411).unwrap(),
+                work_order_parameter(100, Work::from_hours(30.0)),
+            ),
+            (
+                WorkOrderNumber::new(1122334422).unwrap(),
+                work_order_parameter(10, Work::from_hours(20.0)),
+            ),
+            (WorkOrderNumber::new(1122334433).unwrap(), work_order_parameter(1, Work::from_hours(10.0))),
+            (
+                WorkOrderNumber::new(1122334444).unwrap(),
+                WorkOrderParameter {
+                    locked_in_period: Some(periods[1]),
+                    ..work_order_parameter(1, Work::from_hours(40.0))
+                },
+            ),
+            (
+                WorkOrderNumber::new(1122334455).unwrap(),
+                WorkOrderParameter {
+                    excluded_periods: HashSet::from([periods[0], periods[2]]),
+                    ..work_order_parameter(1, Work::from_hours(5.0))
+                },
+            ),
+            (
+                WorkOrderNumber::new(1122334466).unwrap(),
+                WorkOrderParameter {
+                    commitment: Commitment::Forecast,
+                    ..work_order_parameter(1, Work::from_hours(100.0))
+                },
+            ),
+            (WorkOrderNumber::new(1122334477).unwrap(), work_order_parameter(1, Work::from_hours(50.0))),
+        ]),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::from([periods[1]]),
+        strategic_periods: periods.clone(),
+    };
+
+    let strategic_solution = construct_initial_solution(&strategic_instance);
+
+    // The heaviest work order goes first, so 1122334422 no longer fits in
+    // the first period and skips the locked second period, while the
+    // lighter 1122334433 fills up the first period.
+    assert_eq!(
+        strategic_solution,
+        StrategicSolution(HashMap::from([
+            (WorkOrderNumber::new(1122334411).unwrap(), Some(periods[0])),
+            (WorkOrderNumber::new(1122334422).unwrap(), Some(periods[2])),
+            (WorkOrderNumber::new(1122334433).unwrap(), Some(periods[0])),
+            (WorkOrderNumber::new(1122334444).unwrap(), Some(periods[1])),
+            (WorkOrderNumber::new(1122334455).unwrap(), None),
+            (WorkOrderNumber::new(1122334466).unwrap(), Some(periods[0])),
+            (WorkOrderNumber::new(1122334477).unwrap(), None),
+        ]))
+    );
+}
+
+#[test]
+fn test_construct_initial_solution_safety_critical()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    // 40 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_parameter = |weight: i64, work: Work, safety_critical: bool| WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period: periods[2],
+        weight,
+        carry_over_count: 0,
+        commitment: Commitment::Firm,
+        safety_critical,
+        work_load: HashMap::from([(Skill::MTN_MECH, work)]),
+    };
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: HashMap::from([
+            (
+                WorkOrderNumber::new(1122334411).unwrap(),
+                work_order_parameter(100, Work::from_hours(30.0), false),
+            ),
+            (
+                WorkOrderNumber::new(1122334422).unwrap(),
+                work_order_parameter(1, Work::from_hours(50.0), true),
+            ),
+            (
+                WorkOrderNumber::new(1122334433).unwrap(),
+                WorkOrderParameter {
+                    excluded_periods: HashSet::from([periods[0]]),
+                    ..work_order_parameter(1, Work::from_hours(10.0), true)
+                },
+            ),
+        ]),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::from([periods[0]]),
+        strategic_periods: periods.clone(),
+    };
+
+    let strategic_solution = construct_initial_solution(&strategic_instance);
+
+    // The safety work goes first and into the locked first period, even if
+    // 1122334422 does not fit anywhere. The heavier routine work order
+    // takes what is left of the second period.
+    assert_eq!(
+        strategic_solution,
+        StrategicSolution(HashMap::from([
+            (WorkOrderNumber::new(1122334411).unwrap(), Some(periods[1])),
+            (WorkOrderNumber::new(1122334422).unwrap(), Some(periods[0])),
+            (WorkOrderNumber::new(1122334433).unwrap(), Some(periods[1])),
+        ]))
+    );
+}
@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use schedule_hypergraph::fixtures;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::objective::ObjectiveKey;
+use strategic_algorithm::objective::PeriodObjective;
+use strategic_algorithm::objective::StrategicObjective;
+use strategic_algorithm::objective::WorkOrderObjective;
+use strategic_algorithm::simulated_annealing::SimulatedAnnealing;
+use strategic_algorithm::strategic_options::ObjectiveMode;
+use strategic_algorithm::strategic_options::StrategicOptions;
+use strategic_algorithm::strategic_options::StrategicPreset;
+
+#[test]
+fn test_evaluate()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    // 40 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_parameter = |weight: i64, latest_period: Period| WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period,
+        weight,
+        carry_over_count: 0,
+        commitment: Commitment::Firm,
+        safety_critical: false,
+        work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(30.0))]),
+    };
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: HashMap::from([
+            (WorkOrderNumber::new(1122334455).unwrap(), work_order_parameter(1, periods[0])),
+            (WorkOrderNumber::new(1122334466).unwrap(), work_order_parameter(2, periods[2])),
+            (
+                WorkOrderNumber::new(1122334477).unwrap(),
+                WorkOrderParameter {
+                    carry_over_count: 2,
+                    ..work_order_parameter(5, periods[2])
+                },
+            ),
+            (
+                WorkOrderNumber::new(1122334488).unwrap(),
+                WorkOrderParameter {
+                    commitment: Commitment::Forecast,
+                    ..work_order_parameter(1, periods[2])
+                },
+            ),
+        ]),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering(HashMap::from([(
+            (WorkOrderNumber::new(1122334455).unwrap(), WorkOrderNumber::new(1122334466).unwrap()),
+            3,
+        )])),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.clone(),
+    };
+
+    // The second period is loaded with 60 firm hours.
+    let mut strategic_solution = StrategicSolution(HashMap::from([
+        (WorkOrderNumber::new(1122334455).unwrap(), Some(periods[1])),
+        (WorkOrderNumber::new(1122334466).unwrap(), Some(periods[1])),
+        (WorkOrderNumber::new(1122334477).unwrap(), None),
+        (WorkOrderNumber::new(1122334488).unwrap(), Some(periods[1])),
+    ]));
+
+    let strategic_objective = StrategicObjective::new(StrategicOptions {
+        tardiness_weight: 10,
+        throughput_weight: 100,
+        escalation_weight: 20,
+        clustering_weight: 10,
+        capacity_penalty_weight: 20,
+        ..StrategicOptions::preset(StrategicPreset::ThroughputFirst)
+    });
+    let objective_value = strategic_objective.evaluate(&strategic_instance, &strategic_solution);
+
+    // The 20 overloaded hours are shared equally by the two firm work
+    // orders, and the clustered pair rewards both of them.
+    assert_eq!(
+        objective_value.work_orders[&WorkOrderNumber::new(1122334455).unwrap()],
+        WorkOrderObjective {
+            period: Some(periods[1]),
+            tardiness: 10,
+            weight: 100,
+            clustering: 30,
+            capacity_penalty: 200,
+            escalation: 0,
+            stability: 0,
+            hard_violation: false,
+            periods_late: 1,
+            overloaded_work: Work::from_hours(10.0),
+            instability: 0,
+        }
+    );
+    assert_eq!(objective_value.work_orders[&WorkOrderNumber::new(1122334466).unwrap()].value(), -30);
+    // The backlog is one period after the last strategic period.
+    assert_eq!(
+        objective_value.work_orders[&WorkOrderNumber::new(1122334477).unwrap()],
+        WorkOrderObjective {
+            tardiness: 50,
+            escalation: 40,
+            periods_late: 1,
+            ..WorkOrderObjective::default()
+        }
+    );
+    // Forecast work does not share the capacity penalty.
+    assert_eq!(objective_value.work_orders[&WorkOrderNumber::new(1122334488).unwrap()].value(), -100);
+    assert_eq!(objective_value.value(), 80 - 30 + 90 - 100);
+    assert!(objective_value.is_feasible());
+
+    // The second period drives the score up with its overload, while the
+    // backlog holds the tardiness of the unscheduled work order.
+    assert_eq!(
+        objective_value.periods(),
+        vec![
+            PeriodObjective {
+                period: Some(periods[1]),
+                tardiness: 10,
+                weight: 400,
+                clustering: 60,
+                capacity_penalty: 400,
+                escalation: 0,
+                stability: 0,
+                hard_violations: 0,
+            },
+            PeriodObjective {
+                period: None,
+                tardiness: 50,
+                escalation: 40,
+                ..PeriodObjective::default()
+            },
+        ]
+    );
+    let period_report_markdown = objective_value.period_report_markdown();
+    assert!(period_report_markdown.contains(&format!("| {} | 10 | 400 | 60 | 400 | 0 | 0 | 0 | -50 |", periods[1].start_date())));
+    assert!(period_report_markdown.contains("| Backlog | 50 | 0 | 0 | 0 | 40 | 0 | 0 | 90 |"));
+    assert!(period_report_markdown.contains("Total: 40"));
+
+    // The capacity and the latest period are hard constraints of the
+    // deadline first preset.
+    let strategic_objective = StrategicObjective::new(StrategicOptions::default());
+    let objective_value = strategic_objective.evaluate(&strategic_instance, &strategic_solution);
+    assert!(objective_value.work_orders[&WorkOrderNumber::new(1122334455).unwrap()].hard_violation);
+    assert!(objective_value.work_orders[&WorkOrderNumber::new(1122334466).unwrap()].hard_violation);
+    assert_eq!(
+        objective_value.work_orders[&WorkOrderNumber::new(1122334455).unwrap()].capacity_penalty,
+        0
+    );
+    assert!(!objective_value.is_feasible());
+
+    strategic_solution.0.insert(WorkOrderNumber::new(1122334455).unwrap(), Some(periods[0]));
+    assert!(strategic_objective.evaluate(&strategic_instance, &strategic_solution).is_feasible());
+}
+
+#[test]
+fn test_stability()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    let work_order_parameter = WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period: periods[2],
+        weight: 1,
+        carry_over_count: 0,
+        commitment: Commitment::Forecast,
+        safety_critical: false,
+        work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(10.0))]),
+    };
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: (1122334401..=1122334404)
+            .map(|work_order_number| WorkOrderNumber::new(work_order_number).unwrap())
+            .map(|work_order_number| (work_order_number, work_order_parameter.clone()))
+            .collect(),
+        strategic_capacity: StrategicResources::default(),
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.clone(),
+    };
+
+    let reference_solution = StrategicSolution(HashMap::from([
+        (WorkOrderNumber::new(1122334401).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334402).unwrap(), Some(periods[2])),
+        (WorkOrderNumber::new(1122334403).unwrap(), None),
+        (WorkOrderNumber::new(1122334404).unwrap(), Some(periods[1])),
+    ]));
+    let strategic_solution = StrategicSolution(HashMap::from([
+        (WorkOrderNumber::new(1122334401).unwrap(), Some(periods[1])),
+        (WorkOrderNumber::new(1122334402).unwrap(), Some(periods[1])),
+        (WorkOrderNumber::new(1122334403).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334404).unwrap(), Some(periods[1])),
+    ]));
+
+    let strategic_options = StrategicOptions {
+        stability_weight: 10,
+        ..StrategicOptions::preset(StrategicPreset::StabilityFirst)
+    };
+    let strategic_objective = StrategicObjective::new(strategic_options.clone());
+    let objective_value = strategic_objective.evaluate(&strategic_instance, &strategic_solution);
+    assert!(
+        objective_value
+            .work_orders
+            .values()
+            .all(|work_order_objective| work_order_objective.stability == 0)
+    );
+
+    // Moving a work order out of the first period is penalized the most,
+    // while work pulled out of the backlog moves freely.
+    let strategic_objective = strategic_objective.with_reference_solution(reference_solution.clone());
+    let objective_value = strategic_objective.evaluate(&strategic_instance, &strategic_solution);
+    assert_eq!(objective_value.work_orders[&WorkOrderNumber::new(1122334401).unwrap()].stability, 30);
+    assert_eq!(objective_value.work_orders[&WorkOrderNumber::new(1122334402).unwrap()].stability, 10);
+    assert_eq!(objective_value.work_orders[&WorkOrderNumber::new(1122334403).unwrap()].stability, 0);
+    assert_eq!(objective_value.work_orders[&WorkOrderNumber::new(1122334404).unwrap()].stability, 0);
+    assert_eq!(objective_value.periods()[1].stability, 40);
+
+    // The reference solution itself is not penalized.
+    assert_eq!(
+        strategic_objective.evaluate(&strategic_instance, &reference_solution),
+        StrategicObjective::new(strategic_options).evaluate(&strategic_instance, &reference_solution)
+    );
+}
+
+#[test]
+fn test_lexicographic_key()
+{
+    let periods = fixtures::periods()[..2].to_vec();
+
+    // 10 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(10.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(10.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_parameter = WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period: periods[0],
+        weight: 1,
+        carry_over_count: 0,
+        commitment: Commitment::Firm,
+        safety_critical: false,
+        work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(10.0))]),
+    };
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: HashMap::from([
+            (WorkOrderNumber::new(1122334455).unwrap(), work_order_parameter.clone()),
+            (WorkOrderNumber::new(1122334466).unwrap(), work_order_parameter),
+        ]),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.clone(),
+    };
+
+    let overloaded_solution = StrategicSolution(HashMap::from([
+        (WorkOrderNumber::new(1122334455).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334466).unwrap(), Some(periods[0])),
+    ]));
+    let late_solution = StrategicSolution(HashMap::from([
+        (WorkOrderNumber::new(1122334455).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334466).unwrap(), Some(periods[1])),
+    ]));
+
+    let strategic_options = StrategicOptions {
+        tardiness_weight: 10,
+        throughput_weight: 100,
+        capacity_penalty_weight: 20,
+        ..StrategicOptions::preset(StrategicPreset::ThroughputFirst)
+    };
+
+    // The weights prefer the late work order over the 10 overloaded hours.
+    let strategic_objective = StrategicObjective::new(strategic_options.clone());
+    let overloaded_key = strategic_objective.key(&strategic_objective.evaluate(&strategic_instance, &overloaded_solution));
+    let late_key = strategic_objective.key(&strategic_objective.evaluate(&strategic_instance, &late_solution));
+    assert_eq!(
+        overloaded_key,
+        ObjectiveKey {
+            value: 0,
+            ..ObjectiveKey::default()
+        }
+    );
+    assert!(late_key < overloaded_key);
+
+    // Missing a deadline ranks before any overload, whatever the weights.
+    let strategic_objective = StrategicObjective::new(StrategicOptions {
+        objective_mode: ObjectiveMode::Lexicographic,
+        ..strategic_options
+    });
+    let overloaded_key = strategic_objective.key(&strategic_objective.evaluate(&strategic_instance, &overloaded_solution));
+    let late_key = strategic_objective.key(&strategic_objective.evaluate(&strategic_instance, &late_solution));
+    assert_eq!(
+        overloaded_key,
+        ObjectiveKey {
+            hard_violations: 0,
+            deadline_misses: 0,
+            overloaded_work: Work::from_hours(10.0),
+            instability: 0,
+            value: 0,
+        }
+    );
+    assert_eq!(late_key.deadline_misses, 1);
+    assert!(overloaded_key < late_key);
+
+    // The annealing follows the ranking of the mode.
+    let mut strategic_solution = late_solution.clone();
+    SimulatedAnnealing::default().optimize(&strategic_instance, &strategic_objective, &mut strategic_solution);
+    assert_eq!(strategic_solution, overloaded_solution);
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use schedule_hypergraph::fixtures;
+use scheduling_environment::FixedClock;
+use scheduling_environment::Period;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::period_state::PeriodState;
+
+#[test]
+fn test_period_states()
+{
+    let mut periods = fixtures::periods();
+    periods.push(Period::from_start_date(periods.last().unwrap().end_date()));
+    let system_clock = FixedClock(periods[1].start_date().succ_opt().unwrap().and_hms_opt(12, 0, 0).unwrap());
+
+    assert_eq!(
+        PeriodState::classify(&periods, &system_clock),
+        vec![
+            PeriodState::Previous(periods[0]),
+            PeriodState::Frozen(periods[1]),
+            PeriodState::Draft(periods[2]),
+            PeriodState::Draft2(periods[3]),
+        ]
+    );
+
+    // The day after the frozen period ends the next period is frozen.
+    let system_clock = FixedClock(periods[1].end_date().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(
+        PeriodState::classify(&periods, &system_clock),
+        vec![
+            PeriodState::Previous(periods[0]),
+            PeriodState::Previous(periods[1]),
+            PeriodState::Frozen(periods[2]),
+            PeriodState::Draft(periods[3]),
+        ]
+    );
+
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: HashMap::new(),
+        strategic_capacity: StrategicResources::default(),
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::from([periods[3]]),
+        strategic_periods: periods.clone(),
+    };
+    let system_clock = FixedClock(periods[0].start_date().pred_opt().unwrap().and_hms_opt(12, 0, 0).unwrap());
+    assert_eq!(strategic_instance.draft_periods(&system_clock), periods[..3].to_vec());
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use schedule_hypergraph::fixtures;
+use schedule_hypergraph::sharding::WorkCenterPartition;
+use schedule_hypergraph::sharding::WorkCenterShard;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::greedy::GreedySolver;
+use strategic_algorithm::sharding::ShardedSolver;
+use strategic_algorithm::solver::StrategicSolver;
+
+#[test]
+fn test_sharded_solver()
+{
+    let periods = fixtures::periods()[..2].to_vec();
+
+    // A mechanic, an electrician and a technician lent to both work
+    // centers, 40 hours each in every period.
+    let operational_resource = |id: usize, skills: &[Skill]| OperationalResource {
+        id: TechnicianId::new(id),
+        total_hours: Work::from_hours(40.0),
+        skill_hours: skills.iter().map(|skill| (*skill, Work::from_hours(40.0))).collect(),
+        reserved_hours: HashMap::new(),
+    };
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        strategic_capacity.0.insert(
+            *period,
+            HashMap::from([
+                (TechnicianId::new(1001), operational_resource(1001, &[Skill::MTN_MECH])),
+                (TechnicianId::new(1002), operational_resource(1002, &[Skill::MTN_ELEC])),
+                (TechnicianId::new(1003), operational_resource(1003, &[Skill::MTN_MECH, Skill::MTN_ELEC])),
+            ]),
+        );
+    }
+
+    let work_order_parameter = |weight: i64, work_load: &[(Skill, f64)]| WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period: periods[1],
+        weight,
+        carry_over_count: 0,
+        commitment: Commitment::Firm,
+        safety_critical: false,
+        work_load: work_load.iter().map(|(skill, hours)| (*skill, Work::from_hours(*hours))).collect(),
+    };
+    let mechanical_work_orders = (1..=4)
+        .map(|number| WorkOrderNumber::new(1122334400 + number).unwrap())
+        .collect::<BTreeSet<_>>();
+    let electrical_work_orders = (5..=8)
+        .map(|number| WorkOrderNumber::new(1122334400 + number).unwrap())
+        .collect::<BTreeSet<_>>();
+    let boundary_work_order = WorkOrderNumber::new(1122334499).unwrap();
+
+    let mut strategic_work_order_parameters = HashMap::new();
+    for (weight, work_order_number) in mechanical_work_orders.iter().enumerate() {
+        strategic_work_order_parameters.insert(*work_order_number, work_order_parameter(10 - weight as i64, &[(Skill::MTN_MECH, 20.0)]));
+    }
+    for (weight, work_order_number) in electrical_work_orders.iter().enumerate() {
+        strategic_work_order_parameters.insert(*work_order_number, work_order_parameter(5 - weight as i64, &[(Skill::MTN_ELEC, 20.0)]));
+    }
+    strategic_work_order_parameters.insert(
+        boundary_work_order,
+        work_order_parameter(0, &[(Skill::MTN_MECH, 10.0), (Skill::MTN_ELEC, 10.0)]),
+    );
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters,
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.clone(),
+    };
+
+    let work_center_partition = WorkCenterPartition {
+        shards: BTreeMap::from([
+            (
+                Skill::MTN_MECH,
+                WorkCenterShard {
+                    work_orders: mechanical_work_orders.clone(),
+                    technicians: BTreeSet::from([TechnicianId::new(1001)]),
+                },
+            ),
+            (
+                Skill::MTN_ELEC,
+                WorkCenterShard {
+                    work_orders: electrical_work_orders.clone(),
+                    technicians: BTreeSet::from([TechnicianId::new(1002)]),
+                },
+            ),
+        ]),
+        boundary_technicians: BTreeSet::from([TechnicianId::new(1003)]),
+        boundary_work_orders: BTreeSet::from([boundary_work_order]),
+    };
+
+    // Each shard sees the whole boundary technician, so on its own every
+    // shard fits all of its work in the first period.
+    let mechanical_shard = strategic_instance.shard(&work_center_partition, Skill::MTN_MECH);
+    assert_eq!(
+        mechanical_shard.strategic_work_order_parameters.keys().copied().collect::<BTreeSet<_>>(),
+        mechanical_work_orders
+    );
+    assert_eq!(
+        mechanical_shard.strategic_capacity.skill_hours(&periods[0], &Skill::MTN_MECH),
+        Work::from_hours(80.0)
+    );
+    assert_eq!(mechanical_shard.strategic_capacity.skill_hours(&periods[0], &Skill::MTN_ELEC), Work::ZERO);
+    let mechanical_solution = GreedySolver.solve(&mechanical_shard, StrategicSolution::default());
+    assert!(mechanical_solution.0.values().all(|period| *period == Some(periods[0])));
+
+    let initial_solution = StrategicSolution(HashMap::from([(boundary_work_order, Some(periods[1]))]));
+    let mut sharded_solver = ShardedSolver {
+        work_center_partition,
+        solver: GreedySolver,
+    };
+    let strategic_solution = sharded_solver.solve(&strategic_instance, initial_solution);
+    assert_eq!(strategic_solution.0.len(), 9);
+
+    // Together the shards overbook the boundary technician by 40 hours in
+    // the first period, so the two lightest electrical work orders move.
+    let work_orders_in = |period| {
+        strategic_solution
+            .0
+            .iter()
+            .filter(|(_, solution_period)| **solution_period == Some(period))
+            .map(|(work_order_number, _)| *work_order_number)
+            .collect::<BTreeSet<_>>()
+    };
+    let mut first_period = mechanical_work_orders.clone();
+    first_period.extend(electrical_work_orders.iter().take(2));
+    assert_eq!(work_orders_in(periods[0]), first_period);
+    let mut second_period = electrical_work_orders.iter().skip(2).copied().collect::<BTreeSet<_>>();
+    second_period.insert(boundary_work_order);
+    assert_eq!(work_orders_in(periods[1]), second_period);
+
+    let mut remaining_resources = strategic_instance.strategic_capacity.clone();
+    for (work_order_number, period) in &strategic_solution.0 {
+        let work_load = &strategic_instance.strategic_work_order_parameters[work_order_number].work_load;
+        assert!(remaining_resources.reserve_work_load(period.unwrap(), work_load).is_ok());
+    }
+}
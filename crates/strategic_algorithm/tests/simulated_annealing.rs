@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::time::Duration;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use schedule_hypergraph::fixtures;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::objective::StrategicObjective;
+use strategic_algorithm::simulated_annealing::AnnealingMove;
+use strategic_algorithm::simulated_annealing::CoolingSchedule;
+use strategic_algorithm::simulated_annealing::SimulatedAnnealing;
+use strategic_algorithm::simulated_annealing::WeightedPeriodNeighborhood;
+use strategic_algorithm::solver::Neighborhood;
+use strategic_algorithm::strategic_options::StrategicOptions;
+use strategic_algorithm::strategic_options::StrategicPreset;
+
+#[test]
+fn test_simulated_annealing()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    // 40 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_numbers = (1122334401..=1122334406).map(|work_order_number| WorkOrderNumber::new(work_order_number).unwrap());
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: work_order_numbers
+            .clone()
+            .map(|work_order_number| {
+                let work_order_parameter = WorkOrderParameter {
+                    locked_in_period: None,
+                    excluded_periods: HashSet::new(),
+                    latest_period: periods[2],
+                    weight: 1,
+                    carry_over_count: 0,
+                    commitment: Commitment::Firm,
+                    safety_critical: false,
+                    work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(20.0))]),
+                };
+                (work_order_number, work_order_parameter)
+            })
+            .collect(),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.clone(),
+    };
+
+    // Overloading a period costs more than scheduling another work order
+    // gains, so the optimum is two work orders in every period.
+    let strategic_objective = StrategicObjective::new(StrategicOptions::preset(StrategicPreset::ThroughputFirst));
+    let simulated_annealing = SimulatedAnnealing {
+        initial_temperature: 50.0,
+        cooling_schedule: CoolingSchedule::Linear,
+        seed: 42,
+        iteration_budget: 2000,
+        time_budget: None,
+    };
+
+    let backlog = StrategicSolution(work_order_numbers.map(|work_order_number| (work_order_number, None)).collect());
+    let mut strategic_solution = backlog.clone();
+    let annealing_outcome = simulated_annealing.optimize(&strategic_instance, &strategic_objective, &mut strategic_solution);
+
+    assert_eq!(annealing_outcome.iterations, 2000);
+    assert!(annealing_outcome.accepted_moves > 0);
+    assert_eq!(annealing_outcome.objective_value.value(), -600);
+    assert!(annealing_outcome.objective_value.is_feasible());
+    assert_eq!(
+        strategic_objective.evaluate(&strategic_instance, &strategic_solution),
+        annealing_outcome.objective_value
+    );
+    for period in &periods {
+        assert_eq!(strategic_solution.0.values().filter(|scheduled| **scheduled == Some(*period)).count(), 2);
+    }
+
+    // The seed makes the search reproducible.
+    let mut reproduced_solution = backlog.clone();
+    simulated_annealing.optimize(&strategic_instance, &strategic_objective, &mut reproduced_solution);
+    assert_eq!(reproduced_solution, strategic_solution);
+
+    // An exhausted time budget leaves the solution as it is.
+    let mut unchanged_solution = backlog.clone();
+    let annealing_outcome = SimulatedAnnealing {
+        time_budget: Some(Duration::ZERO),
+        ..simulated_annealing
+    }
+    .optimize(&strategic_instance, &strategic_objective, &mut unchanged_solution);
+    assert_eq!(annealing_outcome.iterations, 0);
+    assert_eq!(unchanged_solution, backlog);
+}
+
+#[test]
+fn test_weighted_period_neighborhood()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_numbers = (1122334401..=1122334404)
+        .map(|work_order_number| WorkOrderNumber::new(work_order_number).unwrap())
+        .collect::<Vec<_>>();
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: work_order_numbers
+            .iter()
+            .map(|work_order_number| {
+                let work_order_parameter = WorkOrderParameter {
+                    locked_in_period: None,
+                    excluded_periods: HashSet::new(),
+                    latest_period: periods[0],
+                    weight: 10,
+                    carry_over_count: 0,
+                    commitment: Commitment::Firm,
+                    safety_critical: false,
+                    work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(10.0))]),
+                };
+                (*work_order_number, work_order_parameter)
+            })
+            .collect(),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.clone(),
+    };
+
+    // Three work orders are on time and the last one is two periods late.
+    let strategic_solution = StrategicSolution(
+        work_order_numbers
+            .iter()
+            .zip([periods[0], periods[0], periods[0], periods[2]])
+            .map(|(work_order_number, period)| (*work_order_number, Some(period)))
+            .collect(),
+    );
+    let weighted_period_neighborhood = WeightedPeriodNeighborhood {
+        strategic_objective: StrategicObjective::new(StrategicOptions::default()),
+    };
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let late_moves = (0..1000)
+        .filter(|_| {
+            let neighborhood_move = weighted_period_neighborhood
+                .random_move(&mut rng, &strategic_instance, &strategic_solution)
+                .unwrap();
+            let work_order_number = match neighborhood_move {
+                AnnealingMove::ShiftPeriod { work_order_number, .. } => work_order_number,
+                AnnealingMove::Swap { work_order_number, .. } => work_order_number,
+            };
+            work_order_number == work_order_numbers[3]
+        })
+        .count();
+    assert!(late_moves > 900, "{late_moves}");
+
+    let strategic_objective = StrategicObjective::new(StrategicOptions::default());
+    let mut optimized_solution = strategic_solution.clone();
+    let annealing_outcome = SimulatedAnnealing::default().optimize_with(
+        &weighted_period_neighborhood,
+        &strategic_instance,
+        &strategic_objective,
+        &mut optimized_solution,
+    );
+    assert!(annealing_outcome.objective_value.is_feasible());
+    assert_eq!(optimized_solution.0.values().filter(|period| **period == Some(periods[0])).count(), 4);
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use rand::Rng;
+use rand::RngCore;
+use schedule_hypergraph::fixtures;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::objective::StrategicObjective;
+use strategic_algorithm::simulated_annealing::AnnealingMove;
+use strategic_algorithm::simulated_annealing::SimulatedAnnealing;
+use strategic_algorithm::solver::Neighborhood;
+use strategic_algorithm::solver::built_in_solver;
+use strategic_algorithm::strategic_options::StrategicOptions;
+use strategic_algorithm::strategic_options::StrategicPreset;
+use strategic_algorithm::strategic_options::StrategicScheduler;
+
+/// Six work orders of 20 hours and 40 hours of capacity in each period.
+fn strategic_instance(periods: &[Period]) -> StrategicInstance
+{
+    let mut strategic_capacity = StrategicResources::default();
+    for period in periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    StrategicInstance {
+        strategic_work_order_parameters: (1122334401..=1122334406)
+            .map(|work_order_number| WorkOrderNumber::new(work_order_number).unwrap())
+            .map(|work_order_number| {
+                let work_order_parameter = WorkOrderParameter {
+                    locked_in_period: None,
+                    excluded_periods: HashSet::new(),
+                    latest_period: *periods.last().unwrap(),
+                    weight: 1,
+                    carry_over_count: 0,
+                    commitment: Commitment::Firm,
+                    safety_critical: false,
+                    work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(20.0))]),
+                };
+                (work_order_number, work_order_parameter)
+            })
+            .collect(),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.to_vec(),
+    }
+}
+
+#[test]
+fn test_built_in_solvers()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+    let strategic_instance = strategic_instance(&periods);
+    let backlog = StrategicSolution(
+        strategic_instance
+            .strategic_work_order_parameters
+            .keys()
+            .map(|work_order_number| (*work_order_number, None))
+            .collect(),
+    );
+
+    let strategic_options = StrategicOptions::preset(StrategicPreset::ThroughputFirst);
+    let strategic_objective = StrategicObjective::new(strategic_options.clone());
+    for scheduler in [
+        StrategicScheduler::Greedy,
+        StrategicScheduler::SimulatedAnnealing,
+        StrategicScheduler::CapacitySmoothing,
+    ] {
+        let mut strategic_solver = built_in_solver(&StrategicOptions {
+            scheduler,
+            ..strategic_options.clone()
+        });
+        let strategic_solution = strategic_solver.solve(&strategic_instance, backlog.clone());
+
+        // Smoothing only spreads work that is already scheduled.
+        let expected_value = match scheduler {
+            StrategicScheduler::CapacitySmoothing => strategic_objective.evaluate(&strategic_instance, &backlog).value(),
+            _ => -600,
+        };
+        assert_eq!(
+            strategic_objective.evaluate(&strategic_instance, &strategic_solution).value(),
+            expected_value,
+            "{scheduler:?}"
+        );
+    }
+}
+
+/// Only moves work orders into the first period.
+struct FirstPeriodNeighborhood;
+
+impl Neighborhood for FirstPeriodNeighborhood
+{
+    type Move = AnnealingMove;
+
+    fn random_move(
+        &self,
+        rng: &mut dyn RngCore,
+        strategic_instance: &StrategicInstance,
+        strategic_solution: &StrategicSolution,
+    ) -> Option<AnnealingMove>
+    {
+        let mut work_order_numbers = strategic_solution.0.keys().copied().collect::<Vec<_>>();
+        work_order_numbers.sort();
+        Some(AnnealingMove::ShiftPeriod {
+            work_order_number: work_order_numbers[rng.random_range(0..work_order_numbers.len())],
+            to: strategic_instance.strategic_periods.iter().min().copied(),
+        })
+    }
+}
+
+#[test]
+fn test_custom_neighborhood()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+    let strategic_instance = strategic_instance(&periods);
+    let mut strategic_solution = StrategicSolution(
+        strategic_instance
+            .strategic_work_order_parameters
+            .keys()
+            .map(|work_order_number| (*work_order_number, None))
+            .collect(),
+    );
+
+    let strategic_objective = StrategicObjective::new(StrategicOptions::preset(StrategicPreset::ThroughputFirst));
+    let annealing_outcome = SimulatedAnnealing {
+        iteration_budget: 500,
+        ..SimulatedAnnealing::default()
+    }
+    .optimize_with(
+        &FirstPeriodNeighborhood,
+        &strategic_instance,
+        &strategic_objective,
+        &mut strategic_solution,
+    );
+
+    // Two work orders fill up the first period, the rest stay in the
+    // backlog where each of them is one period late.
+    assert_eq!(annealing_outcome.objective_value.value(), -200 + 4 * 10);
+    assert_eq!(strategic_solution.0.values().filter(|period| **period == Some(periods[0])).count(), 2);
+    assert_eq!(strategic_solution.0.values().filter(|period| period.is_none()).count(), 4);
+}
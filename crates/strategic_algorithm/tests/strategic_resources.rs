@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use schedule_hypergraph::fixtures;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillLevel;
+use scheduling_environment::technician::Technician;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Work;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicResourceErrors;
+use strategic_algorithm::StrategicResources;
+
+#[test]
+fn test_from_schedule_graph()
+{
+    let mut schedule_graph = ScheduleGraph::default();
+
+    let period_start = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+    let period = Period::from_start_date(period_start);
+    schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+    schedule_graph.add_skill(Skill::MTN_ELEC).unwrap();
+    schedule_graph.add_period(period).unwrap();
+
+    // 16 hours on the first and the last day and 24 hours on the two days
+    // in between.
+    let start = period_start.and_hms_opt(8, 0, 0).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 1, 16).unwrap().and_hms_opt(16, 0, 0).unwrap();
+    for technician_id in [TechnicianId::new(1001), TechnicianId::new(1002)] {
+        let technician = Technician::builder(technician_id)
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::MTN_MECH)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+    }
+
+    // 1002 is also an electrician from the third day of the period.
+    schedule_graph
+        .gain_skill_from(
+            TechnicianId::new(1002),
+            Skill::MTN_ELEC,
+            SkillLevel::Journeyman,
+            NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+        )
+        .unwrap();
+
+    let strategic_resources = StrategicResources::from_schedule_graph(&schedule_graph, &[period]).unwrap();
+    let operational_resources = &strategic_resources.0[&period];
+    assert_eq!(operational_resources.len(), 2);
+    assert_eq!(operational_resources[&TechnicianId::new(1001)].total_hours, Work::from_hours(80.0));
+    assert_eq!(operational_resources[&TechnicianId::new(1002)].total_hours, Work::from_hours(80.0));
+    assert_eq!(
+        operational_resources[&TechnicianId::new(1002)].skill_hours[&Skill::MTN_ELEC],
+        Work::from_hours(40.0)
+    );
+    assert_eq!(strategic_resources.skill_hours(&period, &Skill::MTN_MECH), Work::from_hours(160.0));
+    assert_eq!(strategic_resources.skill_hours(&period, &Skill::MTN_ELEC), Work::from_hours(40.0));
+
+    let missing_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+    assert_eq!(
+        StrategicResources::from_schedule_graph(&schedule_graph, &[missing_period]),
+        Err(ScheduleGraphErrors::PeriodMissing {
+            period: missing_period,
+            periods: vec![period],
+        })
+    );
+}
+
+#[test]
+fn test_reserve_and_release()
+{
+    let period = fixtures::periods()[0];
+    let operational_resource = |id, hours| OperationalResource {
+        id,
+        total_hours: hours,
+        skill_hours: HashMap::from([(Skill::MTN_MECH, hours)]),
+        reserved_hours: HashMap::new(),
+    };
+    let mut strategic_resources = StrategicResources(HashMap::from([(
+        period,
+        HashMap::from([
+            (
+                TechnicianId::new(1001),
+                operational_resource(TechnicianId::new(1001), Work::from_hours(30.0)),
+            ),
+            (
+                TechnicianId::new(1002),
+                operational_resource(TechnicianId::new(1002), Work::from_hours(20.0)),
+            ),
+        ]),
+    )]));
+
+    strategic_resources.reserve(period, Skill::MTN_MECH, Work::from_hours(40.0)).unwrap();
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_MECH), Work::from_hours(10.0));
+    assert_eq!(
+        strategic_resources.0[&period][&TechnicianId::new(1001)].reserved_hours[&Skill::MTN_MECH],
+        Work::from_hours(30.0)
+    );
+    assert_eq!(
+        strategic_resources.0[&period][&TechnicianId::new(1002)].reserved_hours[&Skill::MTN_MECH],
+        Work::from_hours(10.0)
+    );
+
+    assert_eq!(
+        strategic_resources.reserve(period, Skill::MTN_MECH, Work::from_hours(15.0)),
+        Err(StrategicResourceErrors::OverAllocation {
+            period,
+            skill: Skill::MTN_MECH,
+            requested: Work::from_hours(15.0),
+            available: Work::from_hours(10.0),
+        })
+    );
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_ELEC), Work::ZERO);
+    assert!(strategic_resources.reserve(period, Skill::MTN_ELEC, Work::from_hours(1.0)).is_err());
+
+    strategic_resources.release(period, Skill::MTN_MECH, Work::from_hours(15.0)).unwrap();
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_MECH), Work::from_hours(25.0));
+    assert!(
+        !strategic_resources.0[&period][&TechnicianId::new(1002)]
+            .reserved_hours
+            .contains_key(&Skill::MTN_MECH)
+    );
+    assert_eq!(
+        strategic_resources.0[&period][&TechnicianId::new(1001)].reserved_hours[&Skill::MTN_MECH],
+        Work::from_hours(25.0)
+    );
+    assert_eq!(
+        strategic_resources.release(period, Skill::MTN_MECH, Work::from_hours(30.0)),
+        Err(StrategicResourceErrors::OverRelease {
+            period,
+            skill: Skill::MTN_MECH,
+            requested: Work::from_hours(30.0),
+            releasable: Work::from_hours(25.0),
+        })
+    );
+    strategic_resources.release(period, Skill::MTN_MECH, Work::from_hours(25.0)).unwrap();
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_MECH), Work::from_hours(50.0));
+}
+
+#[test]
+fn test_reserve_multi_skill()
+{
+    let period = fixtures::periods()[0];
+    let mut strategic_resources = StrategicResources(HashMap::from([(
+        period,
+        HashMap::from([
+            (
+                TechnicianId::new(1001),
+                OperationalResource {
+                    id: TechnicianId::new(1001),
+                    total_hours: Work::from_hours(8.0),
+                    skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(8.0)), (Skill::MTN_ELEC, Work::from_hours(8.0))]),
+                    reserved_hours: HashMap::new(),
+                },
+            ),
+            (
+                TechnicianId::new(1002),
+                OperationalResource {
+                    id: TechnicianId::new(1002),
+                    total_hours: Work::from_hours(8.0),
+                    skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(8.0))]),
+                    reserved_hours: HashMap::new(),
+                },
+            ),
+        ]),
+    )]));
+
+    // The technician with one skill is used first, so the electrician keeps
+    // their hours.
+    strategic_resources.reserve(period, Skill::MTN_MECH, Work::from_hours(10.0)).unwrap();
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_MECH), Work::from_hours(6.0));
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_ELEC), Work::from_hours(6.0));
+
+    assert_eq!(
+        strategic_resources.reserve(period, Skill::MTN_ELEC, Work::from_hours(8.0)),
+        Err(StrategicResourceErrors::OverAllocation {
+            period,
+            skill: Skill::MTN_ELEC,
+            requested: Work::from_hours(8.0),
+            available: Work::from_hours(6.0),
+        })
+    );
+    strategic_resources.reserve(period, Skill::MTN_ELEC, Work::from_hours(6.0)).unwrap();
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_MECH), Work::ZERO);
+
+    assert_eq!(
+        strategic_resources.release(period, Skill::MTN_ELEC, Work::from_hours(7.0)),
+        Err(StrategicResourceErrors::OverRelease {
+            period,
+            skill: Skill::MTN_ELEC,
+            requested: Work::from_hours(7.0),
+            releasable: Work::from_hours(6.0),
+        })
+    );
+    strategic_resources.release(period, Skill::MTN_ELEC, Work::from_hours(6.0)).unwrap();
+    assert_eq!(strategic_resources.remaining(period, Skill::MTN_MECH), Work::from_hours(6.0));
+
+    let work_load = HashMap::from([(Skill::MTN_ELEC, Work::from_hours(4.0)), (Skill::MTN_MECH, Work::from_hours(4.0))]);
+    let before = strategic_resources.clone();
+    assert!(strategic_resources.reserve_work_load(period, &work_load).is_err());
+    assert_eq!(strategic_resources, before);
+}
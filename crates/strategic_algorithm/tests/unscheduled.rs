@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use schedule_hypergraph::fixtures;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use schedule_hypergraph::unscheduled::UnscheduledReason;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Activity;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrder;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::greedy::construct_initial_solution;
+use strategic_algorithm::unscheduled::unscheduled_reasons;
+
+#[test]
+fn test_unscheduled_reasons()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    // 40 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let work_order_parameter = |weight: i64, work: Work| WorkOrderParameter {
+        locked_in_period: None,
+        excluded_periods: HashSet::new(),
+        latest_period: periods[2],
+        weight,
+        carry_over_count: 0,
+        commitment: Commitment::Firm,
+        safety_critical: false,
+        work_load: HashMap::from([(Skill::MTN_MECH, work)]),
+    };
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: HashMap::from([
+            (
+                WorkOrderNumber::new(1122334411).unwrap(),
+                work_order_parameter(100, Work::from_hours(30.0)),
+            ),
+            (
+                WorkOrderNumber::new(1122334422).unwrap(),
+                work_order_parameter(10, Work::from_hours(20.0)),
+            ),
+            (WorkOrderNumber::new(1122334433).unwrap(), work_order_parameter(1, Work::from_hours(10.0))),
+            (
+                WorkOrderNumber::new(1122334455).unwrap(),
+                WorkOrderParameter {
+                    excluded_periods: HashSet::from([periods[0], periods[2]]),
+                    ..work_order_parameter(1, Work::from_hours(5.0))
+                },
+            ),
+            (WorkOrderNumber::new(1122334477).unwrap(), work_order_parameter(1, Work::from_hours(50.0))),
+            (WorkOrderNumber::new(1122334488).unwrap(), work_order_parameter(1, Work::from_hours(25.0))),
+        ]),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::from([periods[1]]),
+        strategic_periods: periods.clone(),
+    };
+
+    let mut schedule_graph = ScheduleGraph::default();
+    schedule_graph.add_skill(Skill::MTN_MECH).unwrap();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+    for work_order_number in strategic_instance.strategic_work_order_parameters.keys() {
+        schedule_graph
+            .add_work_order(
+                &WorkOrder::new(
+                    *work_order_number,
+                    periods[0].start_date(),
+                    vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+                )
+                .unwrap(),
+            )
+            .unwrap();
+    }
+    schedule_graph
+        .add_work_order_dependency(WorkOrderNumber::new(1122334477).unwrap(), WorkOrderNumber::new(1122334488).unwrap())
+        .unwrap();
+
+    // The first period is full after 1122334411 and 1122334433 and the last
+    // period has 20 hours left after 1122334422.
+    let strategic_solution = construct_initial_solution(&strategic_instance);
+    let unscheduled_reasons = unscheduled_reasons(&schedule_graph, &strategic_instance, &strategic_solution).unwrap();
+    assert_eq!(
+        unscheduled_reasons,
+        BTreeMap::from([
+            (WorkOrderNumber::new(1122334455).unwrap(), vec![UnscheduledReason::Excluded]),
+            (
+                WorkOrderNumber::new(1122334477).unwrap(),
+                vec![UnscheduledReason::NoSkillCapacity {
+                    skill: Skill::MTN_MECH,
+                    periods: vec![periods[0], periods[2]],
+                }]
+            ),
+            (
+                WorkOrderNumber::new(1122334488).unwrap(),
+                vec![
+                    UnscheduledReason::WaitingOnPredecessor(WorkOrderNumber::new(1122334477).unwrap()),
+                    UnscheduledReason::NoSkillCapacity {
+                        skill: Skill::MTN_MECH,
+                        periods: vec![periods[0], periods[2]],
+                    }
+                ]
+            ),
+        ])
+    );
+
+    schedule_graph.record_unscheduled_reasons(unscheduled_reasons.clone()).unwrap();
+    assert_eq!(schedule_graph.unscheduled_reasons(), &unscheduled_reasons);
+}
+
+#[test]
+fn test_unscheduled_reasons_of_a_work_order_missing_from_the_graph()
+{
+    let periods = fixtures::periods()[..1].to_vec();
+    let work_order_number = WorkOrderNumber::new(1122334455).unwrap();
+    let strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: HashMap::from([(
+            work_order_number,
+            WorkOrderParameter {
+                locked_in_period: None,
+                excluded_periods: HashSet::new(),
+                latest_period: periods[0],
+                weight: 1,
+                carry_over_count: 0,
+                commitment: Commitment::Firm,
+                safety_critical: false,
+                work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(10.0))]),
+            },
+        )]),
+        strategic_capacity: StrategicResources::default(),
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods,
+    };
+
+    // The dependencies of a work order in the backlog are read from the
+    // graph, which does not have it.
+    let strategic_solution = StrategicSolution(HashMap::from([(work_order_number, None)]));
+    assert_eq!(
+        unscheduled_reasons(&ScheduleGraph::default(), &strategic_instance, &strategic_solution),
+        Err(ScheduleGraphErrors::WorkOrderMissing { work_order_number })
+    );
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use schedule_hypergraph::fixtures;
+use scheduling_environment::FixedClock;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Commitment;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicClustering;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::StrategicSolution;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::solver::built_in_solver;
+use strategic_algorithm::solver::warm_start;
+use strategic_algorithm::strategic_options::StrategicOptions;
+use strategic_algorithm::strategic_options::StrategicPreset;
+use strategic_algorithm::strategic_options::StrategicScheduler;
+
+#[test]
+fn test_partial_reoptimization()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+
+    // 40 hours of mechanical capacity in every period.
+    let mut strategic_capacity = StrategicResources::default();
+    for period in &periods {
+        let operational_resource = OperationalResource {
+            id: TechnicianId::new(1001),
+            total_hours: Work::from_hours(40.0),
+            skill_hours: HashMap::from([(Skill::MTN_MECH, Work::from_hours(40.0))]),
+            reserved_hours: HashMap::new(),
+        };
+        strategic_capacity
+            .0
+            .insert(*period, HashMap::from([(TechnicianId::new(1001), operational_resource)]));
+    }
+
+    let mut strategic_instance = StrategicInstance {
+        strategic_work_order_parameters: (1122334401..=1122334403)
+            .map(|work_order_number| WorkOrderNumber::new(work_order_number).unwrap())
+            .map(|work_order_number| {
+                let work_order_parameter = WorkOrderParameter {
+                    locked_in_period: None,
+                    excluded_periods: HashSet::new(),
+                    latest_period: periods[2],
+                    weight: 1,
+                    carry_over_count: 0,
+                    commitment: Commitment::Firm,
+                    safety_critical: false,
+                    work_load: HashMap::from([(Skill::MTN_MECH, Work::from_hours(20.0))]),
+                };
+                (work_order_number, work_order_parameter)
+            })
+            .collect(),
+        strategic_capacity,
+        strategic_clustering: StrategicClustering::default(),
+        period_locks: HashSet::new(),
+        strategic_periods: periods.clone(),
+    };
+
+    // The first period is in progress.
+    let system_clock = FixedClock(periods[0].start_date().succ_opt().unwrap().and_hms_opt(12, 0, 0).unwrap());
+    strategic_instance.lock_settled_periods(&system_clock);
+    assert_eq!(strategic_instance.period_locks, HashSet::from([periods[0]]));
+
+    // 1122334499 has been completed since the previous run and 1122334403
+    // is new.
+    let previous_solution = StrategicSolution(HashMap::from([
+        (WorkOrderNumber::new(1122334401).unwrap(), Some(periods[0])),
+        (WorkOrderNumber::new(1122334402).unwrap(), Some(periods[2])),
+        (WorkOrderNumber::new(1122334499).unwrap(), Some(periods[1])),
+    ]));
+    let initial_solution = warm_start(&strategic_instance, &previous_solution);
+    assert_eq!(
+        initial_solution,
+        StrategicSolution(HashMap::from([
+            (WorkOrderNumber::new(1122334401).unwrap(), Some(periods[0])),
+            (WorkOrderNumber::new(1122334402).unwrap(), Some(periods[2])),
+            (WorkOrderNumber::new(1122334403).unwrap(), None),
+        ]))
+    );
+
+    for scheduler in [
+        StrategicScheduler::Greedy,
+        StrategicScheduler::SimulatedAnnealing,
+        StrategicScheduler::CapacitySmoothing,
+    ] {
+        let mut strategic_solver = built_in_solver(&StrategicOptions {
+            scheduler,
+            ..StrategicOptions::preset(StrategicPreset::ThroughputFirst)
+        });
+        let strategic_solution = strategic_solver.solve(&strategic_instance, initial_solution.clone());
+
+        // The frozen period keeps its work and receives no new work, even
+        // though it has room for it.
+        assert_eq!(
+            strategic_solution.0[&WorkOrderNumber::new(1122334401).unwrap()],
+            Some(periods[0]),
+            "{scheduler:?}"
+        );
+        assert_eq!(
+            strategic_solution.0.values().filter(|period| **period == Some(periods[0])).count(),
+            1,
+            "{scheduler:?}"
+        );
+        assert_eq!(strategic_solution.0.len(), 3, "{scheduler:?}");
+    }
+
+    // The greedy scheduler plans the draft periods from scratch.
+    let strategic_solution = built_in_solver(&StrategicOptions::preset(StrategicPreset::StabilityFirst)).solve(&strategic_instance, initial_solution);
+    assert_eq!(strategic_solution.0[&WorkOrderNumber::new(1122334402).unwrap()], Some(periods[1]));
+    assert_eq!(strategic_solution.0[&WorkOrderNumber::new(1122334403).unwrap()], Some(periods[1]));
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use schedule_hypergraph::fixtures;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::TechnicianId;
+use scheduling_environment::work_order::Work;
+use strategic_algorithm::OperationalResource;
+use strategic_algorithm::StrategicResources;
+use strategic_algorithm::work_center::WorkCenterResources;
+
+#[test]
+fn test_work_center_resources()
+{
+    let period = fixtures::periods()[0];
+
+    let operational_resource = |id, skills: &[Skill], hours| OperationalResource {
+        id,
+        total_hours: hours,
+        skill_hours: skills.iter().map(|skill| (*skill, hours)).collect(),
+        reserved_hours: HashMap::new(),
+    };
+    let strategic_resources = StrategicResources(HashMap::from([(
+        period,
+        HashMap::from([
+            (
+                TechnicianId::new(1001),
+                operational_resource(TechnicianId::new(1001), &[Skill::MTN_MECH], Work::from_hours(30.0)),
+            ),
+            (
+                TechnicianId::new(1002),
+                operational_resource(TechnicianId::new(1002), &[Skill::MTN_MECH, Skill::MTN_ELEC], Work::from_hours(10.0)),
+            ),
+            (
+                TechnicianId::new(1003),
+                operational_resource(TechnicianId::new(1003), &[Skill::MTN_ELEC], Work::from_hours(30.0)),
+            ),
+        ]),
+    )]));
+
+    let mut work_center_resources = WorkCenterResources::from(&strategic_resources);
+    assert_eq!(work_center_resources.hours(&period, &Skill::MTN_MECH), Work::from_hours(40.0));
+    assert_eq!(work_center_resources.hours(&period, &Skill::MTN_ELEC), Work::from_hours(40.0));
+    assert_eq!(work_center_resources.hours(&period, &Skill::new("MtnCran")), Work::ZERO);
+    assert_eq!(
+        work_center_resources
+            .drill_down(&period, &Skill::MTN_ELEC, &strategic_resources)
+            .into_iter()
+            .map(|operational_resource| operational_resource.id)
+            .collect::<Vec<_>>(),
+        vec![TechnicianId::new(1002), TechnicianId::new(1003)]
+    );
+
+    // Round trip without changes.
+    assert_eq!(work_center_resources.to_strategic_resources(&strategic_resources), strategic_resources);
+
+    // Halving the mechanical work center halves the mechanical hours of
+    // each of its technicians.
+    work_center_resources
+        .0
+        .get_mut(&period)
+        .unwrap()
+        .insert(Skill::MTN_MECH, Work::from_hours(20.0));
+    let distributed_resources = work_center_resources.to_strategic_resources(&strategic_resources);
+    assert_eq!(
+        distributed_resources.0[&period][&TechnicianId::new(1001)].skill_hours[&Skill::MTN_MECH],
+        Work::from_hours(15.0)
+    );
+    assert_eq!(
+        distributed_resources.0[&period][&TechnicianId::new(1001)].total_hours,
+        Work::from_hours(15.0)
+    );
+    assert_eq!(
+        distributed_resources.0[&period][&TechnicianId::new(1002)].skill_hours[&Skill::MTN_MECH],
+        Work::from_hours(5.0)
+    );
+    assert_eq!(
+        distributed_resources.0[&period][&TechnicianId::new(1002)].total_hours,
+        Work::from_hours(10.0)
+    );
+    assert_eq!(WorkCenterResources::from(&distributed_resources), work_center_resources);
+}
@@ -0,0 +1,164 @@
+use schedule_hypergraph::fixtures;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Activity;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Priority;
+use scheduling_environment::work_order::RiskClassification;
+use scheduling_environment::work_order::WorkOrder;
+use scheduling_environment::work_order::WorkOrderNumber;
+use strategic_algorithm::WorkOrderParameter;
+use strategic_algorithm::strategic_options::StrategicOptions;
+
+#[test]
+fn test_derive_weight()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+    let strategic_options = StrategicOptions::default();
+
+    let work_order = |priority: Priority| {
+        WorkOrder::new(
+            WorkOrderNumber::new(1122334455).unwrap(),
+            periods[0].start_date(),
+            vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+        )
+        .unwrap()
+        .with_priority(priority)
+    };
+
+    assert_eq!(
+        WorkOrderParameter::derive_weight(&work_order(Priority::Medium), &periods, &strategic_options),
+        10
+    );
+    assert_eq!(
+        WorkOrderParameter::derive_weight(&work_order(Priority::VeryHigh), &periods, &strategic_options),
+        1000
+    );
+    // Due in the second period, so the second and third periods end after
+    // the due date.
+    assert_eq!(
+        WorkOrderParameter::derive_weight(
+            &work_order(Priority::High).with_due_date(periods[1].start_date()),
+            &periods,
+            &strategic_options
+        ),
+        300
+    );
+    // Overdue work weighs the most.
+    assert_eq!(
+        WorkOrderParameter::derive_weight(
+            &work_order(Priority::Low).with_due_date(periods[0].start_date().pred_opt().unwrap()),
+            &periods,
+            &strategic_options
+        ),
+        4
+    );
+    assert_eq!(
+        WorkOrderParameter::derive_weight(
+            &work_order(Priority::Low).with_due_date(periods[2].end_date()),
+            &periods,
+            &strategic_options
+        ),
+        1
+    );
+    // Safety work is boosted on top of its priority.
+    assert_eq!(
+        WorkOrderParameter::derive_weight(
+            &work_order(Priority::Medium).with_risk_classification(RiskClassification {
+                hse_critical: true,
+                simops_restricted: false,
+            }),
+            &periods,
+            &strategic_options
+        ),
+        100
+    );
+
+    // Two biweekly periods, i.e. four weeks, since the basic start when
+    // the strategic periods begin.
+    let strategic_options = StrategicOptions {
+        priority_weight: 2,
+        age_weight: 5,
+        due_weight: 3,
+        ..StrategicOptions::default()
+    };
+    let old_work_order = WorkOrder::new(
+        WorkOrderNumber::new(1122334466).unwrap(),
+        periods[0].start_date() - (periods[1].start_date() - periods[0].start_date()) * 2,
+        vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+    )
+    .unwrap()
+    .with_priority(Priority::High)
+    .with_due_date(periods[1].start_date());
+    assert_eq!(
+        WorkOrderParameter::derive_weight(&old_work_order, &periods, &strategic_options),
+        4 * (1 + 3 * 2) + 5 * 4
+    );
+}
+
+#[test]
+fn test_derive_weight_with_extreme_weights()
+{
+    let periods = fixtures::periods()[..3].to_vec();
+    let work_order = WorkOrder::new(
+        WorkOrderNumber::new(1122334455).unwrap(),
+        periods[0].start_date() - (periods[1].start_date() - periods[0].start_date()) * 100,
+        vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+    )
+    .unwrap()
+    .with_priority(Priority::VeryHigh)
+    .with_due_date(periods[0].start_date());
+
+    // `i64::MAX` cubed, the due factor and the age all overflow on their
+    // own.
+    let strategic_options = StrategicOptions {
+        priority_weight: i64::MAX,
+        ..StrategicOptions::default()
+    };
+    assert_eq!(WorkOrderParameter::derive_weight(&work_order, &periods, &strategic_options), i64::MAX);
+    let strategic_options = StrategicOptions {
+        due_weight: i64::MAX,
+        ..StrategicOptions::default()
+    };
+    assert_eq!(WorkOrderParameter::derive_weight(&work_order, &periods, &strategic_options), i64::MAX);
+    let strategic_options = StrategicOptions {
+        age_weight: i64::MAX / 2,
+        ..StrategicOptions::default()
+    };
+    assert_eq!(WorkOrderParameter::derive_weight(&work_order, &periods, &strategic_options), i64::MAX);
+
+    // The boost of safety work saturates on top of a saturated weight.
+    let safety_work_order = WorkOrder::new(
+        WorkOrderNumber::new(1122334466).unwrap(),
+        periods[0].start_date(),
+        vec![Activity::new(ActivityNumber::new(10), 1, Skill::MTN_MECH)],
+    )
+    .unwrap()
+    .with_priority(Priority::VeryHigh)
+    .with_risk_classification(RiskClassification {
+        hse_critical: true,
+        simops_restricted: false,
+    });
+    let strategic_options = StrategicOptions {
+        priority_weight: i64::MAX,
+        ..StrategicOptions::default()
+    };
+    assert_eq!(
+        WorkOrderParameter::derive_weight(&safety_work_order, &periods, &strategic_options),
+        i64::MAX
+    );
+    let strategic_options = StrategicOptions {
+        safety_weight_boost: i64::MAX,
+        ..StrategicOptions::default()
+    };
+    assert_eq!(
+        WorkOrderParameter::derive_weight(&safety_work_order, &periods, &strategic_options),
+        i64::MAX
+    );
+
+    // Negative weights saturate at the other end.
+    let strategic_options = StrategicOptions {
+        priority_weight: i64::MIN,
+        ..StrategicOptions::default()
+    };
+    assert_eq!(WorkOrderParameter::derive_weight(&work_order, &periods, &strategic_options), i64::MIN);
+}